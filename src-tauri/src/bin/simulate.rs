@@ -0,0 +1,313 @@
+//! Headless batch runner for the `Engine::step` turn pipeline.
+//!
+//! Plays many seeded games with a pluggable policy and writes one result row per game:
+//!
+//!   cargo run --bin simulate -- --difficulty vc --seeds 0..500 --policy greedy --format csv
+
+use app_lib::game::{
+  actions::{Quality, RefactorDepth},
+  cap_table::Instrument,
+  check_defeat, check_victory,
+  events_enhanced::{EnhancedEventType, GameEvent},
+  progression::get_available_actions,
+  projects::{committed_focus, is_in_progress},
+  replay::apply_and_record_choice,
+  rng::rng_for_stream,
+  Action, DebtKind, DifficultyMode, Engine, GameRng, GameState,
+};
+use rand::prelude::*;
+use serde::Serialize;
+use std::{env, fs, io::Write, ops::Range, process};
+
+const USAGE: &str = "Usage: simulate [--difficulty indie|vc|regulated|infra] [--seeds START..END]
+                [--weeks N] [--policy random|greedy|<script.json>] [--format csv|json] [--out FILE]
+
+A script is a JSON array of weeks, each an array of actions, e.g.
+  [[{\"ShipFeature\":{\"quality\":\"Balanced\"}},{\"FounderLedSales\":{\"call_count\":3}}],[\"TakeBreak\"]]
+and is repeated from the start when it runs out.";
+
+/// RNG stream reserved for policies so they never shift the game's own rolls
+const POLICY_STREAM: u64 = u64::MAX;
+
+struct Options {
+  difficulty: String,
+  seeds: Range<u64>,
+  max_weeks: u32,
+  policy: String,
+  format: String,
+  out: Option<String>,
+}
+
+#[derive(Serialize)]
+struct GameSummary {
+  seed: u64,
+  difficulty: String,
+  policy: String,
+  outcome: String,
+  defeat_reason: Option<String>,
+  weeks_survived: u32,
+  final_mrr: f64,
+  final_bank: f64,
+  founder_equity: f64,
+}
+
+/// Decides what the player does each week
+trait Policy {
+  fn choose_actions(&mut self, state: &GameState, available: &[Action]) -> Vec<Action>;
+
+  /// Pick a choice index for a dilemma; defaults to the first option
+  fn choose_dilemma(&mut self, _state: &GameState, _event: &GameEvent, _choice_count: usize) -> usize {
+    0
+  }
+}
+
+/// Spends focus on uniformly random available actions
+struct RandomPolicy {
+  rng: GameRng,
+}
+
+impl Policy for RandomPolicy {
+  fn choose_actions(&mut self, state: &GameState, available: &[Action]) -> Vec<Action> {
+    let mut pool = available.to_vec();
+    pool.shuffle(&mut self.rng);
+    fill_focus(state, pool)
+  }
+
+  fn choose_dilemma(&mut self, _state: &GameState, _event: &GameEvent, choice_count: usize) -> usize {
+    self.rng.random_range(0..choice_count)
+  }
+}
+
+/// Fixes the most urgent problem first, then ships and sells
+struct GreedyPolicy;
+
+impl Policy for GreedyPolicy {
+  fn choose_actions(&mut self, state: &GameState, available: &[Action]) -> Vec<Action> {
+    let mut wishlist = Vec::new();
+    if state.morale < 40.0 {
+      wishlist.push(Action::TakeBreak);
+    }
+    if state.runway_months < 6.0 && state.mrr < state.burn {
+      wishlist.push(Action::Fundraise { target: 500_000.0, instrument: Instrument::PricedRound });
+      wishlist.push(Action::TakeLoan { kind: DebtKind::RevenueBased });
+      wishlist.push(Action::FounderLedSales { call_count: 5 });
+    }
+    if state.tech_debt > 60.0 {
+      wishlist.push(Action::RefactorCode { depth: RefactorDepth::Surface });
+    }
+    wishlist.push(Action::FounderLedSales { call_count: 5 });
+    wishlist.push(Action::ShipFeature { quality: Quality::Balanced });
+    wishlist.push(Action::TakeBreak);
+
+    let pool = wishlist.into_iter().filter(|a| available.contains(a)).collect();
+    fill_focus(state, pool)
+  }
+}
+
+/// Replays a fixed list of weekly actions from a JSON file
+struct ScriptedPolicy {
+  weeks: Vec<Vec<Action>>,
+  cursor: usize,
+}
+
+impl Policy for ScriptedPolicy {
+  fn choose_actions(&mut self, state: &GameState, available: &[Action]) -> Vec<Action> {
+    if self.weeks.is_empty() {
+      return Vec::new();
+    }
+    let week = &self.weeks[self.cursor % self.weeks.len()];
+    self.cursor += 1;
+    week
+      .iter()
+      .filter(|a| available.contains(a) && !is_in_progress(state, a))
+      .cloned()
+      .collect()
+  }
+}
+
+/// Take actions in order while they fit in the focus left over from running projects
+fn fill_focus(state: &GameState, pool: Vec<Action>) -> Vec<Action> {
+  let mut remaining = state.focus_slots.saturating_sub(committed_focus(state));
+  let mut chosen: Vec<Action> = Vec::new();
+  for action in pool {
+    let cost = action.focus_cost();
+    let project_taken = action.duration_weeks() > 1
+      && (is_in_progress(state, &action) || chosen.iter().any(|c| c.duration_weeks() > 1 && std::mem::discriminant(c) == std::mem::discriminant(&action)));
+    if cost <= remaining && !chosen.contains(&action) && !project_taken {
+      remaining -= cost;
+      chosen.push(action);
+    }
+  }
+  chosen
+}
+
+fn build_policy(name: &str, seed: u64) -> Result<Box<dyn Policy>, String> {
+  match name {
+    "random" => Ok(Box::new(RandomPolicy {
+      rng: rng_for_stream(seed, POLICY_STREAM),
+    })),
+    "greedy" => Ok(Box::new(GreedyPolicy)),
+    path => {
+      let json = fs::read_to_string(path).map_err(|e| format!("Cannot read script {}: {}", path, e))?;
+      let weeks = serde_json::from_str(&json).map_err(|e| format!("Invalid script {}: {}", path, e))?;
+      Ok(Box::new(ScriptedPolicy { weeks, cursor: 0 }))
+    }
+  }
+}
+
+fn play_game(options: &Options, difficulty: &DifficultyMode, seed: u64) -> Result<GameSummary, String> {
+  let mut policy = build_policy(&options.policy, seed)?;
+  let mut state = GameState::with_seed(difficulty.clone(), seed);
+  let mut outcome = "timeout".to_string();
+  let mut defeat_reason = None;
+
+  while state.week < options.max_weeks {
+    if check_victory(&state).is_some() {
+      outcome = "victory".to_string();
+      break;
+    }
+    if let Some(defeat) = check_defeat(&state) {
+      outcome = "defeat".to_string();
+      defeat_reason = Some(format!("{:?}", defeat));
+      break;
+    }
+
+    let available = get_available_actions(&state);
+    let actions = policy.choose_actions(&state, &available);
+    let result = Engine::step(&mut state, &actions)?;
+
+    for event in &result.events {
+      if let EnhancedEventType::Dilemma { choices } = &event.event_type {
+        let index = policy.choose_dilemma(&state, event, choices.len());
+        apply_and_record_choice(&mut state, event, index)?;
+      }
+    }
+  }
+
+  Ok(GameSummary {
+    seed,
+    difficulty: options.difficulty.clone(),
+    policy: options.policy.clone(),
+    outcome,
+    defeat_reason,
+    weeks_survived: state.week,
+    final_mrr: state.mrr,
+    final_bank: state.bank,
+    founder_equity: state.founder_equity,
+  })
+}
+
+fn to_csv(summaries: &[GameSummary]) -> String {
+  let mut csv = String::from(
+    "seed,difficulty,policy,outcome,defeat_reason,weeks_survived,final_mrr,final_bank,founder_equity\n",
+  );
+  for s in summaries {
+    csv.push_str(&format!(
+      "{},{},{},{},{},{},{:.2},{:.2},{:.2}\n",
+      s.seed,
+      s.difficulty,
+      s.policy,
+      s.outcome,
+      s.defeat_reason.as_deref().unwrap_or(""),
+      s.weeks_survived,
+      s.final_mrr,
+      s.final_bank,
+      s.founder_equity,
+    ));
+  }
+  csv
+}
+
+fn parse_seeds(value: &str) -> Result<Range<u64>, String> {
+  let (start, end) = value.split_once("..").ok_or("Seeds must look like START..END")?;
+  let start = start.parse().map_err(|_| format!("Invalid seed: {}", start))?;
+  let end = end.parse().map_err(|_| format!("Invalid seed: {}", end))?;
+  Ok(start..end)
+}
+
+fn parse_args() -> Result<Options, String> {
+  let mut options = Options {
+    difficulty: "indie".to_string(),
+    seeds: 0..100,
+    max_weeks: 104,
+    policy: "greedy".to_string(),
+    format: "csv".to_string(),
+    out: None,
+  };
+
+  let mut args = env::args().skip(1);
+  while let Some(flag) = args.next() {
+    if flag == "--help" || flag == "-h" {
+      println!("{}", USAGE);
+      process::exit(0);
+    }
+    let value = args.next().ok_or(format!("Missing value for {}", flag))?;
+    match flag.as_str() {
+      "--difficulty" => options.difficulty = value,
+      "--seeds" => options.seeds = parse_seeds(&value)?,
+      "--weeks" => options.max_weeks = value.parse().map_err(|_| "Invalid --weeks")?,
+      "--policy" => options.policy = value,
+      "--format" => options.format = value,
+      "--out" => options.out = Some(value),
+      _ => return Err(format!("Unknown flag: {}", flag)),
+    }
+  }
+  Ok(options)
+}
+
+fn run() -> Result<(), String> {
+  let options = parse_args()?;
+  let difficulty = DifficultyMode::from_key(&options.difficulty).ok_or("Invalid difficulty mode")?;
+
+  let summaries = options
+    .seeds
+    .clone()
+    .map(|seed| play_game(&options, &difficulty, seed))
+    .collect::<Result<Vec<_>, _>>()?;
+
+  let output = match options.format.as_str() {
+    "csv" => to_csv(&summaries),
+    "json" => serde_json::to_string_pretty(&summaries).map_err(|e| e.to_string())?,
+    other => return Err(format!("Unknown format: {}", other)),
+  };
+
+  match &options.out {
+    Some(path) => fs::write(path, output).map_err(|e| format!("Cannot write {}: {}", path, e)),
+    None => std::io::stdout().write_all(output.as_bytes()).map_err(|e| e.to_string()),
+  }
+}
+
+fn main() {
+  if let Err(e) = run() {
+    eprintln!("error: {}\n\n{}", e, USAGE);
+    process::exit(1);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use app_lib::game::OfferTerms;
+
+  #[test]
+  fn test_parse_seeds() {
+    assert_eq!(parse_seeds("10..20").unwrap(), 10..20);
+    assert!(parse_seeds("10").is_err());
+  }
+
+  #[test]
+  fn test_fill_focus_respects_slots() {
+    let state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+    let pool = vec![
+      Action::Hire { candidate_id: 2, offer: OfferTerms::AtAsk },
+      Action::Fundraise { target: 250_000.0, instrument: Instrument::PricedRound },
+      Action::TakeBreak,
+    ];
+
+    let chosen = fill_focus(&state, pool);
+
+    let cost: u8 = chosen.iter().map(|a| a.focus_cost()).sum();
+    assert!(cost <= state.focus_slots);
+    assert_eq!(chosen, vec![Action::Hire { candidate_id: 2, offer: OfferTerms::AtAsk }, Action::TakeBreak]);
+  }
+}
@@ -1,7 +1,28 @@
 use serde::{Deserialize, Serialize};
 use rand::Rng;
 use super::state::GameState;
-use super::customers::{generate_customer_persona, calculate_segment_from_mrr};
+use super::customers::{generate_customer_persona, calculate_segment_from_mrr, win_back};
+use super::rng::GameRng;
+use super::market_conditions::Effectiveness;
+use super::effects::{apply_effects, Effect, Stat};
+use super::cap_table::Instrument;
+use super::investors::close_round;
+use super::debt::{take_loan, DebtKind};
+use super::ledger;
+use super::mrr_bridge::{record_mrr, MrrMovement};
+use super::metrics::{record_acquisition, AcquisitionChannel, SALES_CALL_COST};
+use super::cohorts::{add_signups, reactivate};
+use super::funnel::run_funnel;
+use super::billing::sign_annual_contract;
+use super::team::{self, apply_velocity_change, sales_capacity, settle_velocity, team_velocity};
+use super::hiring::{interview, make_offer, OfferTerms};
+use super::pricing::{change_price, conversion_factor, fair_price, set_list_price, PlanTier, PriceChange};
+pub use super::effects::StatEffect;
+
+/// Chance a converted sales call is a lapsed customer coming back
+const WIN_BACK_CHANCE: f64 = 0.3;
+/// Chance a sales prospect is large enough to buy the Enterprise plan
+const ENTERPRISE_PROSPECT_SHARE: f64 = 0.15;
 
 /// Quality level for features
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -81,14 +102,16 @@ pub enum Action {
 
     // SALES & GROWTH (Focus: 1 slot)
     FounderLedSales { call_count: u8 },
+    ChangePrice { tier: PlanTier, change: PriceChange },
     ContentLaunch { content_type: ContentType },
     DevRel { event_type: DevRelEvent },
     PaidAds { budget: f64, channel: AdChannel },
 
     // TEAM (Focus: 1-2 slots)
-    Hire,
+    Interview { candidate_id: u32 },
+    Hire { candidate_id: u32, offer: OfferTerms },
     Coach { focus: CoachingFocus },
-    Fire { reason: FiringReason },
+    Fire { employee_id: u32, reason: FiringReason },
 
     // OPERATIONS (Focus: 1-2 slots)
     ComplianceWork { hours: u8 },
@@ -96,7 +119,13 @@ pub enum Action {
     ProcessImprovement,
 
     // CAPITAL (Focus: 2 slots)
-    Fundraise { target: f64 },
+    Fundraise {
+        target: f64,
+        #[serde(default)]
+        instrument: Instrument,
+    },
+
+    TakeLoan { kind: DebtKind },
 
     // RECOVERY (Focus: 0.5 slots)
     TakeBreak,
@@ -114,19 +143,36 @@ impl Action {
             },
             Action::RunExperiment { .. } => 1,
             Action::FounderLedSales { .. } => 1,
+            Action::ChangePrice { .. } => 1,
             Action::ContentLaunch { .. } => 1,
             Action::DevRel { .. } => 2,
             Action::PaidAds { .. } => 1,
-            Action::Hire => 2,
+            Action::Interview { .. } => 1,
+            Action::Hire { .. } => 2,
             Action::Coach { .. } => 1,
             Action::Fire { .. } => 1,
             Action::ComplianceWork { .. } => 1,
             Action::IncidentResponse => 2,
             Action::ProcessImprovement => 1,
             Action::Fundraise { .. } => 2,
+            Action::TakeLoan { .. } => 1,
             Action::TakeBreak => 1,
         }
     }
+
+    /// Weeks of work before the action pays off; anything longer than one week runs as a project
+    pub fn duration_weeks(&self) -> u32 {
+        match self {
+            Action::RefactorCode { depth: RefactorDepth::Deep } => 3,
+            Action::ComplianceWork { .. } => 2,
+            Action::DevRel { .. } => 2,
+            // The window investors have to get to a term sheet
+            Action::Fundraise { instrument: Instrument::PricedRound, .. } => 6,
+            // SAFEs and notes skip the partner meeting and diligence
+            Action::Fundraise { .. } => 3,
+            _ => 1,
+        }
+    }
 }
 
 /// Result of applying an action
@@ -137,25 +183,18 @@ pub struct ActionResult {
     pub effects: Vec<StatEffect>,
 }
 
-/// Individual stat change from an action
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StatEffect {
-    pub stat_name: String,
-    pub old_value: f64,
-    pub new_value: f64,
-    pub delta: f64,
-}
-
 /// Result of an experiment
 #[derive(Debug, Clone)]
 pub struct ExperimentResult {
     pub success: bool,
     pub insight: String,
-    pub effects: Vec<StatEffect>,
+    pub effects: Vec<Effect>,
+    /// Pro list price a successful pricing experiment settled on
+    pub pro_price: Option<f64>,
 }
 
 /// Calculate refactor impact based on depth and current debt
-pub fn calculate_refactor_impact(depth: &RefactorDepth, current_debt: f64) -> (f64, f64) {
+pub fn calculate_refactor_impact(depth: &RefactorDepth, current_debt: f64, rng: &mut GameRng) -> (f64, f64) {
     let base_reduction = match depth {
         RefactorDepth::Surface => 10.0,
         RefactorDepth::Medium => 20.0,
@@ -163,105 +202,76 @@ pub fn calculate_refactor_impact(depth: &RefactorDepth, current_debt: f64) -> (f
     };
     // More effective when debt is high
     let debt_modifier = if current_debt > 50.0 { 1.2 } else { 1.0 };
-    let debt_reduction = base_reduction * debt_modifier * (0.8 + rand::random::<f64>() * 0.4); // ±20% variance
+    let debt_reduction = base_reduction * debt_modifier * (0.8 + rng.random::<f64>() * 0.4); // ±20% variance
 
     let velocity_gain = match depth {
         RefactorDepth::Surface => 0.05,
         RefactorDepth::Medium => 0.12,
         RefactorDepth::Deep => 0.2,
-    } * (0.9 + rand::random::<f64>() * 0.2); // ±10% variance
+    } * (0.9 + rng.random::<f64>() * 0.2); // ±10% variance
 
     (debt_reduction, velocity_gain)
 }
 
 /// Calculate experiment outcome
-pub fn calculate_experiment_outcome(category: &ExperimentType, state: &GameState) -> ExperimentResult {
-    let mut rng = rand::thread_rng();
-    let success = rng.gen_bool(0.6); // 60% success rate
+pub fn calculate_experiment_outcome(category: &ExperimentType, state: &GameState, rng: &mut GameRng) -> ExperimentResult {
+    let success = rng.random_bool(0.6); // 60% success rate
 
+    let mut pro_price = None;
     let (insight, effects) = if success {
         match category {
             ExperimentType::Pricing => {
-                let mrr_boost = state.mrr * 0.05 * (0.8 + rng.gen_range(0.0..0.4));
-                let insight = "Found optimal pricing tier - increased conversion".to_string();
-                let mut effects = Vec::new();
-                effects.push(StatEffect {
-                    stat_name: "MRR".to_string(),
-                    old_value: state.mrr,
-                    new_value: state.mrr + mrr_boost,
-                    delta: mrr_boost,
-                });
-                (insight, effects)
+                // Price tests find what the product is worth to buyers today
+                let price = fair_price(state, PlanTier::Pro).round();
+                pro_price = Some(price);
+                let insight = format!("Found the price buyers accept - Pro is now ${:.0} a seat", price);
+                (insight, vec![])
             }
             ExperimentType::Onboarding => {
-                let wau_boost = (state.wau as f64 * 0.03) * (0.8 + rng.gen_range(0.0..0.4));
+                let wau_boost = (state.wau as f64 * 0.03) * (0.8 + rng.random_range(0.0..0.4));
                 let insight = "Streamlined onboarding - reduced churn".to_string();
-                let mut effects = Vec::new();
-                effects.push(StatEffect {
-                    stat_name: "WAU".to_string(),
-                    old_value: state.wau as f64,
-                    new_value: state.wau as f64 + wau_boost,
-                    delta: wau_boost,
-                });
-                effects.push(StatEffect {
-                    stat_name: "Churn Rate".to_string(),
-                    old_value: state.churn_rate,
-                    new_value: state.churn_rate * 0.95,
-                    delta: state.churn_rate * -0.05,
-                });
-                (insight, effects)
+                (insight, vec![
+                    Effect::add(Stat::Wau, wau_boost),
+                    Effect::add(Stat::OnboardingQuality, 5.0),
+                ])
             }
             ExperimentType::Channel => {
-                let rep_boost = 5.0 * (0.8 + rng.gen_range(0.0..0.4));
+                let rep_boost = 5.0 * (0.8 + rng.random_range(0.0..0.4));
                 let insight = "Discovered high-converting channel".to_string();
-                let mut effects = Vec::new();
-                effects.push(StatEffect {
-                    stat_name: "Reputation".to_string(),
-                    old_value: state.reputation,
-                    new_value: state.reputation + rep_boost,
-                    delta: rep_boost,
-                });
-                (insight, effects)
+                (insight, vec![Effect::add(Stat::Reputation, rep_boost)])
             }
         }
     } else {
         let insight = "Experiment failed - learned what not to do".to_string();
-        let mut effects = Vec::new();
-        effects.push(StatEffect {
-            stat_name: "Morale".to_string(),
-            old_value: state.morale,
-            new_value: state.morale - 2.0,
-            delta: -2.0,
-        });
-        (insight, effects)
+        (insight, vec![Effect::add(Stat::Morale, -2.0)])
     };
 
-    ExperimentResult { success, insight, effects }
+    ExperimentResult { success, insight, effects, pro_price }
 }
 
-/// Calculate content reach
-pub fn calculate_content_reach(content_type: &ContentType, reputation: f64) -> (f64, f64) {
-    let base_wau = match content_type {
-        ContentType::BlogPost => 2.0,
-        ContentType::Tutorial => 4.0,
-        ContentType::CaseStudy => 3.0,
-        ContentType::Video => 5.0,
+/// Calculate content reach: visitors and reputation gained
+pub fn calculate_content_reach(content_type: &ContentType, reputation: f64, rng: &mut GameRng) -> (f64, f64) {
+    let base_visitors = match content_type {
+        ContentType::BlogPost => 40.0,
+        ContentType::Tutorial => 80.0,
+        ContentType::CaseStudy => 60.0,
+        ContentType::Video => 100.0,
     };
     let rep_modifier = reputation / 100.0;
-    let wau_gain = base_wau * (0.8 + rep_modifier) * (0.8 + rand::random::<f64>() * 0.4); // ±20% variance
+    let visitors = base_visitors * (0.8 + rep_modifier) * (0.8 + rng.random::<f64>() * 0.4); // ±20% variance
 
     let rep_gain = match content_type {
         ContentType::BlogPost => 2.0,
         ContentType::Tutorial => 3.0,
         ContentType::CaseStudy => 4.0,
         ContentType::Video => 5.0,
-    } * (0.9 + rand::random::<f64>() * 0.2); // ±10% variance
+    } * (0.9 + rng.random::<f64>() * 0.2); // ±10% variance
 
-    (wau_gain, rep_gain)
+    (visitors, rep_gain)
 }
 
-/// Calculate ad effectiveness
-pub fn calculate_ad_effectiveness(channel: &AdChannel, budget: f64, market_saturation: f64) -> f64 {
+/// Calculate ad effectiveness: visitors the budget buys
+pub fn calculate_ad_effectiveness(channel: &AdChannel, budget: f64, market_saturation: f64, rng: &mut GameRng) -> f64 {
     let base_effectiveness = match channel {
         AdChannel::Google => 0.8,
         AdChannel::Social => 1.0,
@@ -269,13 +279,28 @@ pub fn calculate_ad_effectiveness(channel: &AdChannel, budget: f64, market_satur
         AdChannel::Influencer => 1.2,
     };
     let saturation_penalty = market_saturation / 100.0; // Assume market_saturation is 0-100
-    let effectiveness = base_effectiveness * (1.0 - saturation_penalty) * (0.8 + rand::random::<f64>() * 0.4); // ±20% variance
-    effectiveness * budget / 10000.0 // Scale by budget
+    let effectiveness = base_effectiveness * (1.0 - saturation_penalty) * (0.8 + rng.random::<f64>() * 0.4); // ±20% variance
+    effectiveness * budget / 10.0 // About $10 a visitor before saturation
 }
 
-/// Apply an action to the game state
-pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
-    let mut rng = rand::thread_rng();
+/// Report the MRR new paying customers brought in, if any converted
+fn push_mrr_effect(effects: &mut Vec<StatEffect>, old_mrr: f64, new_mrr: f64) {
+    if new_mrr != old_mrr {
+        effects.push(StatEffect { stat: Stat::Mrr, old_value: old_mrr, new_value: new_mrr, delta: new_mrr - old_mrr });
+    }
+}
+
+/// Apply an action to the game state, scaled by current market conditions
+///
+/// Any cash the action moves is recorded in the ledger against its message.
+pub fn resolve_action(state: &mut GameState, action: &Action, effectiveness: &Effectiveness, rng: &mut GameRng) -> ActionResult {
+    let old_bank = state.bank;
+    let result = apply_action(state, action, effectiveness, rng);
+    ledger::record_bank_change(state, old_bank, ledger::action_category(action), &result.message);
+    result
+}
+
+fn apply_action(state: &mut GameState, action: &Action, effectiveness: &Effectiveness, rng: &mut GameRng) -> ActionResult {
     let mut effects = Vec::new();
 
     match action {
@@ -289,42 +314,45 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             // Base effects with variance
             let (wau_boost, debt_change, _momentum_change, morale_change) = match quality {
                 Quality::Quick => {
-                    let wau = 3.0 + rng.gen_range(-1.5..1.5);
-                    let debt = 6.0 + rng.gen_range(-2.0..2.0);
-                    let momentum = 8.0 + rng.gen_range(-3.0..3.0);
-                    let morale = -1.0 + rng.gen_range(-0.5..0.5);
+                    let wau = 3.0 + rng.random_range(-1.5..1.5);
+                    let debt = 6.0 + rng.random_range(-2.0..2.0);
+                    let momentum = 8.0 + rng.random_range(-3.0..3.0);
+                    let morale = -1.0 + rng.random_range(-0.5..0.5);
                     (wau, debt, momentum, morale)
                 }
                 Quality::Balanced => {
-                    let wau = 4.0 + rng.gen_range(-1.5..1.5);
-                    let debt = 2.0 + rng.gen_range(-1.0..1.0);
-                    let momentum = 5.0 + rng.gen_range(-2.0..2.0);
-                    let morale = 1.0 + rng.gen_range(-0.5..0.5);
+                    let wau = 4.0 + rng.random_range(-1.5..1.5);
+                    let debt = 2.0 + rng.random_range(-1.0..1.0);
+                    let momentum = 5.0 + rng.random_range(-2.0..2.0);
+                    let morale = 1.0 + rng.random_range(-0.5..0.5);
                     (wau, debt, momentum, morale)
                 }
                 Quality::Polish => {
-                    let wau = 2.0 + rng.gen_range(-1.0..1.0);
-                    let debt = -3.0 + rng.gen_range(-1.0..1.0);
-                    let momentum = 2.0 + rng.gen_range(-1.0..1.0);
-                    let morale = 3.0 + rng.gen_range(-1.0..1.0);
+                    let wau = 2.0 + rng.random_range(-1.0..1.0);
+                    let debt = -3.0 + rng.random_range(-1.0..1.0);
+                    let momentum = 2.0 + rng.random_range(-1.0..1.0);
+                    let morale = 3.0 + rng.random_range(-1.0..1.0);
                     (wau, debt, momentum, morale)
                 }
             };
 
-            // Apply effects
+            // Launch buzz sends visitors in proportion to the user base
+            let wau_boost = wau_boost * effectiveness.output;
             let old_wau = state.wau;
-            state.wau = (state.wau as f64 * (1.0 + wau_boost / 100.0)) as u32;
+            let old_mrr = state.mrr;
+            run_funnel(state, state.wau as f64 * wau_boost / 5.0, 0.0, rng);
             effects.push(StatEffect {
-                stat_name: "WAU".to_string(),
+                stat: Stat::Wau,
                 old_value: old_wau as f64,
                 new_value: state.wau as f64,
-                delta: (state.wau - old_wau) as f64,
+                delta: state.wau as f64 - old_wau as f64,
             });
+            push_mrr_effect(&mut effects, old_mrr, state.mrr);
 
             let old_debt = state.tech_debt;
             state.tech_debt += debt_change;
             effects.push(StatEffect {
-                stat_name: "Tech Debt".to_string(),
+                stat: Stat::TechDebt,
                 old_value: old_debt,
                 new_value: state.tech_debt,
                 delta: debt_change,
@@ -333,32 +361,23 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             let old_morale = state.morale;
             state.morale += morale_change;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: morale_change,
             });
 
-            // Update velocity based on tech debt
+            // Shipping shows what the roster really sustains at this much tech debt; velocity
+            // gained earlier this week from Coach, ProcessImprovement or RefactorCode is dropped
             let old_velocity = state.velocity;
-            state.velocity = 1.0 - (state.tech_debt / 200.0);
+            let delta = settle_velocity(state);
             effects.push(StatEffect {
-                stat_name: "Velocity".to_string(),
+                stat: Stat::Velocity,
                 old_value: old_velocity,
                 new_value: state.velocity,
-                delta: state.velocity - old_velocity,
+                delta,
             });
 
-            // Create new self-serve customers if WAU growth is significant
-            let wau_growth_percent = ((state.wau as f64 - old_wau as f64) / old_wau as f64) * 100.0;
-            if wau_growth_percent > 5.0 {
-                let new_customer_count = (wau_growth_percent / 10.0).ceil() as usize;
-                for _ in 0..new_customer_count.min(3) {
-                    let customer = generate_customer_persona(super::customers::CustomerSegment::SelfServe, state.week, state);
-                    state.add_customer(customer);
-                }
-            }
-
             ActionResult {
                 success: true,
                 message: message.to_string(),
@@ -369,37 +388,87 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
         Action::FounderLedSales { call_count } => {
             let message = format!("Made {} sales calls this week", call_count);
 
-            // Each call has a chance to convert
-            let conversion_rate = 0.05 + (state.reputation / 200.0);
-            let base_deal_size = 500.0;
+            // Each call has a chance to convert, lower when the plan is priced above what buyers accept
+            // and higher with account executives working the pipeline
+            let base_conversion = (0.05 + (state.reputation / 200.0)) * effectiveness.output * sales_capacity(state);
 
             let mut new_mrr = 0.0;
+            let mut reactivated_mrr = 0.0;
             let mut new_customers = Vec::new();
+            let mut returning_customers = Vec::new();
+            let mut annual_deals = Vec::new();
 
             for _ in 0..*call_count {
-                if rng.gen_bool(conversion_rate) {
-                    let deal_size = base_deal_size * (0.8 + rng.gen_range(0.0..0.4));
+                // Large prospects buy Enterprise usage, everyone else Pro seats
+                let (tier, units) = if rng.random_bool(ENTERPRISE_PROSPECT_SHARE) {
+                    (PlanTier::Enterprise, rng.random_range(40.0..120.0))
+                } else {
+                    (PlanTier::Pro, rng.random_range(2.0..12.0_f64).round())
+                };
+                let conversion_rate = (base_conversion * conversion_factor(state, tier)).clamp(0.0, 1.0);
+                if rng.random_bool(conversion_rate) {
+                    // Some conversions are former customers coming back on their old plan
+                    if !state.lapsed_customers.is_empty() && rng.random_bool(WIN_BACK_CHANCE) {
+                        if let Some(mut customer) = state.lapsed_customers.pop() {
+                            win_back(&mut customer);
+                            reactivated_mrr += customer.mrr_contribution;
+                            returning_customers.push(customer);
+                        }
+                        continue;
+                    }
+                    // Enterprise buyers prepay a year at the annual discount; their MRR is a twelfth of it
+                    let plan = state.pricing.plan(tier);
+                    let deal_size = match tier {
+                        PlanTier::Enterprise => plan.annual_price(units) / 12.0,
+                        _ => plan.monthly_price(units),
+                    } * effectiveness.mrr_growth;
                     new_mrr += deal_size;
 
-                    // Create customer persona
+                    // What the deal is worth decides the customer's segment
                     let segment = calculate_segment_from_mrr(deal_size);
-                    let mut customer = generate_customer_persona(segment, state.week, state);
+                    let mut customer = generate_customer_persona(segment, state.week, state, rng);
                     customer.mrr_contribution = deal_size;
+                    if tier == PlanTier::Enterprise {
+                        annual_deals.push((customer.id.clone(), units));
+                    }
                     new_customers.push(customer);
                 }
             }
 
+            // New deals join this week's cohort; returning customers rejoin their own
             let old_mrr = state.mrr;
-            state.mrr += new_mrr;
+            let old_bank = state.bank;
+            for customer in &new_customers {
+                add_signups(state, customer.segment.clone(), customer.mrr_contribution);
+                if let Some((_, units)) = annual_deals.iter().find(|(id, _)| *id == customer.id) {
+                    sign_annual_contract(state, customer, PlanTier::Enterprise, *units);
+                }
+            }
+            for customer in &returning_customers {
+                reactivate(state, customer.join_week, customer.segment.clone(), customer.mrr_contribution);
+            }
+            record_mrr(state, MrrMovement::NewBusiness, new_mrr);
+            record_mrr(state, MrrMovement::Reactivated, reactivated_mrr);
+            let sales_effort = *call_count as f64 * SALES_CALL_COST;
+            let won = (new_customers.len() + returning_customers.len()) as u32;
+            record_acquisition(state, AcquisitionChannel::FounderLedSales, sales_effort, won, new_mrr + reactivated_mrr);
             effects.push(StatEffect {
-                stat_name: "MRR".to_string(),
+                stat: Stat::Mrr,
                 old_value: old_mrr,
                 new_value: state.mrr,
-                delta: new_mrr,
+                delta: new_mrr + reactivated_mrr,
             });
+            if state.bank != old_bank {
+                effects.push(StatEffect {
+                    stat: Stat::Bank,
+                    old_value: old_bank,
+                    new_value: state.bank,
+                    delta: state.bank - old_bank,
+                });
+            }
 
             // Add new customers to state
-            for customer in new_customers {
+            for customer in new_customers.into_iter().chain(returning_customers) {
                 state.add_customer(customer);
             }
 
@@ -408,7 +477,7 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             let old_morale = state.morale;
             state.morale -= morale_cost;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: -morale_cost,
@@ -418,7 +487,7 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             let old_rep = state.reputation;
             state.reputation += 1.0;
             effects.push(StatEffect {
-                stat_name: "Reputation".to_string(),
+                stat: Stat::Reputation,
                 old_value: old_rep,
                 new_value: state.reputation,
                 delta: 1.0,
@@ -441,35 +510,81 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             }
         }
 
-        Action::Hire => {
-            let message = "Hired a new team member";
+        Action::ChangePrice { tier, change } => {
+            if *tier == PlanTier::Free {
+                return ActionResult { success: false, message: "The free plan has no price to change".to_string(), effects };
+            }
+
+            // Customers already on the plan move to the new price
+            let old_mrr = state.mrr;
+            change_price(state, *tier, *change);
+            push_mrr_effect(&mut effects, old_mrr, state.mrr);
 
-            // Hiring costs
-            let salary = 10_000.0;
+            let plan = state.pricing.plan(*tier);
+            let verb = match change {
+                PriceChange::Raise => "Raised",
+                PriceChange::Cut => "Cut",
+            };
+            ActionResult {
+                success: true,
+                message: format!("{} the {:?} plan to ${:.0} a {}", verb, tier, plan.list_price, plan.metric.unit()),
+                effects,
+            }
+        }
+
+        Action::Interview { candidate_id } => match interview(state, *candidate_id, rng) {
+            Ok(candidate) => ActionResult {
+                success: true,
+                message: format!(
+                    "Interviewed {} for {}: skill {:.0}, asking ${:.0}/mo and {:.2}% in options",
+                    candidate.name,
+                    candidate.role.label().to_lowercase(),
+                    candidate.skill,
+                    candidate.salary_ask,
+                    candidate.equity_ask
+                ),
+                effects,
+            },
+            Err(message) => ActionResult { success: false, message, effects },
+        },
+
+        Action::Hire { candidate_id, offer } => {
+            // The hiring market sways the answer; velocity arrives week by week
+            // as the new hire ramps up
             let old_burn = state.burn;
-            state.burn += salary;
+            let outcome = match make_offer(state, *candidate_id, *offer, effectiveness, rng) {
+                Ok(outcome) => outcome,
+                Err(message) => return ActionResult { success: false, message, effects },
+            };
+            let candidate = &outcome.candidate;
+            if !outcome.accepted {
+                let message = match &outcome.counter_offer_from {
+                    Some(competitor) => format!("{} took a counter-offer from {}", candidate.name, competitor),
+                    None => format!("{} turned down your offer", candidate.name),
+                };
+                return ActionResult { success: false, message, effects };
+            }
+
+            let message = format!(
+                "Hired {}, a {:?} {} at ${:.0}/mo with {:.2}% in options",
+                candidate.name,
+                candidate.seniority,
+                candidate.role.label().to_lowercase(),
+                outcome.salary,
+                outcome.equity_granted
+            );
             effects.push(StatEffect {
-                stat_name: "Monthly Burn".to_string(),
+                stat: Stat::Burn,
                 old_value: old_burn,
                 new_value: state.burn,
-                delta: salary,
-            });
-
-            // Velocity boost (takes time to ramp)
-            let old_velocity = state.velocity;
-            state.velocity += 0.1;
-            effects.push(StatEffect {
-                stat_name: "Velocity".to_string(),
-                old_value: old_velocity,
-                new_value: state.velocity,
-                delta: 0.1,
+                delta: outcome.salary,
             });
 
             // Morale boost (team growth)
             let old_morale = state.morale;
             state.morale += 5.0;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: 5.0,
@@ -477,40 +592,19 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
 
             ActionResult {
                 success: true,
-                message: message.to_string(),
+                message,
                 effects,
             }
         }
 
-        Action::Fundraise { target } => {
-            // Simplified fundraising
-            let success_chance = 0.3 + (state.reputation / 200.0) + (state.momentum / 100.0);
-            let success = rng.gen_bool(success_chance.clamp(0.0, 0.8));
-
-            if success {
-                let dilution = (target / 5_000_000.0) * 20.0; // Rough dilution calc
-
-                let old_bank = state.bank;
-                state.bank += target;
-                effects.push(StatEffect {
-                    stat_name: "Bank".to_string(),
-                    old_value: old_bank,
-                    new_value: state.bank,
-                    delta: *target,
-                });
-
-                let old_equity = state.founder_equity;
-                state.founder_equity -= dilution;
-                effects.push(StatEffect {
-                    stat_name: "Founder Equity".to_string(),
-                    old_value: old_equity,
-                    new_value: state.founder_equity,
-                    delta: -dilution,
-                });
+        Action::Fundraise { .. } => {
+            // The raise window is over: sign the best term sheet the pipeline produced
+            if let Some((financing_effects, summary)) = close_round(state) {
+                effects.extend(financing_effects);
 
                 ActionResult {
                     success: true,
-                    message: format!("Raised ${:.0}! Dilution: {:.1}%", target, dilution),
+                    message: format!("{} You now own {:.1}%", summary, state.founder_equity),
                     effects,
                 }
             } else {
@@ -518,7 +612,7 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
                 let old_morale = state.morale;
                 state.morale -= 10.0;
                 effects.push(StatEffect {
-                    stat_name: "Morale".to_string(),
+                    stat: Stat::Morale,
                     old_value: old_morale,
                     new_value: state.morale,
                     delta: -10.0,
@@ -526,12 +620,20 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
 
                 ActionResult {
                     success: false,
-                    message: "Fundraising failed - investors passed".to_string(),
+                    message: "Fundraising failed - no investor got to a term sheet".to_string(),
                     effects,
                 }
             }
         }
 
+        Action::TakeLoan { kind } => match take_loan(state, *kind) {
+            Ok((loan_effects, summary)) => {
+                effects.extend(loan_effects);
+                ActionResult { success: true, message: summary, effects }
+            }
+            Err(reason) => ActionResult { success: false, message: reason, effects },
+        },
+
         Action::RefactorCode { depth } => {
             let message = match depth {
                 RefactorDepth::Surface => "Did surface-level refactoring",
@@ -539,12 +641,12 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
                 RefactorDepth::Deep => "Performed deep code refactoring",
             };
 
-            let (debt_reduction, velocity_gain) = calculate_refactor_impact(depth, state.tech_debt);
+            let (debt_reduction, velocity_gain) = calculate_refactor_impact(depth, state.tech_debt, rng);
 
             let old_debt = state.tech_debt;
             state.tech_debt -= debt_reduction;
             effects.push(StatEffect {
-                stat_name: "Tech Debt".to_string(),
+                stat: Stat::TechDebt,
                 old_value: old_debt,
                 new_value: state.tech_debt,
                 delta: -debt_reduction,
@@ -553,7 +655,7 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             let old_velocity = state.velocity;
             state.velocity += velocity_gain;
             effects.push(StatEffect {
-                stat_name: "Velocity".to_string(),
+                stat: Stat::Velocity,
                 old_value: old_velocity,
                 new_value: state.velocity,
                 delta: velocity_gain,
@@ -564,11 +666,11 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
                 RefactorDepth::Surface => 2.0,
                 RefactorDepth::Medium => 5.0,
                 RefactorDepth::Deep => 10.0,
-            } * (0.9 + rand::random::<f64>() * 0.2);
+            } * (0.9 + rng.random::<f64>() * 0.2);
             let old_morale = state.morale;
             state.morale -= morale_cost;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: -morale_cost,
@@ -582,19 +684,14 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
         }
 
         Action::RunExperiment { category } => {
-            let result = calculate_experiment_outcome(category, state);
+            let result = calculate_experiment_outcome(category, state, rng);
             let message = format!("Ran {} experiment: {}", format!("{:?}", category).to_lowercase(), result.insight);
 
-            for effect in result.effects {
-                match effect.stat_name.as_str() {
-                    "MRR" => state.mrr = effect.new_value,
-                    "WAU" => state.wau = effect.new_value as u32,
-                    "Churn Rate" => state.churn_rate = effect.new_value,
-                    "Morale" => state.morale = effect.new_value,
-                    "Reputation" => state.reputation = effect.new_value,
-                    _ => {}
-                }
-                effects.push(effect);
+            effects.extend(apply_effects(state, &result.effects));
+            if let Some(price) = result.pro_price {
+                let old_mrr = state.mrr;
+                set_list_price(state, PlanTier::Pro, price);
+                push_mrr_effect(&mut effects, old_mrr, state.mrr);
             }
 
             ActionResult {
@@ -607,32 +704,30 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
         Action::ContentLaunch { content_type } => {
             let message = format!("Launched {} content", format!("{:?}", content_type).to_lowercase().replace('_', " "));
 
-            let (wau_gain, rep_gain) = calculate_content_reach(content_type, state.reputation);
+            let (visitors, rep_gain) = calculate_content_reach(content_type, state.reputation, rng);
+            let (visitors, rep_gain) = (visitors * effectiveness.output, rep_gain * effectiveness.output);
 
             let old_wau = state.wau;
-            state.wau = (state.wau as f64 + wau_gain) as u32;
+            let old_mrr = state.mrr;
+            let funnel = run_funnel(state, visitors, 0.0, rng);
             effects.push(StatEffect {
-                stat_name: "WAU".to_string(),
+                stat: Stat::Wau,
                 old_value: old_wau as f64,
                 new_value: state.wau as f64,
-                delta: wau_gain,
+                delta: funnel.activated,
             });
+            push_mrr_effect(&mut effects, old_mrr, state.mrr);
 
             let old_rep = state.reputation;
             state.reputation += rep_gain;
             effects.push(StatEffect {
-                stat_name: "Reputation".to_string(),
+                stat: Stat::Reputation,
                 old_value: old_rep,
                 new_value: state.reputation,
                 delta: rep_gain,
             });
 
-            // Create new self-serve customers based on WAU gain
-            let new_customer_count = (wau_gain / 5.0).ceil() as usize;
-            for _ in 0..new_customer_count {
-                let customer = generate_customer_persona(super::customers::CustomerSegment::SelfServe, state.week, state);
-                state.add_customer(customer);
-            }
+            record_acquisition(state, AcquisitionChannel::Content, 0.0, funnel.paid, funnel.new_mrr);
 
             ActionResult {
                 success: true,
@@ -649,45 +744,41 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
                 DevRelEvent::Podcast => 8.0,
                 DevRelEvent::OpenSource => 6.0,
                 DevRelEvent::Workshop => 10.0,
-            } * (0.9 + rand::random::<f64>() * 0.2);
+            } * (0.9 + rng.random::<f64>() * 0.2) * effectiveness.output;
 
-            let wau_gain = rep_gain * 0.5 * (0.8 + rand::random::<f64>() * 0.4);
+            let visitors = rep_gain * 10.0 * (0.8 + rng.random::<f64>() * 0.4);
 
             let old_rep = state.reputation;
             state.reputation += rep_gain;
             effects.push(StatEffect {
-                stat_name: "Reputation".to_string(),
+                stat: Stat::Reputation,
                 old_value: old_rep,
                 new_value: state.reputation,
                 delta: rep_gain,
             });
 
+            // Developers who hear the talk try the product; a few bring their team
             let old_wau = state.wau;
-            state.wau = (state.wau as f64 + wau_gain) as u32;
+            let old_mrr = state.mrr;
+            let funnel = run_funnel(state, visitors, 0.2, rng);
             effects.push(StatEffect {
-                stat_name: "WAU".to_string(),
+                stat: Stat::Wau,
                 old_value: old_wau as f64,
                 new_value: state.wau as f64,
-                delta: wau_gain,
+                delta: funnel.activated,
             });
+            push_mrr_effect(&mut effects, old_mrr, state.mrr);
 
-            let morale_boost = 5.0 * (0.9 + rand::random::<f64>() * 0.2);
+            let morale_boost = 5.0 * (0.9 + rng.random::<f64>() * 0.2);
             let old_morale = state.morale;
             state.morale += morale_boost;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: morale_boost,
             });
 
-            // Create new developer customers based on reputation gain
-            let new_customer_count = (rep_gain / 3.0).ceil() as usize;
-            for _ in 0..new_customer_count {
-                let customer = generate_customer_persona(super::customers::CustomerSegment::SelfServe, state.week, state);
-                state.add_customer(customer);
-            }
-
             ActionResult {
                 success: true,
                 message: message.to_string(),
@@ -699,46 +790,39 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             let message = format!("Ran ads on {} with ${:.0} budget", format!("{:?}", channel).to_lowercase(), budget);
 
             let market_saturation = 20.0; // Placeholder, could be calculated from state
-            let wau_gain = calculate_ad_effectiveness(channel, *budget, market_saturation);
+            let visitors = calculate_ad_effectiveness(channel, *budget, market_saturation, rng) * effectiveness.output;
+
+            // Share of ad conversions that are small businesses rather than self-serve
+            let smb_share = match channel {
+                AdChannel::Google | AdChannel::Display => 0.2,
+                AdChannel::Social => 0.1,
+                AdChannel::Influencer => 0.3,
+            };
 
             let old_wau = state.wau;
-            state.wau = (state.wau as f64 + wau_gain) as u32;
+            let old_mrr = state.mrr;
+            let funnel = run_funnel(state, visitors, smb_share, rng);
             effects.push(StatEffect {
-                stat_name: "WAU".to_string(),
+                stat: Stat::Wau,
                 old_value: old_wau as f64,
                 new_value: state.wau as f64,
-                delta: wau_gain,
+                delta: funnel.activated,
             });
+            push_mrr_effect(&mut effects, old_mrr, state.mrr);
 
             let old_bank = state.bank;
             state.bank -= budget;
             effects.push(StatEffect {
-                stat_name: "Bank".to_string(),
+                stat: Stat::Bank,
                 old_value: old_bank,
                 new_value: state.bank,
                 delta: -budget,
             });
 
-            // Create new customers based on ad channel and WAU gain
-            let (selfserve_ratio, smb_ratio) = match channel {
-                AdChannel::Google | AdChannel::Display => (0.8, 0.2),
-                AdChannel::Social => (0.9, 0.1),
-                AdChannel::Influencer => (0.7, 0.3),
-            };
-
-            let new_customer_count = (wau_gain / 10.0).ceil() as usize;
-            for _ in 0..new_customer_count {
-                let segment = if rng.gen_bool(selfserve_ratio) {
-                    super::customers::CustomerSegment::SelfServe
-                } else {
-                    super::customers::CustomerSegment::SMB
-                };
-                let customer = generate_customer_persona(segment, state.week, state);
-                state.add_customer(customer);
-            }
+            record_acquisition(state, AcquisitionChannel::Ads(channel.clone()), *budget, funnel.paid, funnel.new_mrr);
 
             ActionResult {
-                success: wau_gain > 0.0,
+                success: visitors > 0.0,
                 message,
                 effects,
             }
@@ -754,21 +838,21 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
                 CoachingFocus::Performance => (0.1, 3.0),
             };
 
-            let velocity_gain = velocity_boost * (0.9 + rand::random::<f64>() * 0.2);
+            let velocity_gain = velocity_boost * (0.9 + rng.random::<f64>() * 0.2) * effectiveness.output;
             let old_velocity = state.velocity;
             state.velocity += velocity_gain;
             effects.push(StatEffect {
-                stat_name: "Velocity".to_string(),
+                stat: Stat::Velocity,
                 old_value: old_velocity,
                 new_value: state.velocity,
                 delta: velocity_gain,
             });
 
-            let morale_gain = morale_boost * (0.9 + rand::random::<f64>() * 0.2);
+            let morale_gain = morale_boost * (0.9 + rng.random::<f64>() * 0.2) * effectiveness.output;
             let old_morale = state.morale;
             state.morale += morale_gain;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: morale_gain,
@@ -781,41 +865,45 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             }
         }
 
-        Action::Fire { reason } => {
-            let message = format!("Fired employee for {}", format!("{:?}", reason).to_lowercase());
-
-            let burn_reduction = 8000.0 * (0.8 + rand::random::<f64>() * 0.4); // Assume average salary
+        Action::Fire { employee_id, reason } => {
             let old_burn = state.burn;
-            state.burn -= burn_reduction;
+            let old_velocity = state.velocity;
+            let before = team_velocity(&state.team);
+            let employee = match team::fire(state, *employee_id) {
+                Ok(employee) => employee,
+                Err(message) => return ActionResult { success: false, message, effects },
+            };
+            let message = format!("Let {} go for {}", employee.name, format!("{:?}", reason).to_lowercase());
             effects.push(StatEffect {
-                stat_name: "Monthly Burn".to_string(),
+                stat: Stat::Burn,
                 old_value: old_burn,
                 new_value: state.burn,
-                delta: -burn_reduction,
+                delta: -employee.salary,
             });
 
+            // Their share of the product work leaves with them, and the disruption costs a little more
             let (morale_hit, velocity_hit): (f64, f64) = match reason {
-                FiringReason::Performance => (-8.0, -0.05),
-                FiringReason::Culture => (-12.0, -0.08),
-                FiringReason::Budget => (-5.0, -0.02),
+                FiringReason::Performance => (-8.0, -0.02),
+                FiringReason::Culture => (-12.0, -0.04),
+                FiringReason::Budget => (-5.0, 0.0),
             };
+            apply_velocity_change(state, before);
 
             let old_morale = state.morale;
             state.morale += morale_hit;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: morale_hit,
             });
 
-            let old_velocity = state.velocity;
             state.velocity += velocity_hit;
             effects.push(StatEffect {
-                stat_name: "Velocity".to_string(),
+                stat: Stat::Velocity,
                 old_value: old_velocity,
                 new_value: state.velocity,
-                delta: velocity_hit,
+                delta: state.velocity - old_velocity,
             });
 
             ActionResult {
@@ -828,21 +916,21 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
         Action::ComplianceWork { hours } => {
             let message = format!("Spent {} hours on compliance work", hours);
 
-            let risk_reduction = (*hours as f64) * 2.0 * (0.9 + rand::random::<f64>() * 0.2);
+            let risk_reduction = (*hours as f64) * 2.0 * (0.9 + rng.random::<f64>() * 0.2) * effectiveness.output;
             let old_risk = state.compliance_risk;
             state.compliance_risk -= risk_reduction;
             effects.push(StatEffect {
-                stat_name: "Compliance Risk".to_string(),
+                stat: Stat::ComplianceRisk,
                 old_value: old_risk,
                 new_value: state.compliance_risk,
                 delta: -risk_reduction,
             });
 
-            let morale_cost = (*hours as f64) * 0.3 * (0.9 + rand::random::<f64>() * 0.2);
+            let morale_cost = (*hours as f64) * 0.3 * (0.9 + rng.random::<f64>() * 0.2);
             let old_morale = state.morale;
             state.morale -= morale_cost;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: -morale_cost,
@@ -858,21 +946,21 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
         Action::IncidentResponse => {
             let message = "Responded to incident - contained damage";
 
-            let rep_loss = 5.0 * (0.8 + rand::random::<f64>() * 0.4); // Mitigated loss
+            let rep_loss = 5.0 * (0.8 + rng.random::<f64>() * 0.4) / effectiveness.output; // Mitigated loss
             let old_rep = state.reputation;
             state.reputation -= rep_loss;
             effects.push(StatEffect {
-                stat_name: "Reputation".to_string(),
+                stat: Stat::Reputation,
                 old_value: old_rep,
                 new_value: state.reputation,
                 delta: -rep_loss,
             });
 
-            let morale_cost = 15.0 * (0.9 + rand::random::<f64>() * 0.2);
+            let morale_cost = 15.0 * (0.9 + rng.random::<f64>() * 0.2);
             let old_morale = state.morale;
             state.morale -= morale_cost;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: -morale_cost,
@@ -888,22 +976,22 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
         Action::ProcessImprovement => {
             let message = "Implemented process improvements";
 
-            let velocity_boost = 0.08 * (0.9 + rand::random::<f64>() * 0.2);
+            let velocity_boost = 0.08 * (0.9 + rng.random::<f64>() * 0.2) * effectiveness.output;
             let old_velocity = state.velocity;
             state.velocity += velocity_boost;
             effects.push(StatEffect {
-                stat_name: "Velocity".to_string(),
+                stat: Stat::Velocity,
                 old_value: old_velocity,
                 new_value: state.velocity,
                 delta: velocity_boost,
             });
 
             // Reduce future incident probability (not directly modeled, but morale boost)
-            let morale_boost = 3.0 * (0.9 + rand::random::<f64>() * 0.2);
+            let morale_boost = 3.0 * (0.9 + rng.random::<f64>() * 0.2);
             let old_morale = state.morale;
             state.morale += morale_boost;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: morale_boost,
@@ -923,7 +1011,7 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             let old_morale = state.morale;
             state.morale += 15.0;
             effects.push(StatEffect {
-                stat_name: "Morale".to_string(),
+                stat: Stat::Morale,
                 old_value: old_morale,
                 new_value: state.morale,
                 delta: 15.0,
@@ -934,7 +1022,7 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
             let old_wau_growth = state.wau_growth_rate;
             state.wau_growth_rate -= momentum_loss;
             effects.push(StatEffect {
-                stat_name: "WAU Growth".to_string(),
+                stat: Stat::WauGrowth,
                 old_value: old_wau_growth,
                 new_value: state.wau_growth_rate,
                 delta: -momentum_loss,
@@ -952,6 +1040,8 @@ pub fn resolve_action(state: &mut GameState, action: &Action) -> ActionResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::cap_table::CapTable;
+    use crate::game::customers::CustomerSegment;
     use crate::game::state::DifficultyMode;
 
     #[test]
@@ -961,20 +1051,40 @@ mod tests {
         let initial_debt = state.tech_debt;
 
         let action = Action::ShipFeature { quality: Quality::Quick };
-        let result = resolve_action(&mut state, &action);
+        let mut rng = state.next_rng();
+        let result = resolve_action(&mut state, &action, &Effectiveness::default(), &mut rng);
 
         assert!(result.success);
         assert!(state.wau > initial_wau);
         assert!(state.tech_debt > initial_debt);
     }
 
+    #[test]
+    fn test_ship_keeps_the_roster_in_velocity() {
+        let mut solo = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let mut staffed = solo.clone();
+        let mut engineer = team::Employee::new_hire(staffed.next_employee_id, "Mei Tanaka".to_string(), team::Role::Engineer, team::Seniority::Senior, 14_000.0, 85.0);
+        engineer.ramp_up_weeks = 0;
+        team::hire(&mut staffed, engineer);
+
+        let ship = Action::ShipFeature { quality: Quality::Balanced };
+        let mut rng = solo.next_rng();
+        resolve_action(&mut solo, &ship, &Effectiveness::default(), &mut rng);
+        let mut rng = staffed.next_rng();
+        resolve_action(&mut staffed, &ship, &Effectiveness::default(), &mut rng);
+
+        assert!(staffed.velocity > solo.velocity);
+        assert!((staffed.velocity - team::roster_velocity(&staffed)).abs() < 1e-9);
+    }
+
     #[test]
     fn test_founder_led_sales() {
         let mut state = GameState::new(DifficultyMode::IndieBootstrap);
         let initial_mrr = state.mrr;
 
         let action = Action::FounderLedSales { call_count: 5 };
-        let result = resolve_action(&mut state, &action);
+        let mut rng = state.next_rng();
+        let result = resolve_action(&mut state, &action, &Effectiveness::default(), &mut rng);
 
         // MRR might increase (probabilistic)
         assert!(state.mrr >= initial_mrr);
@@ -982,17 +1092,78 @@ mod tests {
     }
 
     #[test]
-    fn test_hire() {
-        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+    fn test_founder_led_sales_same_seed_same_outcome() {
+        let mut a = GameState::with_seed(DifficultyMode::IndieBootstrap, 11);
+        let mut b = GameState::with_seed(DifficultyMode::IndieBootstrap, 11);
+        let action = Action::FounderLedSales { call_count: 5 };
+
+        let mut rng_a = a.next_rng();
+        resolve_action(&mut a, &action, &Effectiveness::default(), &mut rng_a);
+        let mut rng_b = b.next_rng();
+        resolve_action(&mut b, &action, &Effectiveness::default(), &mut rng_b);
+
+        assert_eq!(a.mrr, b.mrr);
+        assert_eq!(
+            a.customers.iter().map(|c| c.id.clone()).collect::<Vec<_>>(),
+            b.customers.iter().map(|c| c.id.clone()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_change_price_reprices_existing_customers() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 2);
+        add_signups(&mut state, CustomerSegment::SelfServe, 490.0);
+        let action = Action::ChangePrice { tier: PlanTier::Pro, change: PriceChange::Cut };
+
+        let mut rng = state.next_rng();
+        let result = resolve_action(&mut state, &action, &Effectiveness::default(), &mut rng);
+
+        assert!(result.success);
+        assert!(state.pricing.pro.list_price < crate::game::pricing::MARKET_PRO_PRICE);
+        assert!(state.mrr < 490.0);
+        assert_eq!(state.mrr_bridge.contraction, 490.0 - state.mrr);
+
+        let free = Action::ChangePrice { tier: PlanTier::Free, change: PriceChange::Raise };
+        assert!(!resolve_action(&mut state, &free, &Effectiveness::default(), &mut rng).success);
+    }
+
+    #[test]
+    fn test_interview_then_hire() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 4);
+        state.cap_table = CapTable::from_ownership(90.0, 10.0);
+        state.option_pool = state.cap_table.option_pool_ownership();
+        let candidate = state.candidates[0].clone();
+        let team_size = state.team_size();
         let initial_burn = state.burn;
-        let initial_velocity = state.velocity;
+        let mut rng = state.next_rng();
 
-        let action = Action::Hire;
-        let result = resolve_action(&mut state, &action);
+        let offer = Action::Hire { candidate_id: candidate.id, offer: OfferTerms::AboveAsk };
+        assert!(!resolve_action(&mut state, &offer, &Effectiveness::default(), &mut rng).success);
+        let interview = Action::Interview { candidate_id: candidate.id };
+        assert!(resolve_action(&mut state, &interview, &Effectiveness::default(), &mut rng).success);
+        state.candidates[0].interest = 100.0;
+        let result = resolve_action(&mut state, &offer, &Effectiveness::default(), &mut rng);
 
         assert!(result.success);
         assert!(state.burn > initial_burn);
-        assert!(state.velocity > initial_velocity);
+        assert_eq!(state.team_size(), team_size + 1);
+        assert_eq!(state.team.last().unwrap().role, candidate.role);
+        assert!(state.option_pool < 10.0);
+    }
+
+    #[test]
+    fn test_fire_targets_the_named_employee() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 4);
+        let target = state.team[2].clone();
+        let action = Action::Fire { employee_id: target.id, reason: FiringReason::Budget };
+        let mut rng = state.next_rng();
+
+        let result = resolve_action(&mut state, &action, &Effectiveness::default(), &mut rng);
+
+        assert!(result.success);
+        assert!(result.message.contains(&target.name));
+        assert!(state.team.iter().all(|e| e.id != target.id));
+        assert!(!resolve_action(&mut state, &action, &Effectiveness::default(), &mut rng).success);
     }
 
     #[test]
@@ -1001,7 +1172,8 @@ mod tests {
         state.morale = 50.0;
 
         let action = Action::TakeBreak;
-        let result = resolve_action(&mut state, &action);
+        let mut rng = state.next_rng();
+        let result = resolve_action(&mut state, &action, &Effectiveness::default(), &mut rng);
 
         assert!(result.success);
         assert!(state.morale > 50.0);
@@ -1010,7 +1182,7 @@ mod tests {
     #[test]
     fn test_focus_costs() {
         assert_eq!(Action::ShipFeature { quality: Quality::Quick }.focus_cost(), 1);
-        assert_eq!(Action::Hire.focus_cost(), 2);
+        assert_eq!(Action::Hire { candidate_id: 1, offer: OfferTerms::AtAsk }.focus_cost(), 2);
         assert_eq!(Action::TakeBreak.focus_cost(), 1);
     }
 }
@@ -0,0 +1,326 @@
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use super::cohorts::adjust_segment_mrr;
+use super::customers::{Customer, CustomerLifecycle, CustomerSegment};
+use super::effects::StatEffect;
+use super::ledger::LedgerCategory;
+use super::mrr_bridge::{record_mrr, record_mrr_change, MrrMovement};
+use super::pricing::PlanTier;
+use super::rng::GameRng;
+use super::state::GameState;
+
+/// Length of an annual contract
+pub const CONTRACT_WEEKS: u32 = 52;
+/// Weeks before renewal that the customer starts negotiating
+const RENEWAL_NOTICE_WEEKS: u32 = 4;
+/// Price change a renewal discount gives and an uplift asks for
+const RENEWAL_DISCOUNT: f64 = 0.1;
+const RENEWAL_UPLIFT: f64 = 0.15;
+/// How the chance of renewing moves with the terms offered
+const DISCOUNT_RENEWAL_BONUS: f64 = 0.2;
+const UPLIFT_RENEWAL_PENALTY: f64 = 0.2;
+
+/// How often a customer pays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BillingCycle {
+    /// Billed every month; cash and revenue arrive together
+    Monthly,
+    /// Prepaid for a year; revenue is earned week by week from the cash collected
+    Annual,
+}
+
+/// Terms the player puts on the table for upcoming renewals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RenewalDecision {
+    /// Renew at today's list price
+    ListPrice,
+    /// Give a discount to make renewing easy
+    OfferDiscount,
+    /// Ask for more than list price, at the risk of losing the account
+    PushUplift,
+}
+
+impl RenewalDecision {
+    fn price_factor(&self) -> f64 {
+        match self {
+            RenewalDecision::ListPrice => 1.0,
+            RenewalDecision::OfferDiscount => 1.0 - RENEWAL_DISCOUNT,
+            RenewalDecision::PushUplift => 1.0 + RENEWAL_UPLIFT,
+        }
+    }
+
+    fn renewal_bonus(&self) -> f64 {
+        match self {
+            RenewalDecision::ListPrice => 0.0,
+            RenewalDecision::OfferDiscount => DISCOUNT_RENEWAL_BONUS,
+            RenewalDecision::PushUplift => -UPLIFT_RENEWAL_PENALTY,
+        }
+    }
+}
+
+/// A year of service paid upfront
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contract {
+    pub customer_id: String,
+    pub company: String,
+    pub segment: CustomerSegment,
+    pub tier: PlanTier,
+    /// Seats or usage units bought
+    pub units: f64,
+    /// Week of the customer's cohort, which holds the contract's revenue
+    pub join_week: u32,
+    pub start_week: u32,
+    pub renewal_week: u32,
+    /// Cash collected for the year
+    pub annual_value: f64,
+    /// Cash collected but not yet earned
+    pub deferred_revenue: f64,
+    /// Terms agreed for the coming renewal, if negotiated
+    pub renewal_terms: Option<RenewalDecision>,
+}
+
+impl Contract {
+    /// Revenue the contract contributes to MRR
+    pub fn monthly_value(&self) -> f64 {
+        self.annual_value / 12.0
+    }
+
+    fn is_due_for_renewal(&self, week: u32) -> bool {
+        self.renewal_week.saturating_sub(week) <= RENEWAL_NOTICE_WEEKS
+    }
+}
+
+/// How a customer is billed
+pub fn billing_cycle(state: &GameState, customer_id: &str) -> BillingCycle {
+    if state.contracts.iter().any(|c| c.customer_id == customer_id) {
+        BillingCycle::Annual
+    } else {
+        BillingCycle::Monthly
+    }
+}
+
+/// Sign a customer to a year upfront and bank the cash
+///
+/// The customer's MRR is the contract's monthly value; their revenue is added
+/// to the cohorts by the caller like any other new business.
+pub fn sign_annual_contract(state: &mut GameState, customer: &Customer, tier: PlanTier, units: f64) {
+    let annual_value = customer.mrr_contribution * 12.0;
+    state.contracts.push(Contract {
+        customer_id: customer.id.clone(),
+        company: customer.company.clone(),
+        segment: customer.segment.clone(),
+        tier,
+        units,
+        join_week: customer.join_week,
+        start_week: state.week,
+        renewal_week: state.week + CONTRACT_WEEKS,
+        annual_value,
+        deferred_revenue: annual_value,
+        renewal_terms: None,
+    });
+    state.bank += annual_value;
+    let reason = format!("Annual contract: {}", customer.company);
+    state.ledger.record(state.week, LedgerCategory::DeferredRevenue, annual_value, &reason);
+}
+
+/// MRR under annual contract, optionally only one cohort's segment
+pub fn contracted_mrr(state: &GameState, cohort: Option<(u32, &CustomerSegment)>) -> f64 {
+    state
+        .contracts
+        .iter()
+        .filter(|c| cohort.is_none_or(|(week, segment)| c.join_week == week && c.segment == *segment))
+        .map(Contract::monthly_value)
+        .sum()
+}
+
+/// Cash collected for service not yet delivered
+pub fn deferred_revenue(state: &GameState) -> f64 {
+    state.contracts.iter().map(|c| c.deferred_revenue).sum()
+}
+
+/// Contracts coming up for renewal with no terms agreed, for event triggers
+pub fn renewals_due(state: &GameState) -> usize {
+    state
+        .contracts
+        .iter()
+        .filter(|c| c.renewal_terms.is_none() && c.is_due_for_renewal(state.week))
+        .count()
+}
+
+/// Earn a week of every contract's deferred revenue
+///
+/// No cash moves: the ledger shows revenue earned and the deferred balance
+/// drawn down by the same amount.
+pub fn recognize_revenue(state: &mut GameState) {
+    let mut recognized = 0.0;
+    for contract in &mut state.contracts {
+        let week_of_service = (contract.annual_value / CONTRACT_WEEKS as f64).min(contract.deferred_revenue);
+        contract.deferred_revenue -= week_of_service;
+        recognized += week_of_service;
+    }
+    let reason = "Annual contract revenue earned";
+    state.ledger.record(state.week, LedgerCategory::Revenue, recognized, reason);
+    state.ledger.record(state.week, LedgerCategory::DeferredRevenue, -recognized, reason);
+}
+
+/// Apply a dilemma's terms to every contract waiting on a renewal
+pub fn apply_renewal_decision(state: &mut GameState, decision: RenewalDecision) -> Vec<StatEffect> {
+    let week = state.week;
+    for contract in state.contracts.iter_mut().filter(|c| c.renewal_terms.is_none() && c.is_due_for_renewal(week)) {
+        contract.renewal_terms = Some(decision);
+    }
+    Vec::new()
+}
+
+/// Settle every contract that reaches its renewal date this week
+///
+/// Happier customers renew more often. A renewal reprices the contract at
+/// today's list price and the agreed terms, and collects the next year upfront;
+/// a lost renewal takes the account's revenue out of its cohort at once.
+pub fn renew_contracts(state: &mut GameState, rng: &mut GameRng) {
+    let week = state.week;
+    let (due, mut contracts): (Vec<Contract>, Vec<Contract>) =
+        std::mem::take(&mut state.contracts).into_iter().partition(|c| c.renewal_week <= week);
+
+    let mut lost_mrr = 0.0;
+    let mut repriced_mrr = 0.0;
+    for mut contract in due {
+        let terms = contract.renewal_terms.unwrap_or(RenewalDecision::ListPrice);
+        let customer = state.customers.iter().position(|c| c.id == contract.customer_id);
+        let chance = customer.map_or(0.0, |index| state.customers[index].satisfaction / 100.0 + terms.renewal_bonus());
+        let old_value = contract.monthly_value();
+
+        if rng.random_bool(chance.clamp(0.0, 1.0)) {
+            let plan = state.pricing.plan(contract.tier);
+            contract.annual_value = plan.annual_price(contract.units) * terms.price_factor();
+            contract.deferred_revenue += contract.annual_value;
+            contract.start_week = week;
+            contract.renewal_week = week + CONTRACT_WEEKS;
+            contract.renewal_terms = None;
+
+            let delta = contract.monthly_value() - old_value;
+            repriced_mrr += move_cohort_mrr(state, &contract, delta);
+            if let Some(index) = customer {
+                state.customers[index].mrr_contribution = contract.monthly_value();
+            }
+            state.bank += contract.annual_value;
+            let reason = format!("Annual renewal: {}", contract.company);
+            state.ledger.record(state.week, LedgerCategory::DeferredRevenue, contract.annual_value, &reason);
+            contracts.push(contract);
+        } else {
+            lost_mrr -= move_cohort_mrr(state, &contract, -old_value);
+            if let Some(index) = customer {
+                let mut churned = state.customers.remove(index);
+                churned.lifecycle_stage = CustomerLifecycle::Churned;
+                state.lapsed_customers.push(churned);
+            }
+        }
+    }
+    state.contracts = contracts;
+
+    record_mrr_change(state, repriced_mrr);
+    record_mrr(state, MrrMovement::Churned, lost_mrr);
+}
+
+/// Move a contract's revenue in its cohort; returns the change actually made
+fn move_cohort_mrr(state: &mut GameState, contract: &Contract, delta: f64) -> f64 {
+    let old_mrr = state.mrr;
+    adjust_segment_mrr(state, contract.join_week, contract.segment.clone(), delta);
+    state.mrr - old_mrr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::cohorts::{add_signups, retain_cohorts};
+    use crate::game::customers::generate_customer_persona;
+    use crate::game::ledger::settle_week;
+    use crate::game::state::DifficultyMode;
+
+    fn signed_game() -> GameState {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let mut rng = state.next_rng();
+        let mut customer = generate_customer_persona(CustomerSegment::Enterprise, state.week, &state, &mut rng);
+        customer.mrr_contribution = 3_000.0;
+        add_signups(&mut state, CustomerSegment::Enterprise, customer.mrr_contribution);
+        sign_annual_contract(&mut state, &customer, PlanTier::Enterprise, 120.0);
+        state.add_customer(customer);
+        state
+    }
+
+    #[test]
+    fn test_annual_contract_collects_cash_upfront() {
+        let bank = GameState::with_seed(DifficultyMode::IndieBootstrap, 4).bank;
+        let state = signed_game();
+
+        assert_eq!(state.bank, bank + 36_000.0);
+        assert_eq!(deferred_revenue(&state), 36_000.0);
+        assert_eq!(state.mrr, 3_000.0);
+        assert_eq!(billing_cycle(&state, &state.customers[0].id), BillingCycle::Annual);
+    }
+
+    #[test]
+    fn test_revenue_is_earned_without_cash() {
+        let mut state = signed_game();
+        state.week = 1;
+        state.burn = 0.0;
+        let bank = state.bank;
+
+        settle_week(&mut state);
+
+        // Prepaid customers don't pay again; their revenue comes out of the deferred balance
+        assert_eq!(state.bank, bank);
+        assert!((deferred_revenue(&state) - 36_000.0 * 51.0 / 52.0).abs() < 1e-6);
+        let pnl = state.ledger.profit_and_loss(1, 1);
+        assert!((pnl.revenue - 36_000.0 / 52.0).abs() < 1e-6);
+        assert!((pnl.net_cash_flow).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_contracted_revenue_does_not_churn_mid_term() {
+        let mut state = signed_game();
+        state.churn_rate = 40.0;
+
+        retain_cohorts(&mut state);
+
+        assert_eq!(state.mrr, 3_000.0);
+    }
+
+    #[test]
+    fn test_renewal_terms_decide_the_outcome() {
+        let mut state = signed_game();
+        state.week = CONTRACT_WEEKS - 2;
+        assert_eq!(renewals_due(&state), 1);
+
+        apply_renewal_decision(&mut state, RenewalDecision::OfferDiscount);
+        assert_eq!(renewals_due(&state), 0);
+
+        // A delighted customer offered a discount always renews, at the discounted list price
+        state.week = CONTRACT_WEEKS;
+        state.customers[0].satisfaction = 100.0;
+        let mut rng = state.next_rng();
+        renew_contracts(&mut state, &mut rng);
+
+        let expected = state.pricing.enterprise.annual_price(120.0) * 0.9;
+        assert!((state.contracts[0].annual_value - expected).abs() < 1e-6);
+        assert!((state.mrr - expected / 12.0).abs() < 1e-6);
+        assert_eq!(state.contracts[0].renewal_week, CONTRACT_WEEKS * 2);
+    }
+
+    #[test]
+    fn test_lost_renewal_churns_the_account() {
+        let mut state = signed_game();
+        state.week = CONTRACT_WEEKS;
+        state.customers[0].satisfaction = 0.0;
+        apply_renewal_decision(&mut state, RenewalDecision::PushUplift);
+
+        let mut rng = state.next_rng();
+        renew_contracts(&mut state, &mut rng);
+
+        assert!(state.contracts.is_empty());
+        assert!(state.mrr.abs() < 1e-6);
+        assert!(state.customers.is_empty());
+        assert_eq!(state.lapsed_customers.len(), 1);
+        assert!((state.mrr_bridge.churned - 3_000.0).abs() < 1e-6);
+    }
+}
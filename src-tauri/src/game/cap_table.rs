@@ -0,0 +1,505 @@
+use serde::{Deserialize, Serialize};
+use super::effects::{Stat, StatEffect};
+use super::market_conditions::Effectiveness;
+use super::state::GameState;
+
+pub const FOUNDER: &str = "Founder";
+pub const OPTION_POOL: &str = "Option Pool";
+/// Holder for equity handed out outside priced rounds (grants, partnerships)
+pub const OTHER_HOLDERS: &str = "Other holders";
+/// Smallest stake, in percent, that dilution outside priced rounds leaves the founder
+pub const MIN_FOUNDER_OWNERSHIP: f64 = 1.0;
+
+/// Shares the founder starts with; only ratios matter
+const FOUNDER_SHARES: u64 = 10_000_000;
+/// Option pool investors expect to exist after each priced round, in percent
+const TARGET_OPTION_POOL: f64 = 10.0;
+/// Weeks back used to measure month-over-month MRR growth
+const GROWTH_WINDOW_WEEKS: usize = 4;
+/// Discount to the next round's price that SAFEs and notes convert at
+const CONVERSION_DISCOUNT: f64 = 0.20;
+/// Simple annual interest a convertible note accrues until it converts
+const NOTE_INTEREST_RATE: f64 = 0.08;
+
+/// How a raise is structured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Instrument {
+    /// Shares sold now at an agreed pre-money valuation
+    #[default]
+    PricedRound,
+    /// Simple agreement for future equity: converts at the next priced round
+    Safe,
+    /// Debt that converts at the next priced round, with interest
+    ConvertibleNote,
+}
+
+impl Instrument {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Instrument::PricedRound => "priced round",
+            Instrument::Safe => "SAFE",
+            Instrument::ConvertibleNote => "convertible note",
+        }
+    }
+
+    fn holder_name(&self) -> &'static str {
+        match self {
+            Instrument::PricedRound => "Investors",
+            Instrument::Safe => "SAFE holders",
+            Instrument::ConvertibleNote => "Noteholders",
+        }
+    }
+}
+
+/// Money raised on a SAFE or note that has not turned into shares yet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Convertible {
+    pub instrument: Instrument,
+    pub principal: f64,
+    pub valuation_cap: f64,
+    pub discount: f64,
+    pub interest_rate: f64,
+    pub issued_week: u32,
+}
+
+impl Convertible {
+    /// Principal plus simple interest accrued by `week`
+    pub fn conversion_amount(&self, week: u32) -> f64 {
+        let years = week.saturating_sub(self.issued_week) as f64 / 52.0;
+        self.principal * (1.0 + self.interest_rate * years)
+    }
+}
+
+/// A convertible as it turned into shares at a priced round
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conversion {
+    pub instrument: Instrument,
+    pub amount: f64,
+    pub price_per_share: f64,
+    pub shares: u64,
+}
+
+/// Terms of money coming in, from a `Fundraise` or a dilemma choice
+///
+/// `valuation` is the pre-money for a priced round and the cap for a SAFE or note.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Financing {
+    pub instrument: Instrument,
+    pub amount: f64,
+    pub valuation: f64,
+}
+
+/// Kind of stock a holder owns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareClass {
+    Common,
+    Preferred,
+    /// Reserved for employee grants
+    Options,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shareholder {
+    pub name: String,
+    pub class: ShareClass,
+    pub shares: u64,
+}
+
+/// One priced round as it was closed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PricedRound {
+    pub name: String,
+    pub week: u32,
+    pub pre_money: f64,
+    pub amount_raised: f64,
+    pub post_money: f64,
+    pub price_per_share: f64,
+    pub shares_issued: u64,
+    /// New option pool shares created before the round, at the existing holders' expense
+    pub pool_top_up: u64,
+    /// SAFEs and notes that converted just before the new money came in
+    pub conversions: Vec<Conversion>,
+    pub founder_ownership: f64,
+}
+
+/// Who owns the company; founder equity and option pool are read from here
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapTable {
+    pub shareholders: Vec<Shareholder>,
+    pub rounds: Vec<PricedRound>,
+    /// SAFEs and notes waiting for the next priced round
+    pub convertibles: Vec<Convertible>,
+}
+
+impl Default for CapTable {
+    fn default() -> Self {
+        Self::founding()
+    }
+}
+
+impl CapTable {
+    /// The founder owns everything
+    pub fn founding() -> Self {
+        Self {
+            shareholders: vec![Shareholder {
+                name: FOUNDER.to_string(),
+                class: ShareClass::Common,
+                shares: FOUNDER_SHARES,
+            }],
+            rounds: Vec::new(),
+            convertibles: Vec::new(),
+        }
+    }
+
+    /// Rebuild a table matching ownership percentages, for games that predate cap tables
+    pub fn from_ownership(founder: f64, option_pool: f64) -> Self {
+        let founder = founder.clamp(1.0, 100.0);
+        let option_pool = option_pool.clamp(0.0, 100.0 - founder);
+        let total = FOUNDER_SHARES as f64 * 100.0 / founder;
+
+        let mut table = Self::founding();
+        table.issue(OPTION_POOL, ShareClass::Options, (total * option_pool / 100.0).round() as u64);
+        table.issue(OTHER_HOLDERS, ShareClass::Preferred, (total - table.total_shares() as f64).round().max(0.0) as u64);
+        table
+    }
+
+    pub fn total_shares(&self) -> u64 {
+        self.shareholders.iter().map(|h| h.shares).sum()
+    }
+
+    pub fn shares_of(&self, name: &str) -> u64 {
+        self.shareholders.iter().filter(|h| h.name == name).map(|h| h.shares).sum()
+    }
+
+    /// Fully diluted ownership in percent
+    pub fn ownership(&self, name: &str) -> f64 {
+        let total = self.total_shares();
+        if total == 0 {
+            return 0.0;
+        }
+        self.shares_of(name) as f64 / total as f64 * 100.0
+    }
+
+    pub fn founder_ownership(&self) -> f64 {
+        self.ownership(FOUNDER)
+    }
+
+    pub fn option_pool_ownership(&self) -> f64 {
+        self.ownership(OPTION_POOL)
+    }
+
+    fn issue(&mut self, name: &str, class: ShareClass, shares: u64) {
+        if shares == 0 {
+            return;
+        }
+        match self.shareholders.iter_mut().find(|h| h.name == name) {
+            Some(holder) => holder.shares += shares,
+            None => self.shareholders.push(Shareholder { name: name.to_string(), class, shares }),
+        }
+    }
+
+    /// Total still owed in shares to SAFE and note holders at `week`
+    pub fn outstanding_convertibles(&self, week: u32) -> f64 {
+        self.convertibles.iter().map(|c| c.conversion_amount(week)).sum()
+    }
+
+    /// Record a SAFE or note; nobody's ownership changes until the next priced round
+    pub fn issue_convertible(&mut self, instrument: Instrument, week: u32, principal: f64, valuation_cap: f64) -> Convertible {
+        let convertible = Convertible {
+            instrument,
+            principal,
+            valuation_cap,
+            discount: CONVERSION_DISCOUNT,
+            interest_rate: if instrument == Instrument::ConvertibleNote { NOTE_INTEREST_RATE } else { 0.0 },
+            issued_week: week,
+        };
+        self.convertibles.push(convertible.clone());
+        convertible
+    }
+
+    /// Turn every outstanding convertible into shares ahead of a round priced at `pre_money`
+    ///
+    /// Each converts at the lower of its cap price and the discounted round price.
+    fn convert_outstanding(&mut self, week: u32, pre_money: f64) -> Vec<Conversion> {
+        let existing = self.total_shares() as f64;
+        let round_price = pre_money / existing;
+        let conversions: Vec<Conversion> = self
+            .convertibles
+            .drain(..)
+            .map(|c| {
+                let amount = c.conversion_amount(week);
+                let price_per_share = (c.valuation_cap / existing).min(round_price * (1.0 - c.discount));
+                Conversion {
+                    instrument: c.instrument,
+                    amount,
+                    price_per_share,
+                    shares: (amount / price_per_share).round() as u64,
+                }
+            })
+            .collect();
+
+        for conversion in &conversions {
+            self.issue(conversion.instrument.holder_name(), ShareClass::Preferred, conversion.shares);
+        }
+        conversions
+    }
+
+    /// Close a priced round, converting SAFEs and notes and topping the option pool up first
+    ///
+    /// Conversions and the top-up are both counted in the pre-money, so they
+    /// dilute existing holders and not the new investors (the "option pool shuffle").
+    pub fn price_round(&mut self, week: u32, pre_money: f64, amount: f64) -> PricedRound {
+        let name = round_name(self.rounds.len());
+        let conversions = self.convert_outstanding(week, pre_money);
+        let post_money = pre_money + amount;
+        let investor_fraction = amount / post_money;
+        let pool_fraction = TARGET_OPTION_POOL / 100.0;
+
+        let existing = self.total_shares() as f64;
+        let pool = self.shares_of(OPTION_POOL) as f64;
+        let total_without_top_up = existing / (1.0 - investor_fraction);
+        let needs_top_up = pool < pool_fraction * total_without_top_up && investor_fraction + pool_fraction < 1.0;
+        let (total_after, pool_top_up) = if needs_top_up {
+            let total = (existing - pool) / (1.0 - investor_fraction - pool_fraction);
+            (total, (pool_fraction * total - pool).round() as u64)
+        } else {
+            (total_without_top_up, 0)
+        };
+
+        let shares_issued = (investor_fraction * total_after).round() as u64;
+        self.issue(OPTION_POOL, ShareClass::Options, pool_top_up);
+        self.issue(&format!("{} investors", name), ShareClass::Preferred, shares_issued);
+
+        let round = PricedRound {
+            name,
+            week,
+            pre_money,
+            amount_raised: amount,
+            post_money,
+            price_per_share: pre_money / (self.total_shares() - shares_issued) as f64,
+            shares_issued,
+            pool_top_up,
+            conversions,
+            founder_ownership: self.founder_ownership(),
+        };
+        self.rounds.push(round.clone());
+        round
+    }
+
+    /// Issue shares to other holders until the founder owns `ownership` percent
+    ///
+    /// Only dilution is modelled; asking for a larger stake leaves the table unchanged.
+    pub fn dilute_founder_to(&mut self, ownership: f64) {
+        let ownership = ownership.max(MIN_FOUNDER_OWNERSHIP);
+        if ownership >= self.founder_ownership() {
+            return;
+        }
+        let total_after = self.shares_of(FOUNDER) as f64 * 100.0 / ownership;
+        let new_shares = (total_after - self.total_shares() as f64).round() as u64;
+        self.issue(OTHER_HOLDERS, ShareClass::Common, new_shares);
+    }
+
+    /// Issue `name` a fresh `percent` stake, diluting every holder by the same factor
+    ///
+    /// The stake is trimmed so the founder keeps at least `MIN_FOUNDER_OWNERSHIP`.
+    pub fn issue_stake(&mut self, name: &str, percent: f64) {
+        let founder = self.founder_ownership();
+        let most = (100.0 * (1.0 - MIN_FOUNDER_OWNERSHIP / founder)).max(0.0);
+        let percent = percent.clamp(0.0, most);
+        let new_shares = (self.total_shares() as f64 * percent / (100.0 - percent)).round() as u64;
+        self.issue(name, ShareClass::Common, new_shares);
+    }
+
+    /// Grant an employee `percent` of the company out of the option pool
+    ///
+    /// Grants never dilute anyone: they move shares the pool already holds, so
+    /// an empty pool grants less. Returns the percent actually granted.
+    pub fn grant_options(&mut self, name: &str, percent: f64) -> f64 {
+        let total = self.total_shares();
+        let wanted = (total as f64 * percent.max(0.0) / 100.0).round() as u64;
+        let shares = wanted.min(self.shares_of(OPTION_POOL));
+        if shares == 0 {
+            return 0.0;
+        }
+        if let Some(pool) = self.shareholders.iter_mut().find(|h| h.name == OPTION_POOL) {
+            pool.shares -= shares;
+        }
+        self.issue(name, ShareClass::Options, shares);
+        shares as f64 / total as f64 * 100.0
+    }
+}
+
+/// Bank the money and update the cap table; returns the stat changes and a summary
+pub fn close_financing(state: &mut GameState, financing: &Financing) -> (Vec<StatEffect>, String) {
+    let old_bank = state.bank;
+    let old_equity = state.founder_equity;
+    state.bank += financing.amount;
+
+    let summary = match financing.instrument {
+        Instrument::PricedRound => {
+            let round = state.cap_table.price_round(state.week, financing.valuation, financing.amount);
+            let converted = if round.conversions.is_empty() {
+                String::new()
+            } else {
+                format!(" {} SAFE/note investment(s) converted first.", round.conversions.len())
+            };
+            format!(
+                "Raised ${:.0} in a {} round at ${:.1}M pre-money.{}",
+                financing.amount, round.name, financing.valuation / 1_000_000.0, converted
+            )
+        }
+        Instrument::Safe | Instrument::ConvertibleNote => {
+            let convertible = state.cap_table.issue_convertible(financing.instrument, state.week, financing.amount, financing.valuation);
+            format!(
+                "Raised ${:.0} on a {} capped at ${:.1}M with a {:.0}% discount. It converts at your next priced round.",
+                financing.amount, financing.instrument.label(), convertible.valuation_cap / 1_000_000.0, convertible.discount * 100.0
+            )
+        }
+    };
+    state.founder_equity = state.cap_table.founder_ownership();
+    state.option_pool = state.cap_table.option_pool_ownership();
+
+    let mut effects = vec![StatEffect {
+        stat: Stat::Bank,
+        old_value: old_bank,
+        new_value: state.bank,
+        delta: financing.amount,
+    }];
+    if state.founder_equity != old_equity {
+        effects.push(StatEffect {
+            stat: Stat::FounderEquity,
+            old_value: old_equity,
+            new_value: state.founder_equity,
+            delta: state.founder_equity - old_equity,
+        });
+    }
+    (effects, summary)
+}
+
+/// Conventional name for the nth priced round
+pub fn round_name(index: usize) -> String {
+    match index {
+        0 => "Seed".to_string(),
+        n => format!("Series {}", (b'A' + ((n - 1) % 26) as u8) as char),
+    }
+}
+
+/// Month-over-month MRR growth in percent, or 0 without a month of history
+pub fn mrr_growth_rate(state: &GameState) -> f64 {
+    let history = &state.history;
+    if history.len() < GROWTH_WINDOW_WEEKS {
+        return 0.0;
+    }
+    let month_ago = history[history.len() - GROWTH_WINDOW_WEEKS].mrr;
+    if month_ago <= 0.0 {
+        return 0.0;
+    }
+    (state.mrr / month_ago - 1.0) * 100.0
+}
+
+/// What investors will price the company at before their money goes in
+///
+/// Revenue is valued as a multiple of ARR that grows with MRR growth; before
+/// there is meaningful revenue the price rests on reputation and usage.
+/// Hot markets lift the whole number.
+pub fn pre_money_valuation(state: &GameState, effectiveness: &Effectiveness) -> f64 {
+    let arr = state.mrr * 12.0;
+    let multiple = (8.0 + mrr_growth_rate(state)).clamp(5.0, 40.0);
+    let floor = 1_000_000.0 + state.reputation * 30_000.0 + state.wau as f64 * 100.0;
+    (arr * multiple).max(floor) * effectiveness.output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_round_tops_up_pool_before_investors() {
+        let mut table = CapTable::founding();
+
+        let round = table.price_round(10, 4_000_000.0, 1_000_000.0);
+
+        // Investors get 20% of post-money and the pool is 10%, both out of the founder's stake
+        assert_eq!(round.name, "Seed");
+        assert!((table.ownership("Seed investors") - 20.0).abs() < 0.01);
+        assert!((table.option_pool_ownership() - 10.0).abs() < 0.01);
+        assert!((table.founder_ownership() - 70.0).abs() < 0.01);
+        assert!((round.price_per_share * round.shares_issued as f64 - 1_000_000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_repeated_rounds_never_drive_equity_negative() {
+        let mut table = CapTable::founding();
+        for week in 0..30 {
+            table.price_round(week, 1_000_000.0, 1_000_000.0);
+        }
+
+        assert!(table.founder_ownership() > 0.0);
+        assert_eq!(table.rounds[3].name, "Series C");
+        let total: f64 = table.shareholders.iter().map(|h| table.ownership(&h.name)).sum();
+        assert!((total - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_safe_converts_at_cap_in_next_round() {
+        let mut table = CapTable::founding();
+        table.issue_convertible(Instrument::Safe, 0, 500_000.0, 5_000_000.0);
+        assert_eq!(table.founder_ownership(), 100.0);
+
+        // The round prices the company at 10M, so the 5M cap beats the 20% discount
+        let round = table.price_round(20, 10_000_000.0, 2_000_000.0);
+
+        assert!(table.convertibles.is_empty());
+        assert_eq!(round.conversions.len(), 1);
+        assert!((round.conversions[0].price_per_share - 0.5).abs() < 1e-9);
+        assert_eq!(table.shares_of("SAFE holders"), 1_000_000);
+        assert!(table.founder_ownership() < 100.0 * (1.0 - 2.0 / 12.0 - 0.1));
+    }
+
+    #[test]
+    fn test_note_accrues_interest_until_conversion() {
+        let mut table = CapTable::founding();
+        let note = table.issue_convertible(Instrument::ConvertibleNote, 0, 100_000.0, 50_000_000.0);
+
+        assert!((note.conversion_amount(52) - 108_000.0).abs() < 1e-6);
+        let round = table.price_round(52, 10_000_000.0, 1_000_000.0);
+        // A high cap leaves the discount as the better price
+        assert!((round.conversions[0].price_per_share - 0.8).abs() < 1e-9);
+        assert_eq!(round.conversions[0].shares, 135_000);
+    }
+
+    #[test]
+    fn test_dilute_founder_to_target() {
+        let mut table = CapTable::founding();
+
+        table.dilute_founder_to(80.0);
+        assert!((table.founder_ownership() - 80.0).abs() < 0.01);
+
+        table.dilute_founder_to(90.0);
+        assert!((table.founder_ownership() - 80.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_issue_stake_dilutes_proportionally() {
+        let mut table = CapTable::from_ownership(80.0, 10.0);
+
+        table.issue_stake("Partner", 30.0);
+
+        assert!((table.founder_ownership() - 56.0).abs() < 0.01);
+        assert!((table.option_pool_ownership() - 7.0).abs() < 0.01);
+        assert!((table.shares_of("Partner") as f64 / table.total_shares() as f64 - 0.30).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_grants_come_out_of_the_pool() {
+        let mut table = CapTable::from_ownership(90.0, 10.0);
+
+        assert!((table.grant_options("Mei Tanaka", 1.5) - 1.5).abs() < 0.01);
+        assert!((table.option_pool_ownership() - 8.5).abs() < 0.01);
+        assert!((table.founder_ownership() - 90.0).abs() < 0.01);
+
+        // Only what is left in the pool can be granted
+        assert!((table.grant_options("Kofi Mensah", 20.0) - 8.5).abs() < 0.01);
+        assert_eq!(table.grant_options("Zoe Walsh", 1.0), 0.0);
+    }
+}
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use super::event_library::EventLibrary;
+use super::events_enhanced::{EventChoice, GameEvent};
+use super::rng::GameRng;
+use super::state::GameState;
+
+/// A follow-up event queued `delay_weeks` after the event or choice that leads to it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FollowUp {
+    pub event_id: String,
+    pub delay_weeks: u32,
+}
+
+/// A follow-up waiting for its week to come around
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledEvent {
+    pub event_id: String,
+    pub due_week: u32,
+    /// Event whose outcome queued this one
+    pub source_event_id: String,
+}
+
+/// Queue a follow-up relative to the current week
+pub fn schedule_follow_up(state: &mut GameState, source_event_id: &str, follow_up: &FollowUp) {
+    state.scheduled_events.push(ScheduledEvent {
+        event_id: follow_up.event_id.clone(),
+        due_week: state.week + follow_up.delay_weeks,
+        source_event_id: source_event_id.to_string(),
+    });
+}
+
+/// Remember which branch the player took and queue where it leads
+pub fn record_branch(state: &mut GameState, event: &GameEvent, choice: &EventChoice) {
+    state.story_branches.insert(event.id.clone(), choice.label.clone());
+    if let Some(follow_up) = &choice.follow_up {
+        schedule_follow_up(state, &event.id, follow_up);
+    }
+}
+
+/// Build every follow-up that has come due, dropping it from the queue
+///
+/// Follow-ups skip their template's probability, and the trigger too unless
+/// the event only arrives as a follow-up; they also fail when the event needs
+/// a subject that no longer exists.
+pub fn take_due_events(state: &mut GameState, library: &EventLibrary, rng: &mut GameRng) -> Vec<GameEvent> {
+    let week = state.week;
+    let (due, pending): (Vec<_>, Vec<_>) = state.scheduled_events.drain(..).partition(|s| s.due_week <= week);
+    state.scheduled_events = pending;
+
+    due.iter()
+        .filter_map(|scheduled| library.get(&scheduled.event_id)?.fire_follow_up(state, rng))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::events_enhanced::{check_for_events, EnhancedEventType};
+    use crate::game::state::DifficultyMode;
+
+    fn vc_offer(state: &mut GameState) -> GameEvent {
+        let mut rng = state.next_rng();
+        EventLibrary::bundled().get("vc_offer").unwrap().fire(state, &mut rng).unwrap()
+    }
+
+    #[test]
+    fn test_choice_schedules_follow_up() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        let event = vc_offer(&mut state);
+        let choices = match &event.event_type {
+            EnhancedEventType::Dilemma { choices } => choices,
+            _ => panic!("vc_offer should be a dilemma"),
+        };
+
+        record_branch(&mut state, &event, &choices[0]);
+
+        assert_eq!(state.story_branches.get("vc_offer"), Some(&choices[0].label));
+        assert_eq!(state.scheduled_events.len(), 1);
+        assert_eq!(state.scheduled_events[0].event_id, "board_pressure");
+        assert_eq!(state.scheduled_events[0].due_week, state.week + 13);
+    }
+
+    #[test]
+    fn test_follow_up_fires_when_due() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        state.story_branches.insert("vc_offer".to_string(), "Take the Money - Growth Mode".to_string());
+        schedule_follow_up(&mut state, "vc_offer", &FollowUp { event_id: "board_pressure".to_string(), delay_weeks: 2 });
+        let mut rng = state.next_rng();
+
+        state.week += 1;
+        assert!(!check_for_events(&mut state, &mut rng).iter().any(|e| e.id == "board_pressure"));
+
+        state.week += 1;
+        assert!(check_for_events(&mut state, &mut rng).iter().any(|e| e.id == "board_pressure"));
+        assert!(state.scheduled_events.is_empty());
+    }
+
+    #[test]
+    fn test_board_pressure_skipped_after_turning_investors_down() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        let event = vc_offer(&mut state);
+        let choices = match &event.event_type {
+            EnhancedEventType::Dilemma { choices } => choices,
+            _ => panic!("vc_offer should be a dilemma"),
+        };
+        record_branch(&mut state, &event, &choices[0]);
+        // A later offer turned down overwrites the branch before the board meets
+        state.story_branches.insert("vc_offer".to_string(), choices[1].label.clone());
+        let mut rng = state.next_rng();
+
+        state.week += 13;
+        assert!(!check_for_events(&mut state, &mut rng).iter().any(|e| e.id == "board_pressure"));
+        assert!(state.scheduled_events.is_empty());
+    }
+
+    #[test]
+    fn test_follow_up_fires_only_on_its_branch() {
+        let library = EventLibrary::from_json(r#"[{
+            "id": "enterprise_payoff",
+            "title": "The enterprise bet pays off",
+            "description": "",
+            "probability": 0,
+            "cooldown_weeks": 0,
+            "trigger": {"branch": {"chain": "pivot", "choice": "Go enterprise"}},
+            "effects": []
+        }]"#).unwrap();
+        let follow_up = FollowUp { event_id: "enterprise_payoff".to_string(), delay_weeks: 0 };
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        let mut rng = state.next_rng();
+
+        state.story_branches.insert("pivot".to_string(), "Stay self-serve".to_string());
+        schedule_follow_up(&mut state, "pivot", &follow_up);
+        assert!(take_due_events(&mut state, &library, &mut rng).is_empty());
+
+        state.story_branches.insert("pivot".to_string(), "Go enterprise".to_string());
+        schedule_follow_up(&mut state, "pivot", &follow_up);
+        assert_eq!(take_due_events(&mut state, &library, &mut rng)[0].id, "enterprise_payoff");
+    }
+}
@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+use super::billing::contracted_mrr;
+use super::customers::{calculate_segment_from_mrr, CustomerSegment};
+use super::mrr_bridge::{record_mrr, MrrMovement};
+use super::pricing::churn_factor;
+use super::state::GameState;
+
+/// Weeks of retention history kept per cohort
+const COHORT_HISTORY_WEEKS: usize = 52;
+/// How quickly the early-life churn bump fades, in weeks
+const EARLY_CHURN_WEEKS: f64 = 4.0;
+/// Onboarding quality a new game starts with
+pub const STARTING_ONBOARDING_QUALITY: f64 = 50.0;
+
+/// Revenue from one customer segment within a cohort
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentRevenue {
+    pub segment: CustomerSegment,
+    /// MRR signed up in the cohort's first week
+    pub initial_mrr: f64,
+    pub mrr: f64,
+}
+
+/// Users and revenue a cohort still had at the end of a week
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CohortWeek {
+    pub users: f64,
+    pub mrr: f64,
+}
+
+/// Everyone who signed up in the same week
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cohort {
+    pub join_week: u32,
+    /// Onboarding quality when the cohort signed up; it shapes their early churn
+    pub onboarding_quality: f64,
+    pub initial_users: f64,
+    pub users: f64,
+    pub revenue: Vec<SegmentRevenue>,
+    /// End-of-week totals, one per week since signup
+    pub history: Vec<CohortWeek>,
+}
+
+impl Cohort {
+    fn new(join_week: u32, onboarding_quality: f64) -> Self {
+        Self { join_week, onboarding_quality, initial_users: 0.0, users: 0.0, revenue: Vec::new(), history: Vec::new() }
+    }
+
+    pub fn initial_mrr(&self) -> f64 {
+        self.revenue.iter().map(|r| r.initial_mrr).sum()
+    }
+
+    pub fn mrr(&self) -> f64 {
+        self.revenue.iter().map(|r| r.mrr).sum()
+    }
+
+    fn segment_mut(&mut self, segment: CustomerSegment) -> &mut SegmentRevenue {
+        match self.revenue.iter().position(|r| r.segment == segment) {
+            Some(index) => &mut self.revenue[index],
+            None => {
+                self.revenue.push(SegmentRevenue { segment, initial_mrr: 0.0, mrr: 0.0 });
+                self.revenue.last_mut().unwrap()
+            }
+        }
+    }
+}
+
+/// One row of the cohort table: retention by week since signup, in percent
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CohortRow {
+    pub join_week: u32,
+    pub initial_users: f64,
+    pub initial_mrr: f64,
+    pub user_retention: Vec<f64>,
+    /// Can pass 100% when expansion outgrows churn
+    pub revenue_retention: Vec<f64>,
+}
+
+/// Larger customers churn less than self-serve ones
+fn segment_churn_factor(segment: &CustomerSegment) -> f64 {
+    match segment {
+        CustomerSegment::Enterprise => 0.4,
+        CustomerSegment::SMB => 0.8,
+        CustomerSegment::SelfServe => 1.2,
+    }
+}
+
+/// Multiplier on the baseline churn for a cohort this week
+///
+/// New signups churn most and good onboarding shrinks that early bump; happy
+/// customers and a stable product keep every cohort around longer.
+fn churn_multiplier(state: &GameState, cohort: &Cohort) -> f64 {
+    let age = state.week.saturating_sub(cohort.join_week) as f64;
+    let onboarding_gap = 1.5 - cohort.onboarding_quality / 100.0;
+    let age_factor = 1.0 + onboarding_gap * (-age / EARLY_CHURN_WEEKS).exp();
+    let quality_factor = (1.0 - state.nps / 200.0 + state.tech_debt / 200.0).max(0.2);
+    age_factor * quality_factor
+}
+
+/// Monthly churn, in percent, for one segment of a cohort this week
+pub fn cohort_churn_rate(state: &GameState, cohort: &Cohort, segment: &CustomerSegment) -> f64 {
+    state.churn_rate * segment_churn_factor(segment) * churn_factor(state, segment) * churn_multiplier(state, cohort)
+}
+
+/// The founding cohort a new game starts with
+pub fn founding_cohort(users: f64) -> Cohort {
+    let mut cohort = Cohort::new(0, STARTING_ONBOARDING_QUALITY);
+    cohort.initial_users = users;
+    cohort.users = users;
+    cohort
+}
+
+/// One founding cohort holding every user and dollar, for games saved before cohorts
+pub fn legacy_cohort(users: f64, mrr: f64) -> Cohort {
+    let mut cohort = founding_cohort(users);
+    if mrr > 0.0 {
+        cohort.revenue.push(SegmentRevenue { segment: CustomerSegment::SMB, initial_mrr: mrr, mrr });
+    }
+    cohort
+}
+
+fn cohort_for_week(state: &mut GameState, week: u32) -> &mut Cohort {
+    let index = match state.cohorts.iter().position(|c| c.join_week >= week) {
+        Some(index) if state.cohorts[index].join_week == week => index,
+        Some(index) => {
+            state.cohorts.insert(index, Cohort::new(week, state.onboarding_quality));
+            index
+        }
+        None => {
+            state.cohorts.push(Cohort::new(week, state.onboarding_quality));
+            state.cohorts.len() - 1
+        }
+    };
+    &mut state.cohorts[index]
+}
+
+/// Set MRR and WAU from the cohorts
+fn sync_totals(state: &mut GameState) {
+    state.mrr = state.cohorts.iter().map(Cohort::mrr).sum();
+    state.wau = state.cohorts.iter().map(|c| c.users).sum::<f64>().round() as u32;
+}
+
+/// New paying customers join this week's cohort
+pub fn add_signups(state: &mut GameState, segment: CustomerSegment, mrr: f64) {
+    let week = state.week;
+    let revenue = cohort_for_week(state, week).segment_mut(segment);
+    revenue.initial_mrr += mrr;
+    revenue.mrr += mrr;
+    sync_totals(state);
+}
+
+/// A returning customer's revenue goes back to the cohort they first joined
+pub fn reactivate(state: &mut GameState, join_week: u32, segment: CustomerSegment, mrr: f64) {
+    cohort_for_week(state, join_week).segment_mut(segment).mrr += mrr;
+    sync_totals(state);
+}
+
+/// New users join this week's cohort
+pub fn add_users(state: &mut GameState, users: f64) {
+    let week = state.week;
+    let cohort = cohort_for_week(state, week);
+    cohort.initial_users += users;
+    cohort.users += users;
+    sync_totals(state);
+}
+
+/// Spread a change in MRR across every cohort in proportion to what it pays
+///
+/// With no revenue yet, a gain can only come from new customers this week.
+pub fn adjust_mrr(state: &mut GameState, delta: f64) {
+    let total: f64 = state.cohorts.iter().map(Cohort::mrr).sum();
+    if total > 0.0 {
+        let factor = ((total + delta) / total).max(0.0);
+        for revenue in state.cohorts.iter_mut().flat_map(|c| &mut c.revenue) {
+            revenue.mrr *= factor;
+        }
+        sync_totals(state);
+    } else if delta > 0.0 {
+        add_signups(state, calculate_segment_from_mrr(delta), delta);
+    }
+}
+
+/// Scale the revenue of matching segments in every cohort, returning the change in MRR
+pub fn reprice_segments(state: &mut GameState, matches: impl Fn(&CustomerSegment) -> bool, factor: f64) -> f64 {
+    let old_mrr = state.mrr;
+    let mut cohorts = std::mem::take(&mut state.cohorts);
+    for cohort in &mut cohorts {
+        for revenue in cohort.revenue.iter_mut().filter(|r| matches(&r.segment)) {
+            // Annual contracts keep their price until renewal
+            let locked = contracted_mrr(state, Some((cohort.join_week, &revenue.segment))).min(revenue.mrr);
+            revenue.mrr = locked + (revenue.mrr - locked) * factor;
+        }
+    }
+    state.cohorts = cohorts;
+    sync_totals(state);
+    state.mrr - old_mrr
+}
+
+/// Move one segment of one cohort's MRR, as when a contract renews or lapses
+pub fn adjust_segment_mrr(state: &mut GameState, join_week: u32, segment: CustomerSegment, delta: f64) {
+    let revenue = cohort_for_week(state, join_week).segment_mut(segment);
+    revenue.mrr = (revenue.mrr + delta).max(0.0);
+    sync_totals(state);
+}
+
+/// Gained users are new signups; lost users leave every cohort in proportion
+pub fn adjust_users(state: &mut GameState, delta: f64) {
+    let total: f64 = state.cohorts.iter().map(|c| c.users).sum();
+    if delta >= 0.0 {
+        add_users(state, delta);
+    } else if total > 0.0 {
+        let factor = ((total + delta) / total).max(0.0);
+        for cohort in &mut state.cohorts {
+            cohort.users *= factor;
+        }
+        sync_totals(state);
+    }
+}
+
+/// Age every cohort by a week along its retention curve
+///
+/// MRR and WAU are the cohort totals. Revenue under annual contract is only
+/// lost when a renewal falls through.
+pub fn retain_cohorts(state: &mut GameState) {
+    let mut cohorts = std::mem::take(&mut state.cohorts);
+    let mut churned_mrr = 0.0;
+    for cohort in &mut cohorts {
+        let multiplier = churn_multiplier(state, cohort);
+        for revenue in &mut cohort.revenue {
+            let segment_factor = segment_churn_factor(&revenue.segment) * churn_factor(state, &revenue.segment);
+            let weekly = (state.churn_rate * segment_factor * multiplier / 400.0).clamp(0.0, 1.0);
+            let at_risk = (revenue.mrr - contracted_mrr(state, Some((cohort.join_week, &revenue.segment)))).max(0.0);
+            churned_mrr += at_risk * weekly;
+            revenue.mrr -= at_risk * weekly;
+        }
+        let weekly_users = (state.churn_rate * multiplier / 400.0).clamp(0.0, 1.0);
+        cohort.users -= cohort.users * weekly_users;
+
+        cohort.history.push(CohortWeek { users: cohort.users, mrr: cohort.mrr() });
+        if cohort.history.len() > COHORT_HISTORY_WEEKS {
+            cohort.history.remove(0);
+        }
+    }
+    state.cohorts = cohorts;
+    record_mrr(state, MrrMovement::Churned, churned_mrr);
+    sync_totals(state);
+}
+
+/// Retention by cohort for the UI, oldest cohort first
+pub fn cohort_table(state: &GameState) -> Vec<CohortRow> {
+    let percent = |value: f64, initial: f64| if initial > 0.0 { value / initial * 100.0 } else { 0.0 };
+    state
+        .cohorts
+        .iter()
+        .map(|cohort| {
+            let initial_mrr = cohort.initial_mrr();
+            CohortRow {
+                join_week: cohort.join_week,
+                initial_users: cohort.initial_users,
+                initial_mrr,
+                user_retention: cohort.history.iter().map(|w| percent(w.users, cohort.initial_users)).collect(),
+                revenue_retention: cohort.history.iter().map(|w| percent(w.mrr, initial_mrr)).collect(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::DifficultyMode;
+
+    fn new_game() -> GameState {
+        GameState::with_seed(DifficultyMode::IndieBootstrap, 6)
+    }
+
+    #[test]
+    fn test_mrr_and_wau_come_from_cohorts() {
+        let mut state = new_game();
+        state.week = 3;
+        add_signups(&mut state, CustomerSegment::SMB, 1_000.0);
+        add_users(&mut state, 50.0);
+
+        assert_eq!(state.mrr, 1_000.0);
+        assert_eq!(state.wau, 150);
+        assert_eq!(state.cohorts.iter().map(|c| c.join_week).collect::<Vec<_>>(), vec![0, 3]);
+
+        reactivate(&mut state, 1, CustomerSegment::SelfServe, 100.0);
+        assert_eq!(state.cohorts.iter().map(|c| c.join_week).collect::<Vec<_>>(), vec![0, 1, 3]);
+        assert_eq!(state.mrr, 1_100.0);
+    }
+
+    #[test]
+    fn test_enterprise_retains_better_than_self_serve() {
+        let mut state = new_game();
+        add_signups(&mut state, CustomerSegment::Enterprise, 1_000.0);
+        add_signups(&mut state, CustomerSegment::SelfServe, 1_000.0);
+
+        for week in 1..=8 {
+            state.week = week;
+            retain_cohorts(&mut state);
+        }
+
+        let cohort = &state.cohorts[0];
+        assert!(cohort.revenue[0].mrr > cohort.revenue[1].mrr);
+        assert!(state.mrr < 2_000.0);
+        assert_eq!(cohort.history.len(), 8);
+    }
+
+    #[test]
+    fn test_onboarding_and_nps_lower_churn() {
+        let state = new_game();
+        let rough = Cohort::new(0, 20.0);
+        let smooth = Cohort::new(0, 90.0);
+        let segment = CustomerSegment::SMB;
+        assert!(cohort_churn_rate(&state, &smooth, &segment) < cohort_churn_rate(&state, &rough, &segment));
+
+        let mut loved = new_game();
+        loved.nps = 60.0;
+        assert!(cohort_churn_rate(&loved, &rough, &segment) < cohort_churn_rate(&state, &rough, &segment));
+    }
+
+    #[test]
+    fn test_cohort_table_reports_retention() {
+        let mut state = new_game();
+        add_signups(&mut state, CustomerSegment::SMB, 1_000.0);
+        state.week = 1;
+        retain_cohorts(&mut state);
+
+        let table = cohort_table(&state);
+
+        assert_eq!(table[0].initial_mrr, 1_000.0);
+        assert_eq!(table[0].user_retention.len(), 1);
+        assert!(table[0].revenue_retention[0] < 100.0);
+    }
+}
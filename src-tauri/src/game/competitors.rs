@@ -1,7 +1,7 @@
 use rand::prelude::*;
+use super::rng::{GameRng, random_id};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Competitor {
@@ -20,7 +20,7 @@ pub struct Competitor {
     pub is_acquired: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum FundingStage {
     Bootstrapped,
     Seed,
@@ -30,7 +30,7 @@ pub enum FundingStage {
     PublicCompany,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PricingStrategy {
     Freemium,
     Undercut,
@@ -60,22 +60,20 @@ pub enum CompetitorActionType {
     PartnershipAnnouncement,
 }
 
-pub fn generate_competitors(difficulty: &super::DifficultyMode, week: u32) -> Vec<Competitor> {
-    let mut rng = rand::thread_rng();
+pub fn generate_competitors(difficulty: &super::DifficultyMode, week: u32, rng: &mut GameRng) -> Vec<Competitor> {
     let count = match difficulty {
-        super::DifficultyMode::IndieBootstrap => rng.gen_range(2..=3),
-        super::DifficultyMode::VCTrack => rng.gen_range(3..=4),
-        super::DifficultyMode::RegulatedFintech => rng.gen_range(2..=3),
-        super::DifficultyMode::InfraDevTool => rng.gen_range(3..=4),
+        super::DifficultyMode::IndieBootstrap => rng.random_range(2..=3),
+        super::DifficultyMode::VCTrack => rng.random_range(3..=4),
+        super::DifficultyMode::RegulatedFintech => rng.random_range(2..=3),
+        super::DifficultyMode::InfraDevTool => rng.random_range(3..=4),
     };
 
     (0..count)
-        .map(|_| generate_competitor_persona(difficulty, week))
+        .map(|_| generate_competitor_persona(difficulty, week, rng))
         .collect()
 }
 
-pub fn generate_competitor_name() -> (String, String) {
-    let mut rng = rand::thread_rng();
+pub fn generate_competitor_name(rng: &mut GameRng) -> (String, String) {
     let names = vec![
         "TechFlow", "DataSync", "CloudPulse", "NexusAI", "StreamLine",
         "VelocityHQ", "PulseMetrics", "FlowState", "SyncWave", "ApexTools",
@@ -93,33 +91,32 @@ pub fn generate_competitor_name() -> (String, String) {
         "Data-driven decisions",
     ];
 
-    let name = names[rng.gen_range(0..names.len())].to_string();
-    let tagline = taglines[rng.gen_range(0..taglines.len())].to_string();
+    let name = names[rng.random_range(0..names.len())].to_string();
+    let tagline = taglines[rng.random_range(0..taglines.len())].to_string();
     (name, tagline)
 }
 
-pub fn generate_competitor_persona(difficulty: &super::DifficultyMode, week: u32) -> Competitor {
-    let mut rng = rand::thread_rng();
-    let (name, tagline) = generate_competitor_name();
+pub fn generate_competitor_persona(difficulty: &super::DifficultyMode, week: u32, rng: &mut GameRng) -> Competitor {
+    let (name, tagline) = generate_competitor_name(rng);
 
-    let funding_stage = determine_funding_stage(difficulty);
-    let pricing_strategy = determine_pricing_strategy();
-    let total_funding = funding_stage_to_amount(&funding_stage);
+    let funding_stage = determine_funding_stage(difficulty, rng);
+    let pricing_strategy = determine_pricing_strategy(rng);
+    let total_funding = funding_stage_to_amount(&funding_stage, rng);
     let team_size = calculate_competitor_team_size(total_funding);
 
     let aggressiveness = match difficulty {
-        super::DifficultyMode::IndieBootstrap => rng.gen_range(0.3..=0.6),
-        super::DifficultyMode::VCTrack => rng.gen_range(0.5..=0.8),
-        super::DifficultyMode::RegulatedFintech => rng.gen_range(0.4..=0.7),
-        super::DifficultyMode::InfraDevTool => rng.gen_range(0.6..=0.9),
+        super::DifficultyMode::IndieBootstrap => rng.random_range(0.3..=0.6),
+        super::DifficultyMode::VCTrack => rng.random_range(0.5..=0.8),
+        super::DifficultyMode::RegulatedFintech => rng.random_range(0.4..=0.7),
+        super::DifficultyMode::InfraDevTool => rng.random_range(0.6..=0.9),
     };
 
     Competitor {
-        id: generate_competitor_id(),
+        id: generate_competitor_id(rng),
         name,
         tagline,
         funding_stage,
-        feature_parity: rng.gen_range(20.0..=60.0),
+        feature_parity: rng.random_range(20.0..=60.0),
         pricing_strategy,
         market_share: 0.0, // Will be calculated later
         aggressiveness,
@@ -131,28 +128,27 @@ pub fn generate_competitor_persona(difficulty: &super::DifficultyMode, week: u32
     }
 }
 
-pub fn determine_funding_stage(difficulty: &super::DifficultyMode) -> FundingStage {
-    let mut rng = rand::thread_rng();
+pub fn determine_funding_stage(difficulty: &super::DifficultyMode, rng: &mut GameRng) -> FundingStage {
     match difficulty {
         super::DifficultyMode::IndieBootstrap => {
-            if rng.gen_bool(0.7) { FundingStage::Bootstrapped } else { FundingStage::Seed }
+            if rng.random_bool(0.7) { FundingStage::Bootstrapped } else { FundingStage::Seed }
         },
         super::DifficultyMode::VCTrack => {
-            match rng.gen_range(0..3) {
+            match rng.random_range(0..3) {
                 0 => FundingStage::Seed,
                 1 => FundingStage::SeriesA,
                 _ => FundingStage::SeriesB,
             }
         },
         super::DifficultyMode::RegulatedFintech => {
-            match rng.gen_range(0..3) {
+            match rng.random_range(0..3) {
                 0 => FundingStage::SeriesA,
                 1 => FundingStage::SeriesB,
                 _ => FundingStage::SeriesC,
             }
         },
         super::DifficultyMode::InfraDevTool => {
-            match rng.gen_range(0..3) {
+            match rng.random_range(0..3) {
                 0 => FundingStage::Seed,
                 1 => FundingStage::SeriesA,
                 _ => FundingStage::SeriesB,
@@ -161,9 +157,8 @@ pub fn determine_funding_stage(difficulty: &super::DifficultyMode) -> FundingSta
     }
 }
 
-pub fn determine_pricing_strategy() -> PricingStrategy {
-    let mut rng = rand::thread_rng();
-    let roll = rng.gen_range(0..100);
+pub fn determine_pricing_strategy(rng: &mut GameRng) -> PricingStrategy {
+    let roll = rng.random_range(0..100);
     match roll {
         0..=39 => PricingStrategy::Freemium,
         40..=59 => PricingStrategy::Undercut,
@@ -173,15 +168,13 @@ pub fn determine_pricing_strategy() -> PricingStrategy {
     }
 }
 
-pub fn generate_competitor_action(competitor: &Competitor, state: &super::GameState) -> Option<CompetitorAction> {
-    let mut rng = rand::thread_rng();
-
+pub fn generate_competitor_action(competitor: &Competitor, state: &super::GameState, rng: &mut GameRng) -> Option<CompetitorAction> {
     // Probability based on aggressiveness
-    if !rng.gen_bool(competitor.aggressiveness * 0.3) {
+    if !rng.random_bool(competitor.aggressiveness * 0.3) {
         return None;
     }
 
-    let action_type = match rng.gen_range(0..8) {
+    let action_type = match rng.random_range(0..8) {
         0 => CompetitorActionType::FeatureLaunch,
         1 => CompetitorActionType::PricingChange,
         2 => CompetitorActionType::FundingRound,
@@ -193,16 +186,19 @@ pub fn generate_competitor_action(competitor: &Competitor, state: &super::GameSt
     };
 
     let (description, impact, amount) = match action_type {
-        CompetitorActionType::FeatureLaunch => (generate_feature_launch(competitor, state).0, generate_feature_launch(competitor, state).1, None),
+        CompetitorActionType::FeatureLaunch => {
+            let (desc, impact) = generate_feature_launch(competitor, state, rng);
+            (desc, impact, None)
+        },
         CompetitorActionType::PricingChange => (generate_pricing_change(competitor, state).0, generate_pricing_change(competitor, state).1, None),
         CompetitorActionType::FundingRound => {
-            let (desc, impact) = generate_funding_round(competitor);
+            let (desc, impact) = generate_funding_round(competitor, rng);
             let amount = Some(competitor.total_funding); // Use the competitor's total funding as the round amount
             (desc, impact, amount)
         },
         CompetitorActionType::Acquisition => {
-            let (desc, impact) = generate_acquisition_action(competitor);
-            let amount = Some(rand::thread_rng().gen_range(50..=200) as f64 * 1_000_000.0); // Random acquisition amount
+            let (desc, impact) = generate_acquisition_action(competitor, rng);
+            let amount = Some(rng.random_range(50..=200) as f64 * 1_000_000.0); // Random acquisition amount
             (desc, impact, amount)
         },
         CompetitorActionType::ProductPivot => ("Pivoted to a new market segment".to_string(), "Reduced competitive pressure".to_string(), None),
@@ -220,14 +216,13 @@ pub fn generate_competitor_action(competitor: &Competitor, state: &super::GameSt
     })
 }
 
-pub fn generate_feature_launch(competitor: &Competitor, state: &super::GameState) -> (String, String) {
+pub fn generate_feature_launch(competitor: &Competitor, state: &super::GameState, rng: &mut GameRng) -> (String, String) {
     let features = vec![
         "advanced analytics", "mobile app", "API integrations", "enterprise SSO",
         "real-time collaboration", "AI-powered insights", "automated workflows",
         "advanced security", "custom dashboards", "integrations marketplace"
     ];
-    let mut rng = rand::thread_rng();
-    let feature = features[rng.gen_range(0..features.len())];
+    let feature = features[rng.random_range(0..features.len())];
 
     let description = format!("Launched {} - a feature you don't have yet", feature);
     let impact = if state.velocity < 1.0 {
@@ -250,12 +245,12 @@ pub fn generate_pricing_change(competitor: &Competitor, state: &super::GameState
     (description, impact)
 }
 
-pub fn generate_funding_round(competitor: &Competitor) -> (String, String) {
+pub fn generate_funding_round(competitor: &Competitor, rng: &mut GameRng) -> (String, String) {
     let amount = match competitor.funding_stage {
-        FundingStage::Seed => rand::thread_rng().gen_range(1..=3),
-        FundingStage::SeriesA => rand::thread_rng().gen_range(5..=15),
-        FundingStage::SeriesB => rand::thread_rng().gen_range(20..=50),
-        _ => rand::thread_rng().gen_range(10..=30),
+        FundingStage::Seed => rng.random_range(1..=3),
+        FundingStage::SeriesA => rng.random_range(5..=15),
+        FundingStage::SeriesB => rng.random_range(20..=50),
+        _ => rng.random_range(10..=30),
     };
 
     let description = format!("Raised ${}M in funding", amount);
@@ -263,11 +258,10 @@ pub fn generate_funding_round(competitor: &Competitor) -> (String, String) {
     (description, impact)
 }
 
-pub fn generate_acquisition_action(competitor: &Competitor) -> (String, String) {
+pub fn generate_acquisition_action(competitor: &Competitor, rng: &mut GameRng) -> (String, String) {
     let acquirers = vec!["BigTech Corp", "Enterprise Solutions Inc", "Global Ventures", "Tech Giant Ltd"];
-    let mut rng = rand::thread_rng();
-    let acquirer = acquirers[rng.gen_range(0..acquirers.len())];
-    let amount = rand::thread_rng().gen_range(50..=200);
+    let acquirer = acquirers[rng.random_range(0..acquirers.len())];
+    let amount = rng.random_range(50..=200);
 
     let description = format!("Acquired by {} for ${}M", acquirer, amount);
     let impact = "Market consolidation may affect your positioning".to_string();
@@ -348,28 +342,27 @@ pub fn get_recently_funded_competitors(competitors: &[Competitor], current_week:
         .collect()
 }
 
-pub fn get_random_competitor(competitors: &[Competitor]) -> Option<&Competitor> {
+pub fn get_random_competitor<'a>(competitors: &'a [Competitor], rng: &mut GameRng) -> Option<&'a Competitor> {
     let active = competitors.iter().filter(|c| !c.is_acquired).collect::<Vec<_>>();
     if active.is_empty() {
         None
     } else {
-        let mut rng = rand::thread_rng();
-        Some(active[rng.gen_range(0..active.len())])
+        Some(active[rng.random_range(0..active.len())])
     }
 }
 
-pub fn generate_competitor_id() -> String {
-    Uuid::new_v4().to_string()
+pub fn generate_competitor_id(rng: &mut GameRng) -> String {
+    random_id(rng)
 }
 
-pub fn funding_stage_to_amount(stage: &FundingStage) -> f64 {
+pub fn funding_stage_to_amount(stage: &FundingStage, rng: &mut GameRng) -> f64 {
     match stage {
         FundingStage::Bootstrapped => 0.0,
-        FundingStage::Seed => rand::thread_rng().gen_range(500_000.0..=2_000_000.0),
-        FundingStage::SeriesA => rand::thread_rng().gen_range(5_000_000.0..=15_000_000.0),
-        FundingStage::SeriesB => rand::thread_rng().gen_range(20_000_000.0..=50_000_000.0),
-        FundingStage::SeriesC => rand::thread_rng().gen_range(50_000_000.0..=100_000_000.0),
-        FundingStage::PublicCompany => rand::thread_rng().gen_range(100_000_000.0..=500_000_000.0),
+        FundingStage::Seed => rng.random_range(500_000.0..=2_000_000.0),
+        FundingStage::SeriesA => rng.random_range(5_000_000.0..=15_000_000.0),
+        FundingStage::SeriesB => rng.random_range(20_000_000.0..=50_000_000.0),
+        FundingStage::SeriesC => rng.random_range(50_000_000.0..=100_000_000.0),
+        FundingStage::PublicCompany => rng.random_range(100_000_000.0..=500_000_000.0),
     }
 }
 
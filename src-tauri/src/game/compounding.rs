@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use super::state::GameState;
+use super::effects::{apply_effects, Effect, Stat, StatEffect};
 
 /// Compounding effects that reward long-term good practices
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,14 +18,7 @@ pub struct CompoundingBonus {
     pub effect_id: String,
     pub name: String,
     pub message: String,
-    pub bonuses: Vec<StatBonus>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StatBonus {
-    pub stat_name: String,
-    pub bonus_amount: f64,
-    pub is_multiplier: bool, // true = multiplier (1.2x), false = additive (+20)
+    pub bonuses: Vec<Effect>,
 }
 
 /// Check and apply compounding effects based on sustained good practices
@@ -53,16 +47,8 @@ pub fn check_compounding_effects(state: &GameState, history_weeks: usize) -> Vec
                     (bonus_strength * 10.0) as u32
                 ),
                 bonuses: vec![
-                    StatBonus {
-                        stat_name: "Velocity".to_string(),
-                        bonus_amount: 0.05 * bonus_strength,
-                        is_multiplier: true,
-                    },
-                    StatBonus {
-                        stat_name: "Morale".to_string(),
-                        bonus_amount: 5.0 * bonus_strength,
-                        is_multiplier: false,
-                    },
+                    Effect::boost(Stat::Velocity, 0.05 * bonus_strength),
+                    Effect::add(Stat::Morale, 5.0 * bonus_strength),
                 ],
             });
         }
@@ -87,16 +73,8 @@ pub fn check_compounding_effects(state: &GameState, history_weeks: usize) -> Vec
                     (bonus_strength * 20.0) as u32
                 ),
                 bonuses: vec![
-                    StatBonus {
-                        stat_name: "WAU Growth".to_string(),
-                        bonus_amount: 3.0 * bonus_strength,
-                        is_multiplier: false,
-                    },
-                    StatBonus {
-                        stat_name: "Churn Rate".to_string(),
-                        bonus_amount: -2.0 * bonus_strength,
-                        is_multiplier: false,
-                    },
+                    Effect::add(Stat::WauGrowth, 3.0 * bonus_strength),
+                    Effect::add(Stat::ChurnRate, -2.0 * bonus_strength),
                 ],
             });
         }
@@ -121,16 +99,8 @@ pub fn check_compounding_effects(state: &GameState, history_weeks: usize) -> Vec
                     (bonus_strength * 15.0) as u32
                 ),
                 bonuses: vec![
-                    StatBonus {
-                        stat_name: "Velocity".to_string(),
-                        bonus_amount: 0.1 * bonus_strength,
-                        is_multiplier: true,
-                    },
-                    StatBonus {
-                        stat_name: "Reputation".to_string(),
-                        bonus_amount: 5.0 * bonus_strength,
-                        is_multiplier: false,
-                    },
+                    Effect::boost(Stat::Velocity, 0.1 * bonus_strength),
+                    Effect::add(Stat::Reputation, 5.0 * bonus_strength),
                 ],
             });
         }
@@ -162,16 +132,8 @@ pub fn check_compounding_effects(state: &GameState, history_weeks: usize) -> Vec
                         (bonus_strength * 25.0) as u32
                     ),
                     bonuses: vec![
-                        StatBonus {
-                            stat_name: "Reputation".to_string(),
-                            bonus_amount: 10.0 * bonus_strength,
-                            is_multiplier: false,
-                        },
-                        StatBonus {
-                            stat_name: "Morale".to_string(),
-                            bonus_amount: 5.0 * bonus_strength,
-                            is_multiplier: false,
-                        },
+                        Effect::add(Stat::Reputation, 10.0 * bonus_strength),
+                        Effect::add(Stat::Morale, 5.0 * bonus_strength),
                     ],
                 });
             }
@@ -204,16 +166,8 @@ pub fn check_compounding_effects(state: &GameState, history_weeks: usize) -> Vec
                     (bonus_strength * 20.0) as u32
                 ),
                 bonuses: vec![
-                    StatBonus {
-                        stat_name: "WAU Growth".to_string(),
-                        bonus_amount: 2.0 * bonus_strength,
-                        is_multiplier: false,
-                    },
-                    StatBonus {
-                        stat_name: "Reputation".to_string(),
-                        bonus_amount: 8.0 * bonus_strength,
-                        is_multiplier: false,
-                    },
+                    Effect::add(Stat::WauGrowth, 2.0 * bonus_strength),
+                    Effect::add(Stat::Reputation, 8.0 * bonus_strength),
                 ],
             });
         }
@@ -238,16 +192,9 @@ pub fn check_compounding_effects(state: &GameState, history_weeks: usize) -> Vec
                     (bonus_strength * 15.0) as u32
                 ),
                 bonuses: vec![
-                    StatBonus {
-                        stat_name: "Morale Decay".to_string(),
-                        bonus_amount: -0.3 * bonus_strength,
-                        is_multiplier: false,
-                    },
-                    StatBonus {
-                        stat_name: "Velocity".to_string(),
-                        bonus_amount: 0.05 * bonus_strength,
-                        is_multiplier: true,
-                    },
+                    // Slower morale decay, paid out as a morale boost
+                    Effect::add(Stat::Morale, 0.6 * bonus_strength),
+                    Effect::boost(Stat::Velocity, 0.05 * bonus_strength),
                 ],
             });
         }
@@ -281,41 +228,8 @@ where
 }
 
 /// Apply compounding bonuses to game state
-pub fn apply_compounding_bonuses(state: &mut GameState, bonuses: &[CompoundingBonus]) {
-    for bonus in bonuses {
-        for stat_bonus in &bonus.bonuses {
-            match stat_bonus.stat_name.as_str() {
-                "Velocity" => {
-                    if stat_bonus.is_multiplier {
-                        state.velocity *= 1.0 + stat_bonus.bonus_amount;
-                    } else {
-                        state.velocity += stat_bonus.bonus_amount;
-                    }
-                }
-                "Morale" => {
-                    state.morale += stat_bonus.bonus_amount;
-                }
-                "WAU Growth" => {
-                    state.wau_growth_rate += stat_bonus.bonus_amount;
-                }
-                "Churn Rate" => {
-                    state.churn_rate += stat_bonus.bonus_amount; // Can be negative (reduction)
-                }
-                "Reputation" => {
-                    state.reputation += stat_bonus.bonus_amount;
-                }
-                "Morale Decay" => {
-                    // This would reduce the natural morale decay in advance_week
-                    // For now, apply as morale boost
-                    state.morale += stat_bonus.bonus_amount.abs() * 2.0;
-                }
-                _ => {}
-            }
-        }
-    }
-
-    // Clamp values after applying bonuses
-    state.update_derived_metrics();
+pub fn apply_compounding_bonuses(state: &mut GameState, bonuses: &[CompoundingBonus]) -> Vec<StatEffect> {
+    apply_effects(state, bonuses.iter().flat_map(|b| &b.bonuses))
 }
 
 #[cfg(test)]
@@ -424,16 +338,8 @@ mod tests {
             name: "Test Bonus".to_string(),
             message: "Testing".to_string(),
             bonuses: vec![
-                StatBonus {
-                    stat_name: "Velocity".to_string(),
-                    bonus_amount: 0.1,
-                    is_multiplier: true,
-                },
-                StatBonus {
-                    stat_name: "Morale".to_string(),
-                    bonus_amount: 10.0,
-                    is_multiplier: false,
-                },
+                Effect::boost(Stat::Velocity, 0.1),
+                Effect::add(Stat::Morale, 10.0),
             ],
         }];
 
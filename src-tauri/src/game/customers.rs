@@ -6,17 +6,17 @@
 
 use serde::{Deserialize, Serialize};
 use rand::prelude::*;
-use uuid::Uuid;
+use super::rng::{GameRng, random_id};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CustomerSegment {
     Enterprise,
     SMB,
     SelfServe,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CustomerLifecycle {
     Onboarding,
     Active,
@@ -60,15 +60,15 @@ pub struct Customer {
 // PERSONA GENERATION FUNCTIONS
 // ============================================================================
 
-pub fn generate_customer_persona(segment: CustomerSegment, week: u32, state: &super::state::GameState) -> Customer {
+pub fn generate_customer_persona(segment: CustomerSegment, week: u32, state: &super::state::GameState, rng: &mut GameRng) -> Customer {
     match segment {
-        CustomerSegment::Enterprise => generate_enterprise_customer(week),
-        CustomerSegment::SMB => generate_smb_customer(week),
-        CustomerSegment::SelfServe => generate_selfserve_customer(week),
+        CustomerSegment::Enterprise => generate_enterprise_customer(week, rng),
+        CustomerSegment::SMB => generate_smb_customer(week, rng),
+        CustomerSegment::SelfServe => generate_selfserve_customer(week, rng),
     }
 }
 
-fn generate_enterprise_customer(week: u32) -> Customer {
+fn generate_enterprise_customer(week: u32, rng: &mut GameRng) -> Customer {
     let enterprise_names = vec![
         ("Sarah Chen", "Acme Corp"),
         ("David Martinez", "TechGlobal Inc"),
@@ -82,16 +82,15 @@ fn generate_enterprise_customer(week: u32) -> Customer {
         ("Kevin Brown", "FutureWorks Inc"),
     ];
 
-    let mut rng = thread_rng();
-    let (name, company) = enterprise_names.choose(&mut rng).unwrap();
+    let (name, company) = enterprise_names.choose(rng).unwrap();
 
     Customer {
-        id: generate_customer_id(),
+        id: generate_customer_id(rng),
         name: name.to_string(),
         company: company.to_string(),
         segment: CustomerSegment::Enterprise,
         join_week: week,
-        satisfaction: 70.0 + rng.gen::<f64>() * 20.0, // 70-90 initial satisfaction
+        satisfaction: 70.0 + rng.random::<f64>() * 20.0, // 70-90 initial satisfaction
         lifecycle_stage: CustomerLifecycle::Onboarding,
         story: generate_customer_story(&Customer {
             id: String::new(),
@@ -105,14 +104,14 @@ fn generate_enterprise_customer(week: u32) -> Customer {
             feedback_history: vec![],
             mrr_contribution: 0.0,
             is_champion: false,
-        }),
+        }, rng),
         feedback_history: vec![],
         mrr_contribution: 0.0,
         is_champion: false,
     }
 }
 
-fn generate_smb_customer(week: u32) -> Customer {
+fn generate_smb_customer(week: u32, rng: &mut GameRng) -> Customer {
     let smb_names = vec![
         ("Mike Johnson", "Mike's Coffee Shop"),
         ("Lisa Chen", "Lisa's Design Studio"),
@@ -126,16 +125,15 @@ fn generate_smb_customer(week: u32) -> Customer {
         ("Emily White", "White Photography"),
     ];
 
-    let mut rng = thread_rng();
-    let (name, company) = smb_names.choose(&mut rng).unwrap();
+    let (name, company) = smb_names.choose(rng).unwrap();
 
     Customer {
-        id: generate_customer_id(),
+        id: generate_customer_id(rng),
         name: name.to_string(),
         company: company.to_string(),
         segment: CustomerSegment::SMB,
         join_week: week,
-        satisfaction: 65.0 + rng.gen::<f64>() * 25.0, // 65-90 initial satisfaction
+        satisfaction: 65.0 + rng.random::<f64>() * 25.0, // 65-90 initial satisfaction
         lifecycle_stage: CustomerLifecycle::Onboarding,
         story: generate_customer_story(&Customer {
             id: String::new(),
@@ -149,14 +147,14 @@ fn generate_smb_customer(week: u32) -> Customer {
             feedback_history: vec![],
             mrr_contribution: 0.0,
             is_champion: false,
-        }),
+        }, rng),
         feedback_history: vec![],
         mrr_contribution: 0.0,
         is_champion: false,
     }
 }
 
-fn generate_selfserve_customer(week: u32) -> Customer {
+fn generate_selfserve_customer(week: u32, rng: &mut GameRng) -> Customer {
     let selfserve_names = vec![
         ("Alex Chen", "Alex the indie dev"),
         ("Jordan Taylor", "Jordan (freelance designer)"),
@@ -170,16 +168,15 @@ fn generate_selfserve_customer(week: u32) -> Customer {
         ("Skyler Park", "Skyler the marketer"),
     ];
 
-    let mut rng = thread_rng();
-    let (name, company) = selfserve_names.choose(&mut rng).unwrap();
+    let (name, company) = selfserve_names.choose(rng).unwrap();
 
     Customer {
-        id: generate_customer_id(),
+        id: generate_customer_id(rng),
         name: name.to_string(),
         company: company.to_string(),
         segment: CustomerSegment::SelfServe,
         join_week: week,
-        satisfaction: 60.0 + rng.gen::<f64>() * 30.0, // 60-90 initial satisfaction
+        satisfaction: 60.0 + rng.random::<f64>() * 30.0, // 60-90 initial satisfaction
         lifecycle_stage: CustomerLifecycle::Onboarding,
         story: generate_customer_story(&Customer {
             id: String::new(),
@@ -193,14 +190,14 @@ fn generate_selfserve_customer(week: u32) -> Customer {
             feedback_history: vec![],
             mrr_contribution: 0.0,
             is_champion: false,
-        }),
+        }, rng),
         feedback_history: vec![],
         mrr_contribution: 0.0,
         is_champion: false,
     }
 }
 
-fn generate_customer_story(customer: &Customer) -> String {
+fn generate_customer_story(customer: &Customer, rng: &mut GameRng) -> String {
     let stories = match customer.segment {
         CustomerSegment::Enterprise => vec![
             format!("{}'s team was drowning in spreadsheets and manual processes. They needed a scalable solution.", customer.name),
@@ -219,33 +216,30 @@ fn generate_customer_story(customer: &Customer) -> String {
         ],
     };
 
-    let mut rng = thread_rng();
-    stories.choose(&mut rng).unwrap().clone()
+    stories.choose(rng).unwrap().clone()
 }
 
 // ============================================================================
 // FEEDBACK GENERATION FUNCTIONS
 // ============================================================================
 
-pub fn generate_customer_feedback(customer: &Customer, state: &super::state::GameState) -> CustomerFeedback {
-    let mut rng = thread_rng();
-
+pub fn generate_customer_feedback(customer: &Customer, state: &super::state::GameState, rng: &mut GameRng) -> CustomerFeedback {
     // Determine sentiment based on customer satisfaction and game state
     let sentiment = if customer.satisfaction > 80.0 && state.nps > 30.0 {
-        if rng.gen_bool(0.8) { FeedbackSentiment::Positive } else { FeedbackSentiment::Neutral }
+        if rng.random_bool(0.8) { FeedbackSentiment::Positive } else { FeedbackSentiment::Neutral }
     } else if customer.satisfaction > 60.0 {
         FeedbackSentiment::Neutral
     } else if customer.satisfaction > 40.0 {
-        if rng.gen_bool(0.7) { FeedbackSentiment::Neutral } else { FeedbackSentiment::Negative }
+        if rng.random_bool(0.7) { FeedbackSentiment::Neutral } else { FeedbackSentiment::Negative }
     } else {
-        if rng.gen_bool(0.6) { FeedbackSentiment::Negative } else { FeedbackSentiment::Critical }
+        if rng.random_bool(0.6) { FeedbackSentiment::Negative } else { FeedbackSentiment::Critical }
     };
 
     let quote = match sentiment {
-        FeedbackSentiment::Positive => generate_positive_feedback(customer, state),
-        FeedbackSentiment::Neutral => generate_neutral_feedback(customer),
-        FeedbackSentiment::Negative => generate_negative_feedback(customer, state),
-        FeedbackSentiment::Critical => generate_negative_feedback(customer, state), // Critical is just stronger negative
+        FeedbackSentiment::Positive => generate_positive_feedback(customer, state, rng),
+        FeedbackSentiment::Neutral => generate_neutral_feedback(customer, rng),
+        FeedbackSentiment::Negative => generate_negative_feedback(customer, state, rng),
+        FeedbackSentiment::Critical => generate_negative_feedback(customer, state, rng), // Critical is just stronger negative
     };
 
     let context = match customer.segment {
@@ -262,7 +256,7 @@ pub fn generate_customer_feedback(customer: &Customer, state: &super::state::Gam
     }
 }
 
-fn generate_positive_feedback(customer: &Customer, state: &super::state::GameState) -> String {
+fn generate_positive_feedback(customer: &Customer, state: &super::state::GameState, rng: &mut GameRng) -> String {
     let feedbacks = vec![
         "This saved us 10 hours/week!",
         "Game-changer for our team",
@@ -274,11 +268,10 @@ fn generate_positive_feedback(customer: &Customer, state: &super::state::GameSta
         "Our productivity has doubled",
     ];
 
-    let mut rng = thread_rng();
-    feedbacks.choose(&mut rng).unwrap().to_string()
+    feedbacks.choose(rng).unwrap().to_string()
 }
 
-fn generate_negative_feedback(customer: &Customer, state: &super::state::GameState) -> String {
+fn generate_negative_feedback(customer: &Customer, state: &super::state::GameState, rng: &mut GameRng) -> String {
     let feedbacks = if state.tech_debt > 60.0 {
         vec![
             "Too many bugs lately",
@@ -302,11 +295,10 @@ fn generate_negative_feedback(customer: &Customer, state: &super::state::GameSta
         ]
     };
 
-    let mut rng = thread_rng();
-    feedbacks.choose(&mut rng).unwrap().to_string()
+    feedbacks.choose(rng).unwrap().to_string()
 }
 
-fn generate_neutral_feedback(customer: &Customer) -> String {
+fn generate_neutral_feedback(customer: &Customer, rng: &mut GameRng) -> String {
     let feedbacks = vec![
         "It's okay, does the job",
         "Still evaluating the full impact",
@@ -316,19 +308,16 @@ fn generate_neutral_feedback(customer: &Customer) -> String {
         "Functional but not exceptional",
     ];
 
-    let mut rng = thread_rng();
-    feedbacks.choose(&mut rng).unwrap().to_string()
+    feedbacks.choose(rng).unwrap().to_string()
 }
 
 // ============================================================================
 // LIFECYCLE MANAGEMENT FUNCTIONS
 // ============================================================================
 
-pub fn update_customer_satisfaction(customer: &mut Customer, nps: f64, tech_debt: f64, velocity: f64) {
-    let mut rng = thread_rng();
-
+pub fn update_customer_satisfaction(customer: &mut Customer, nps: f64, tech_debt: f64, velocity: f64, rng: &mut GameRng) {
     // Base satisfaction change
-    let mut satisfaction_change = (rng.gen::<f64>() - 0.5) * 10.0; // -5 to +5
+    let mut satisfaction_change = (rng.random::<f64>() - 0.5) * 10.0; // -5 to +5
 
     // Factors affecting satisfaction
     if nps > 40.0 {
@@ -397,6 +386,13 @@ pub fn promote_to_champion(customer: &mut Customer) {
     }
 }
 
+/// Bring a lapsed customer back on their old plan, starting over in onboarding
+pub fn win_back(customer: &mut Customer) {
+    customer.lifecycle_stage = CustomerLifecycle::Onboarding;
+    customer.satisfaction = 55.0;
+    customer.is_champion = false;
+}
+
 // ============================================================================
 // QUERY FUNCTIONS
 // ============================================================================
@@ -417,7 +413,7 @@ pub fn get_at_risk_customers(customers: &[Customer]) -> Vec<&Customer> {
     get_customers_by_lifecycle(customers, CustomerLifecycle::AtRisk)
 }
 
-pub fn get_random_customer(customers: &[Customer], segment: Option<CustomerSegment>) -> Option<&Customer> {
+pub fn get_random_customer<'a>(customers: &'a [Customer], segment: Option<CustomerSegment>, rng: &mut GameRng) -> Option<&'a Customer> {
     let filtered: Vec<&Customer> = if let Some(seg) = segment {
         get_customers_by_segment(customers, seg)
     } else {
@@ -427,8 +423,7 @@ pub fn get_random_customer(customers: &[Customer], segment: Option<CustomerSegme
     if filtered.is_empty() {
         None
     } else {
-        let mut rng = thread_rng();
-        Some(filtered.choose(&mut rng).unwrap())
+        Some(filtered.choose(rng).unwrap())
     }
 }
 
@@ -446,6 +441,6 @@ pub fn calculate_segment_from_mrr(mrr: f64) -> CustomerSegment {
     }
 }
 
-fn generate_customer_id() -> String {
-    Uuid::new_v4().to_string()
+fn generate_customer_id(rng: &mut GameRng) -> String {
+    random_id(rng)
 }
\ No newline at end of file
@@ -0,0 +1,339 @@
+use serde::{Deserialize, Serialize};
+use super::effects::{Stat, StatEffect};
+use super::state::GameState;
+use super::ledger::LedgerCategory;
+
+/// Missed payments in a row before a loan is in default
+const DEFAULT_AFTER_MISSED: u32 = 3;
+/// Weeks a covenant can stay breached before the lender calls the loan
+const COVENANT_CURE_WEEKS: u32 = 4;
+/// Smallest loan a lender will bother underwriting
+const MIN_LOAN: f64 = 10_000.0;
+/// Share of monthly revenue a revenue-based financing takes until repaid
+const RBF_REVENUE_SHARE: f64 = 0.08;
+/// Total a revenue-based financing collects, as a multiple of the advance
+const RBF_REPAYMENT_CAP: f64 = 1.4;
+/// Weeks of interest-only payments before venture debt amortizes
+const VENTURE_DEBT_INTEREST_ONLY_WEEKS: u32 = 26;
+/// Rate increase lenders charge for waiving a breach
+const WAIVER_RATE_BUMP: f64 = 0.02;
+/// Fee for a waiver, as a share of the outstanding balance
+const WAIVER_FEE: f64 = 0.01;
+/// How much a waiver loosens the covenant
+const WAIVER_RELIEF: f64 = 0.75;
+
+/// Kinds of non-dilutive capital
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DebtKind {
+    /// Term loan from a bank, sized off revenue and amortized from day one
+    BankLoan,
+    /// Loan from a venture lender alongside a priced round; interest-only at first
+    VentureDebt,
+    /// Advance repaid as a share of revenue until a fixed multiple is paid back
+    RevenueBased,
+}
+
+impl DebtKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DebtKind::BankLoan => "bank loan",
+            DebtKind::VentureDebt => "venture debt",
+            DebtKind::RevenueBased => "revenue-based financing",
+        }
+    }
+
+    fn annual_rate(&self) -> f64 {
+        match self {
+            DebtKind::BankLoan => 0.08,
+            DebtKind::VentureDebt => 0.12,
+            DebtKind::RevenueBased => 0.0,
+        }
+    }
+
+    fn term_weeks(&self) -> u32 {
+        match self {
+            DebtKind::BankLoan => 104,
+            DebtKind::VentureDebt => 156,
+            // Repaid whenever revenue gets it there
+            DebtKind::RevenueBased => 0,
+        }
+    }
+}
+
+/// A condition the borrower must keep meeting while the loan is out
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Covenant {
+    MinimumCash(f64),
+    MinimumMrr(f64),
+}
+
+impl Covenant {
+    pub fn is_met(&self, state: &GameState) -> bool {
+        match self {
+            Covenant::MinimumCash(minimum) => state.bank >= *minimum,
+            Covenant::MinimumMrr(minimum) => state.mrr >= *minimum,
+        }
+    }
+
+    fn relaxed(&self) -> Covenant {
+        match self {
+            Covenant::MinimumCash(minimum) => Covenant::MinimumCash(minimum * WAIVER_RELIEF),
+            Covenant::MinimumMrr(minimum) => Covenant::MinimumMrr(minimum * WAIVER_RELIEF),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Loan {
+    pub kind: DebtKind,
+    pub principal: f64,
+    /// Still owed: principal for loans, the rest of the repayment cap for RBF
+    pub balance: f64,
+    pub annual_rate: f64,
+    pub term_weeks: u32,
+    pub issued_week: u32,
+    pub weeks_elapsed: u32,
+    pub covenant: Option<Covenant>,
+    pub weeks_in_breach: u32,
+    pub missed_payments: u32,
+}
+
+impl Loan {
+    /// Payment due this week from the current balance and revenue
+    pub fn weekly_payment(&self, mrr: f64) -> f64 {
+        let payment = match self.kind {
+            DebtKind::RevenueBased => mrr / 4.0 * RBF_REVENUE_SHARE,
+            DebtKind::VentureDebt if self.weeks_elapsed < VENTURE_DEBT_INTEREST_ONLY_WEEKS => self.weekly_interest(),
+            DebtKind::VentureDebt => {
+                let amortizing_weeks = self.term_weeks - VENTURE_DEBT_INTEREST_ONLY_WEEKS;
+                self.weekly_interest() + self.principal / amortizing_weeks as f64
+            }
+            DebtKind::BankLoan => self.weekly_interest() + self.principal / self.term_weeks as f64,
+        };
+        payment.min(self.balance + self.weekly_interest())
+    }
+
+    fn weekly_interest(&self) -> f64 {
+        self.balance * self.annual_rate / 52.0
+    }
+
+    pub fn in_breach(&self) -> bool {
+        self.weeks_in_breach > 0
+    }
+
+    pub fn is_in_default(&self) -> bool {
+        self.missed_payments >= DEFAULT_AFTER_MISSED
+    }
+
+    fn is_repaid(&self) -> bool {
+        self.balance <= 0.01
+    }
+}
+
+/// What a dilemma choice does about loans in breach
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DebtDecision {
+    /// Pay a fee and a higher rate for a looser covenant
+    NegotiateWaiver,
+    /// Repay every breached loan in full, if the cash is there
+    RepayInFull,
+}
+
+/// Size a loan from what the lender underwrites against, or say why they decline
+pub fn loan_offer(state: &GameState, kind: DebtKind) -> Result<f64, String> {
+    let amount = match kind {
+        DebtKind::BankLoan => state.mrr * 6.0,
+        DebtKind::RevenueBased => state.mrr * 4.0,
+        // Venture lenders follow equity: a share of the last priced round
+        DebtKind::VentureDebt => state.cap_table.rounds.last().map_or(0.0, |r| r.amount_raised * 0.3),
+    };
+    if state.loans.iter().any(Loan::in_breach) {
+        return Err("Lenders won't extend credit while a covenant is in breach".to_string());
+    }
+    if amount < MIN_LOAN {
+        return Err(match kind {
+            DebtKind::VentureDebt => "Venture lenders only lend after a priced round".to_string(),
+            _ => format!("Not enough revenue for a {}", kind.label()),
+        });
+    }
+    Ok(amount)
+}
+
+/// Take on a loan and bank the money; returns the stat changes and a summary
+pub fn take_loan(state: &mut GameState, kind: DebtKind) -> Result<(Vec<StatEffect>, String), String> {
+    let amount = loan_offer(state, kind)?;
+    let (balance, covenant) = match kind {
+        DebtKind::BankLoan => (amount, Some(Covenant::MinimumMrr(state.mrr * 0.75))),
+        DebtKind::VentureDebt => (amount, Some(Covenant::MinimumCash(amount * 0.5))),
+        DebtKind::RevenueBased => (amount * RBF_REPAYMENT_CAP, None),
+    };
+    state.loans.push(Loan {
+        kind,
+        principal: amount,
+        balance,
+        annual_rate: kind.annual_rate(),
+        term_weeks: kind.term_weeks(),
+        issued_week: state.week,
+        weeks_elapsed: 0,
+        covenant,
+        weeks_in_breach: 0,
+        missed_payments: 0,
+    });
+
+    let old_bank = state.bank;
+    state.bank += amount;
+    let effects = vec![StatEffect { stat: Stat::Bank, old_value: old_bank, new_value: state.bank, delta: amount }];
+    Ok((effects, format!("Took ${:.0} of {}", amount, kind.label())))
+}
+
+/// Debt payments over the next month at today's revenue
+pub fn monthly_debt_service(state: &GameState) -> f64 {
+    state.loans.iter().map(|l| l.weekly_payment(state.mrr) * 4.0).sum()
+}
+
+/// Make this week's payments from the bank and check covenants
+///
+/// A payment the bank can't cover is missed; a covenant left breached past the
+/// cure period makes the lender call the whole balance.
+pub fn service_debt(state: &mut GameState) {
+    let mut loans = std::mem::take(&mut state.loans);
+    for loan in &mut loans {
+        loan.weeks_elapsed += 1;
+        let payment = loan.weekly_payment(state.mrr);
+        if state.bank >= payment {
+            state.bank -= payment;
+            // An RBF payment is part advance, part fee, in the ratio of the repayment cap
+            let interest = match loan.kind {
+                DebtKind::RevenueBased => payment * (1.0 - 1.0 / RBF_REPAYMENT_CAP),
+                _ => loan.weekly_interest(),
+            };
+            loan.balance -= match loan.kind {
+                DebtKind::RevenueBased => payment,
+                _ => payment - interest,
+            };
+            loan.missed_payments = 0;
+            let reason = format!("{} payment", loan.kind.label());
+            state.ledger.record(state.week, LedgerCategory::Interest, -interest, &reason);
+            state.ledger.record(state.week, LedgerCategory::Debt, interest - payment, &reason);
+        } else {
+            loan.missed_payments += 1;
+        }
+
+        match loan.covenant {
+            Some(covenant) if !covenant.is_met(state) => loan.weeks_in_breach += 1,
+            _ => loan.weeks_in_breach = 0,
+        }
+        if loan.weeks_in_breach > COVENANT_CURE_WEEKS {
+            // The lender accelerates: everything is due now
+            if state.bank >= loan.balance {
+                state.bank -= loan.balance;
+                let reason = format!("{} called by the lender", loan.kind.label());
+                state.ledger.record(state.week, LedgerCategory::Debt, -loan.balance, &reason);
+                loan.balance = 0.0;
+            } else {
+                loan.missed_payments = DEFAULT_AFTER_MISSED;
+            }
+        }
+    }
+    loans.retain(|l| !l.is_repaid());
+    state.loans = loans;
+}
+
+/// Loans currently breaching a covenant, for event triggers
+pub fn breached_loans(state: &GameState) -> usize {
+    state.loans.iter().filter(|l| l.in_breach()).count()
+}
+
+/// Apply a dilemma's decision about loans in breach; returns the stat changes
+pub fn apply_debt_decision(state: &mut GameState, decision: DebtDecision) -> Vec<StatEffect> {
+    let old_bank = state.bank;
+    match decision {
+        DebtDecision::NegotiateWaiver => {
+            for loan in state.loans.iter_mut().filter(|l| l.in_breach()) {
+                state.bank -= loan.balance * WAIVER_FEE;
+                loan.annual_rate += WAIVER_RATE_BUMP;
+                loan.covenant = loan.covenant.map(|c| c.relaxed());
+                loan.weeks_in_breach = 0;
+            }
+        }
+        DebtDecision::RepayInFull => {
+            let owed: f64 = state.loans.iter().filter(|l| l.in_breach()).map(|l| l.balance).sum();
+            if state.bank >= owed {
+                state.bank -= owed;
+                state.loans.retain(|l| !l.in_breach());
+            }
+        }
+    }
+
+    if state.bank == old_bank {
+        return Vec::new();
+    }
+    vec![StatEffect { stat: Stat::Bank, old_value: old_bank, new_value: state.bank, delta: state.bank - old_bank }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::DifficultyMode;
+
+    fn with_revenue(mrr: f64) -> GameState {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        state.mrr = mrr;
+        state
+    }
+
+    #[test]
+    fn test_lenders_size_loans_off_revenue() {
+        let state = with_revenue(20_000.0);
+
+        assert_eq!(loan_offer(&state, DebtKind::BankLoan), Ok(120_000.0));
+        assert_eq!(loan_offer(&state, DebtKind::RevenueBased), Ok(80_000.0));
+        assert!(loan_offer(&state, DebtKind::VentureDebt).is_err());
+        assert!(loan_offer(&with_revenue(0.0), DebtKind::BankLoan).is_err());
+    }
+
+    #[test]
+    fn test_revenue_based_financing_repays_from_revenue() {
+        let mut state = with_revenue(20_000.0);
+        take_loan(&mut state, DebtKind::RevenueBased).unwrap();
+        let bank = state.bank;
+
+        service_debt(&mut state);
+
+        assert_eq!(state.bank, bank - 400.0);
+        assert_eq!(state.loans[0].balance, 80_000.0 * RBF_REPAYMENT_CAP - 400.0);
+        state.loans[0].balance = 100.0;
+        service_debt(&mut state);
+        assert!(state.loans.is_empty());
+    }
+
+    #[test]
+    fn test_unpaid_loan_defaults() {
+        let mut state = with_revenue(20_000.0);
+        take_loan(&mut state, DebtKind::BankLoan).unwrap();
+        state.bank = 0.0;
+
+        for _ in 0..DEFAULT_AFTER_MISSED {
+            assert!(!state.loans[0].is_in_default());
+            service_debt(&mut state);
+        }
+
+        assert!(state.loans[0].is_in_default());
+    }
+
+    #[test]
+    fn test_waiver_clears_breach_at_a_higher_rate() {
+        let mut state = with_revenue(20_000.0);
+        take_loan(&mut state, DebtKind::BankLoan).unwrap();
+        state.mrr = 10_000.0;
+        service_debt(&mut state);
+        assert_eq!(breached_loans(&state), 1);
+
+        apply_debt_decision(&mut state, DebtDecision::NegotiateWaiver);
+
+        let loan = &state.loans[0];
+        assert_eq!(breached_loans(&state), 0);
+        assert_eq!(loan.annual_rate, 0.08 + WAIVER_RATE_BUMP);
+        assert_eq!(loan.covenant, Some(Covenant::MinimumMrr(15_000.0 * WAIVER_RELIEF)));
+    }
+}
@@ -1,4 +1,5 @@
 use super::state::GameState;
+use super::cohorts::retain_cohorts;
 
 /// Calculate weekly revenue from MRR
 pub fn calculate_weekly_revenue(mrr: f64) -> f64 {
@@ -10,12 +11,9 @@ pub fn calculate_weekly_burn(burn: f64) -> f64 {
     burn / 4.0
 }
 
-/// Apply churn to MRR
+/// Apply a week of churn by moving every signup cohort along its retention curve
 pub fn apply_churn(state: &mut GameState) {
-    let monthly_churn = state.churn_rate / 100.0;
-    let weekly_churn = monthly_churn / 4.0;
-
-    state.mrr *= 1.0 - weekly_churn;
+    retain_cohorts(state);
 }
 
 /// Calculate base churn rate based on NPS and incidents
@@ -67,6 +65,8 @@ pub fn update_nps(state: &mut GameState) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::cohorts::add_signups;
+    use crate::game::customers::CustomerSegment;
     use crate::game::state::DifficultyMode;
 
     #[test]
@@ -90,7 +90,7 @@ mod tests {
     #[test]
     fn test_apply_churn() {
         let mut state = GameState::new(DifficultyMode::IndieBootstrap);
-        state.mrr = 10_000.0;
+        add_signups(&mut state, CustomerSegment::SMB, 10_000.0);
         state.churn_rate = 8.0; // 8% monthly
 
         let initial_mrr = state.mrr;
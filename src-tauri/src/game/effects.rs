@@ -0,0 +1,371 @@
+use serde::{Deserialize, Serialize};
+use super::state::GameState;
+use super::cap_table::OTHER_HOLDERS;
+use super::ledger::{record_bank_change, scale_expenses, LedgerCategory};
+use super::mrr_bridge::record_mrr_change;
+use super::cohorts::{adjust_mrr, adjust_users};
+
+/// A game stat that events, synergies, compounding bonuses and market conditions can change
+///
+/// Serialized with its display label so event data and the UI read naturally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Stat {
+    Bank,
+    #[serde(alias = "Monthly Burn")]
+    Burn,
+    #[serde(rename = "MRR")]
+    Mrr,
+    #[serde(rename = "WAU")]
+    Wau,
+    #[serde(rename = "WAU Growth")]
+    WauGrowth,
+    #[serde(rename = "Churn Rate")]
+    ChurnRate,
+    Morale,
+    Reputation,
+    #[serde(rename = "NPS")]
+    Nps,
+    #[serde(rename = "Tech Debt")]
+    TechDebt,
+    #[serde(rename = "Compliance Risk")]
+    ComplianceRisk,
+    Velocity,
+    #[serde(rename = "Founder Equity")]
+    FounderEquity,
+    #[serde(rename = "Focus")]
+    FocusSlots,
+    #[serde(rename = "Onboarding Quality")]
+    OnboardingQuality,
+}
+
+impl Stat {
+    pub const ALL: [Stat; 15] = [
+        Stat::Bank,
+        Stat::Burn,
+        Stat::Mrr,
+        Stat::Wau,
+        Stat::WauGrowth,
+        Stat::ChurnRate,
+        Stat::Morale,
+        Stat::Reputation,
+        Stat::Nps,
+        Stat::TechDebt,
+        Stat::ComplianceRisk,
+        Stat::Velocity,
+        Stat::FounderEquity,
+        Stat::FocusSlots,
+        Stat::OnboardingQuality,
+    ];
+
+    /// Human-readable name, matching the serialized form
+    pub fn label(&self) -> &'static str {
+        match self {
+            Stat::Bank => "Bank",
+            Stat::Burn => "Burn",
+            Stat::Mrr => "MRR",
+            Stat::Wau => "WAU",
+            Stat::WauGrowth => "WAU Growth",
+            Stat::ChurnRate => "Churn Rate",
+            Stat::Morale => "Morale",
+            Stat::Reputation => "Reputation",
+            Stat::Nps => "NPS",
+            Stat::TechDebt => "Tech Debt",
+            Stat::ComplianceRisk => "Compliance Risk",
+            Stat::Velocity => "Velocity",
+            Stat::FounderEquity => "Founder Equity",
+            Stat::FocusSlots => "Focus",
+            Stat::OnboardingQuality => "Onboarding Quality",
+        }
+    }
+
+    /// Valid range for the stat; the applier clamps every change into it
+    pub fn bounds(&self) -> (f64, f64) {
+        match self {
+            Stat::Bank | Stat::WauGrowth => (f64::MIN, f64::MAX),
+            Stat::Burn | Stat::Mrr => (0.0, f64::MAX),
+            Stat::Wau => (0.0, u32::MAX as f64),
+            Stat::ChurnRate
+            | Stat::Morale
+            | Stat::Reputation
+            | Stat::TechDebt
+            | Stat::ComplianceRisk
+            | Stat::FounderEquity
+            | Stat::OnboardingQuality => (0.0, 100.0),
+            Stat::Nps => (-100.0, 100.0),
+            Stat::Velocity => (0.1, 3.0),
+            Stat::FocusSlots => (2.0, u8::MAX as f64),
+        }
+    }
+
+    pub fn get(&self, state: &GameState) -> f64 {
+        match self {
+            Stat::Bank => state.bank,
+            Stat::Burn => state.burn,
+            Stat::Mrr => state.mrr,
+            Stat::Wau => state.wau as f64,
+            Stat::WauGrowth => state.wau_growth_rate,
+            Stat::ChurnRate => state.churn_rate,
+            Stat::Morale => state.morale,
+            Stat::Reputation => state.reputation,
+            Stat::Nps => state.nps,
+            Stat::TechDebt => state.tech_debt,
+            Stat::ComplianceRisk => state.compliance_risk,
+            Stat::Velocity => state.velocity,
+            Stat::FounderEquity => state.founder_equity,
+            Stat::FocusSlots => state.focus_slots as f64,
+            Stat::OnboardingQuality => state.onboarding_quality,
+        }
+    }
+
+    fn set(&self, state: &mut GameState, value: f64) {
+        match self {
+            Stat::Bank => state.bank = value,
+            // Burn is the sum of the expense lines, so spread the change across them
+            Stat::Burn => scale_expenses(state, value),
+            // MRR and WAU are cohort totals, so the change is spread across the cohorts
+            Stat::Mrr => {
+                let delta = value - state.mrr;
+                adjust_mrr(state, delta);
+                state.mrr = value;
+                record_mrr_change(state, delta);
+            }
+            Stat::Wau => {
+                adjust_users(state, value.round() - state.wau as f64);
+                state.wau = value.round() as u32;
+            }
+            Stat::WauGrowth => state.wau_growth_rate = value,
+            Stat::ChurnRate => state.churn_rate = value,
+            Stat::Morale => state.morale = value,
+            Stat::Reputation => state.reputation = value,
+            Stat::Nps => state.nps = value,
+            Stat::TechDebt => state.tech_debt = value,
+            Stat::ComplianceRisk => state.compliance_risk = value,
+            Stat::Velocity => state.velocity = value,
+            Stat::FounderEquity => {
+                // Equity changes go through the cap table so ownership always adds up;
+                // equity given away is handled in apply_effect
+                state.cap_table.dilute_founder_to(value);
+                state.founder_equity = state.cap_table.founder_ownership();
+            }
+            Stat::FocusSlots => state.focus_slots = value.round() as u8,
+            Stat::OnboardingQuality => state.onboarding_quality = value,
+        }
+    }
+}
+
+/// How an effect combines its amount with the current value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EffectOp {
+    /// value + amount
+    Add,
+    /// value × (1 + amount), so 0.1 is a 10% boost
+    Boost,
+    /// value × amount
+    Multiply,
+    /// value = amount
+    Set,
+}
+
+/// A single typed change to one stat
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Effect {
+    pub stat: Stat,
+    pub op: EffectOp,
+    pub amount: f64,
+}
+
+impl Effect {
+    pub fn add(stat: Stat, amount: f64) -> Self {
+        Self { stat, op: EffectOp::Add, amount }
+    }
+
+    pub fn boost(stat: Stat, amount: f64) -> Self {
+        Self { stat, op: EffectOp::Boost, amount }
+    }
+
+    pub fn multiply(stat: Stat, amount: f64) -> Self {
+        Self { stat, op: EffectOp::Multiply, amount }
+    }
+
+    pub fn set(stat: Stat, amount: f64) -> Self {
+        Self { stat, op: EffectOp::Set, amount }
+    }
+
+    /// Value the stat would take from `current`, before clamping
+    fn target_value(&self, current: f64) -> f64 {
+        match self.op {
+            EffectOp::Add => current + self.amount,
+            EffectOp::Boost => current * (1.0 + self.amount),
+            EffectOp::Multiply => current * self.amount,
+            EffectOp::Set => self.amount,
+        }
+    }
+}
+
+/// Record of a stat change that was actually applied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatEffect {
+    pub stat: Stat,
+    pub old_value: f64,
+    pub new_value: f64,
+    pub delta: f64,
+}
+
+/// Apply one effect, clamping the stat into its valid range
+pub fn apply_effect(state: &mut GameState, effect: &Effect) -> StatEffect {
+    let old_value = effect.stat.get(state);
+    match (effect.stat, effect.op) {
+        // Equity given away is a new issue: -30 hands a holder 30% of the company and
+        // shrinks the founder's stake by that share rather than by 30 points
+        (Stat::FounderEquity, EffectOp::Add) if effect.amount < 0.0 => {
+            state.cap_table.issue_stake(OTHER_HOLDERS, -effect.amount);
+            state.founder_equity = state.cap_table.founder_ownership();
+        }
+        _ => {
+            let (min, max) = effect.stat.bounds();
+            effect.stat.set(state, effect.target_value(old_value).clamp(min, max));
+        }
+    }
+    // Read back so integer stats report the value they were rounded to
+    let new_value = effect.stat.get(state);
+
+    StatEffect {
+        stat: effect.stat,
+        old_value,
+        new_value,
+        delta: new_value - old_value,
+    }
+}
+
+/// Apply effects in order and refresh derived metrics once at the end
+pub fn apply_effects<'a>(state: &mut GameState, effects: impl IntoIterator<Item = &'a Effect>) -> Vec<StatEffect> {
+    let applied = effects.into_iter().map(|effect| apply_effect(state, effect)).collect();
+    state.update_derived_metrics();
+    applied
+}
+
+/// An effect that lands in a later week, possibly once a week for several weeks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledEffect {
+    pub effect: Effect,
+    pub description: String,
+    /// First week the effect applies
+    pub start_week: u32,
+    /// Weekly applications still to come
+    pub remaining_weeks: u32,
+}
+
+/// Apply the scheduled effects that are due this week and drop the finished ones
+pub fn resolve_scheduled_effects(state: &mut GameState) -> Vec<StatEffect> {
+    let week = state.week;
+    let mut scheduled = std::mem::take(&mut state.scheduled_effects);
+    let mut applied = Vec::new();
+    for pending in scheduled.iter_mut().filter(|s| s.start_week <= week) {
+        let old_bank = state.bank;
+        applied.push(apply_effect(state, &pending.effect));
+        record_bank_change(state, old_bank, LedgerCategory::Other, &pending.description);
+        pending.remaining_weeks = pending.remaining_weeks.saturating_sub(1);
+    }
+    scheduled.retain(|s| s.remaining_weeks > 0);
+    state.scheduled_effects = scheduled;
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::DifficultyMode;
+
+    #[test]
+    fn test_apply_effect_clamps_to_bounds() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        state.morale = 95.0;
+
+        let applied = apply_effect(&mut state, &Effect::add(Stat::Morale, 20.0));
+
+        assert_eq!(state.morale, 100.0);
+        assert_eq!(applied.delta, 5.0);
+    }
+
+    #[test]
+    fn test_effect_ops() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        state.mrr = 1000.0;
+
+        apply_effect(&mut state, &Effect::boost(Stat::Mrr, 0.1));
+        assert!((state.mrr - 1100.0).abs() < 1e-9);
+        apply_effect(&mut state, &Effect::multiply(Stat::Mrr, 0.5));
+        assert!((state.mrr - 550.0).abs() < 1e-9);
+        apply_effect(&mut state, &Effect::set(Stat::Mrr, 42.0));
+        assert_eq!(state.mrr, 42.0);
+    }
+
+    #[test]
+    fn test_equity_given_away_dilutes_relatively() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 1);
+        let before = state.founder_equity;
+
+        let applied = apply_effect(&mut state, &Effect::add(Stat::FounderEquity, -30.0));
+
+        assert!((state.founder_equity - before * 0.7).abs() < 0.01);
+        assert!((applied.delta + before * 0.3).abs() < 0.01);
+        assert!((state.founder_equity - state.cap_table.founder_ownership()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_integer_stats_report_rounded_values() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        state.wau = 10;
+
+        let applied = apply_effect(&mut state, &Effect::add(Stat::Wau, 2.6));
+
+        assert_eq!(state.wau, 13);
+        assert_eq!(applied.delta, 3.0);
+    }
+
+    #[test]
+    fn test_scheduled_effect_repeats_then_expires() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        state.morale = 50.0;
+        state.scheduled_effects.push(ScheduledEffect {
+            effect: Effect::add(Stat::Morale, -2.0),
+            description: "Lingering exhaustion".to_string(),
+            start_week: 2,
+            remaining_weeks: 3,
+        });
+
+        state.week = 1;
+        assert!(resolve_scheduled_effects(&mut state).is_empty());
+        for week in 2..=4 {
+            state.week = week;
+            assert_eq!(resolve_scheduled_effects(&mut state).len(), 1);
+        }
+
+        assert_eq!(state.morale, 44.0);
+        assert!(state.scheduled_effects.is_empty());
+    }
+
+    #[test]
+    fn test_scheduled_effect_with_no_weeks_left_lands_once() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        state.morale = 50.0;
+        state.scheduled_effects.push(ScheduledEffect {
+            effect: Effect::add(Stat::Morale, -2.0),
+            description: "Hand-edited save".to_string(),
+            start_week: 0,
+            remaining_weeks: 0,
+        });
+
+        assert_eq!(resolve_scheduled_effects(&mut state).len(), 1);
+        assert_eq!(state.morale, 48.0);
+        assert!(state.scheduled_effects.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_stat_fails_to_parse() {
+        let effect: Effect = serde_json::from_str(r#"{"stat":"Tech Debt","op":"Add","amount":5.0}"#).unwrap();
+        assert_eq!(effect.stat, Stat::TechDebt);
+
+        assert!(serde_json::from_str::<Effect>(r#"{"stat":"TechDebt","op":"Add","amount":5.0}"#).is_err());
+    }
+}
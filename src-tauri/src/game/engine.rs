@@ -0,0 +1,260 @@
+use serde::Serialize;
+use super::state::GameState;
+use super::actions::{Action, resolve_action};
+use super::rng::GameRng;
+use super::economy::{apply_churn, update_nps};
+use super::victory::update_escape_velocity_progress;
+use super::insights::{WeeklyInsight, generate_weekly_insights};
+use super::warnings::{FailureWarning, check_failure_warnings};
+use super::compounding::{CompoundingBonus, check_compounding_effects, apply_compounding_bonuses};
+use super::events_enhanced::{GameEvent, check_for_events};
+use super::synergies::{ActionSynergy, SpecializationPath, check_action_synergies, detect_specialization_path, apply_synergy_bonuses};
+use super::market_conditions::{MarketCondition, get_active_conditions, update_market_conditions, generate_market_condition, action_effectiveness};
+use super::progression::{MilestoneEvent, action_unlock_key, get_available_actions, check_milestone_events, check_unlocks};
+use super::projects::{CompletedProject, advance_projects, committed_focus, is_in_progress, start_project};
+use super::investors::{advance_pipeline, open_pipeline};
+use super::mrr_bridge::{MrrBridge, close_mrr_week, net_revenue_retention};
+use super::replay::checkpoint_hash;
+
+/// Weeks of history considered when looking for compounding streaks
+const COMPOUNDING_LOOKBACK_WEEKS: usize = 12;
+
+/// Everything a front-end needs to explain what happened in a turn
+#[derive(Debug, Clone, Serialize)]
+pub struct TurnResult {
+    pub insights: Vec<WeeklyInsight>,
+    pub warnings: Vec<FailureWarning>,
+    pub compounding_bonuses: Vec<CompoundingBonus>,
+    pub events: Vec<GameEvent>,
+    pub synergies: Vec<ActionSynergy>,
+    pub market_conditions: Vec<MarketCondition>,
+    pub unlocked_actions: Vec<String>,
+    pub milestone_event: Option<MilestoneEvent>,
+    pub specialization_bonus: Option<SpecializationPath>,
+    pub completed_projects: Vec<CompletedProject>,
+    /// Where the week's change in MRR came from
+    pub mrr_bridge: MrrBridge,
+    /// Net revenue retention over the last year, as a percentage
+    pub net_revenue_retention: Option<f64>,
+}
+
+/// The weekly turn pipeline, shared by the Tauri commands, the simulator and tests
+pub struct Engine;
+
+impl Engine {
+    /// Play one week: validate, resolve actions, apply systems, advance, then report
+    ///
+    /// The state is left untouched if the actions are rejected.
+    pub fn step(state: &mut GameState, actions: &[Action]) -> Result<TurnResult, String> {
+        // Phase 1: validation
+        Self::validate(state, actions)?;
+
+        let prev_state = state.clone();
+        let market_conditions = get_active_conditions(state);
+        // One seeded RNG stream drives every random roll this turn
+        let mut rng = state.next_rng();
+
+        // Phase 2: player actions, then a week of work on every project
+        Self::resolve_actions(state, actions, &market_conditions, &mut rng);
+        advance_pipeline(state, &market_conditions, &mut rng);
+        let completed_projects = advance_projects(state, &market_conditions, &mut rng);
+
+        // Phase 3: action synergies and specialization
+        let synergies = check_action_synergies(actions);
+        apply_synergy_bonuses(state, &synergies);
+        let specialization_bonus = detect_specialization_path(&state.action_history, actions);
+
+        // Phase 4: compounding rewards for sustained good practices
+        let compounding_bonuses = check_compounding_effects(state, COMPOUNDING_LOOKBACK_WEEKS);
+        apply_compounding_bonuses(state, &compounding_bonuses);
+
+        // Phase 5: weekly economy
+        apply_churn(state);
+        update_nps(state);
+        update_escape_velocity_progress(state);
+
+        // Phase 6: market
+        Self::update_market(state, &mut rng);
+
+        // Phase 7: progression
+        let milestone_event = check_milestone_events(state);
+        let unlocked_actions = Self::apply_unlocks(state);
+
+        // Phase 8: advance the clock
+        state.advance_week(&mut rng);
+        state.update_derived_metrics();
+        let mrr_bridge = close_mrr_week(state);
+        let net_revenue_retention = net_revenue_retention(state);
+
+        // Phase 9: feedback for the player
+        let insights = generate_weekly_insights(&prev_state, state);
+        let warnings = check_failure_warnings(state);
+
+        // Phase 10: random events for the new week
+        let events = check_for_events(state, &mut rng);
+
+        // Phase 11: record the turn so the game can be replayed and verified later
+        let checkpoint = checkpoint_hash(state);
+        state.replay.record_turn(prev_state.week, actions.to_vec(), checkpoint);
+
+        Ok(TurnResult {
+            insights,
+            warnings,
+            compounding_bonuses,
+            events,
+            synergies,
+            market_conditions,
+            unlocked_actions,
+            milestone_event,
+            specialization_bonus,
+            completed_projects,
+            mrr_bridge,
+            net_revenue_retention,
+        })
+    }
+
+    /// Check that every action is unlocked and the week's focus budget is not exceeded
+    ///
+    /// Running projects hold their focus first; new actions get what is left.
+    pub fn validate(state: &GameState, actions: &[Action]) -> Result<(), String> {
+        let available_actions = get_available_actions(state);
+        for (index, action) in actions.iter().enumerate() {
+            if !available_actions.iter().any(|available| available == action) {
+                return Err(format!("Action {:?} is not unlocked yet", action));
+            }
+            let started_twice = actions[..index].iter().any(|a| a.duration_weeks() > 1 && std::mem::discriminant(a) == std::mem::discriminant(action));
+            if action.duration_weeks() > 1 && (started_twice || is_in_progress(state, action)) {
+                return Err(format!("A {:?} project is already in progress", action));
+            }
+        }
+
+        let total_focus: u8 = actions.iter().map(|a| a.focus_cost()).sum();
+        let available_focus = state.focus_slots.saturating_sub(committed_focus(state));
+        if total_focus > available_focus {
+            return Err(format!("Not enough focus slots! Required: {}, Available: {}", total_focus, available_focus));
+        }
+
+        Ok(())
+    }
+
+    fn resolve_actions(state: &mut GameState, actions: &[Action], market_conditions: &[MarketCondition], rng: &mut GameRng) {
+        for action in actions {
+            if action.duration_weeks() > 1 {
+                start_project(state, action);
+                if let Action::Fundraise { target, instrument } = action {
+                    open_pipeline(state, *target, *instrument, rng);
+                }
+                continue;
+            }
+            let effectiveness = action_effectiveness(action, market_conditions);
+            resolve_action(state, action, &effectiveness, rng);
+        }
+        state.action_history.push((state.week, actions.to_vec()));
+    }
+
+    fn update_market(state: &mut GameState, rng: &mut GameRng) {
+        update_market_conditions(state);
+        if let Some(condition) = generate_market_condition(state, state.week, rng) {
+            state.active_market_conditions.push(condition);
+        }
+    }
+
+    /// Unlock newly earned actions and return their keys
+    fn apply_unlocks(state: &mut GameState) -> Vec<String> {
+        let mut new_unlocks = Vec::new();
+        for action in check_unlocks(state) {
+            let key = action_unlock_key(&action);
+            if !state.unlocked_actions.contains(&key) {
+                state.unlocked_actions.push(key.clone());
+                new_unlocks.push(key);
+            }
+        }
+        new_unlocks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::Quality;
+    use crate::game::cap_table::Instrument;
+    use crate::game::state::DifficultyMode;
+    use crate::game::hiring::OfferTerms;
+
+    #[test]
+    fn test_step_advances_week() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        let actions = vec![Action::ShipFeature { quality: Quality::Balanced }];
+
+        Engine::step(&mut state, &actions).unwrap();
+
+        assert_eq!(state.week, 1);
+        assert_eq!(state.replay.weeks.len(), 1);
+        assert_eq!(state.action_history.len(), 1);
+    }
+
+    #[test]
+    fn test_step_rejects_over_focus_without_changes() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        state.candidates[0].stage = crate::game::hiring::CandidateStage::Interviewed;
+        let hire = Action::Hire { candidate_id: state.candidates[0].id, offer: OfferTerms::AtAsk };
+        let actions = vec![hire, Action::Fundraise { target: 250_000.0, instrument: Instrument::PricedRound }];
+
+        let result = Engine::step(&mut state, &actions);
+
+        assert!(result.is_err());
+        assert_eq!(state.week, 0);
+        assert_eq!(state.rng_stream, 1);
+    }
+
+    #[test]
+    fn test_step_is_deterministic() {
+        let mut a = GameState::with_seed(DifficultyMode::VCTrack, 8);
+        let mut b = GameState::with_seed(DifficultyMode::VCTrack, 8);
+        let actions = vec![Action::FounderLedSales { call_count: 5 }, Action::TakeBreak];
+
+        for _ in 0..10 {
+            Engine::step(&mut a, &actions).unwrap();
+            Engine::step(&mut b, &actions).unwrap();
+        }
+
+        assert_eq!(checkpoint_hash(&a), checkpoint_hash(&b));
+    }
+
+    #[test]
+    fn test_mrr_bridge_accounts_for_every_change() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 12);
+        let actions = vec![Action::FounderLedSales { call_count: 5 }, Action::TakeBreak];
+
+        for _ in 0..20 {
+            let result = Engine::step(&mut state, &actions).unwrap();
+            let bridge = result.mrr_bridge;
+            assert!((bridge.starting_mrr + bridge.net_new_mrr() - bridge.ending_mrr).abs() < 1e-6);
+        }
+        assert!(state.mrr_history.iter().any(|b| b.new_business > 0.0));
+    }
+
+    #[test]
+    fn test_project_holds_focus_until_done() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 5);
+        let fundraise = Action::Fundraise { target: 250_000.0, instrument: Instrument::PricedRound };
+
+        let first = Engine::step(&mut state, &[fundraise.clone(), Action::TakeBreak]).unwrap();
+        assert!(first.completed_projects.is_empty());
+        assert_eq!(state.projects.len(), 1);
+        assert!(state.investor_pipeline.is_some());
+
+        // The raise still holds two slots, leaving room for one more
+        let ship = Action::ShipFeature { quality: Quality::Balanced };
+        assert!(Engine::step(&mut state, &[Action::TakeBreak, ship]).is_err());
+        assert!(Engine::step(&mut state, &[Action::Fundraise { target: 500_000.0, instrument: Instrument::PricedRound }]).is_err());
+
+        for _ in 2..fundraise.duration_weeks() {
+            assert!(Engine::step(&mut state, &[Action::TakeBreak]).unwrap().completed_projects.is_empty());
+        }
+        let last = Engine::step(&mut state, &[Action::TakeBreak]).unwrap();
+        assert_eq!(last.completed_projects.len(), 1);
+        assert!(state.projects.is_empty());
+        assert!(state.investor_pipeline.is_none());
+    }
+}
@@ -0,0 +1,666 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use std::sync::OnceLock;
+use rand::Rng;
+use serde::Deserialize;
+use super::cap_table::Financing;
+use super::chains::FollowUp;
+use super::competitors::{get_most_threatening_competitor, get_random_competitor, Competitor, FundingStage};
+use super::customers::{generate_customer_persona, get_random_customer, Customer, CustomerSegment};
+use super::billing::RenewalDecision;
+use super::debt::DebtDecision;
+use super::effects::{Effect, EffectOp, Stat};
+use super::investors::RoundDecision;
+use super::events_enhanced::{EnhancedEventType, EventChoice, EventEffect, GameEvent};
+use super::rng::GameRng;
+use super::state::GameState;
+use super::triggers::{Condition, TriggerContext};
+
+/// Event content compiled into the app as a fallback for the files shipped
+/// alongside it, evaluated in this order each week
+const BUNDLED_EVENTS: [(&str, &str); 4] = [
+    ("company.json", include_str!("../../content/events/company.json")),
+    ("customers.json", include_str!("../../content/events/customers.json")),
+    ("competitors.json", include_str!("../../content/events/competitors.json")),
+    ("background.json", include_str!("../../content/events/background.json")),
+];
+
+/// Library installed at startup from the content directory
+static INSTALLED: OnceLock<EventLibrary> = OnceLock::new();
+
+/// Subject numbers an effect can scale by, besides template variables
+const SUBJECT_NUMBERS: [&str; 4] = ["customer.mrr", "competitor.funding_m", "competitor.exit_m", "runway_months"];
+
+/// A set of event templates loaded from content files
+#[derive(Debug, Clone, Default)]
+pub struct EventLibrary {
+    pub templates: Vec<EventTemplate>,
+}
+
+/// An event as written in a content file
+///
+/// Text fields may contain `{placeholders}` naming template variables or
+/// subject fields such as `{customer.company}` and `{competitor.name}`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventTemplate {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    #[serde(default)]
+    pub trigger: Condition,
+    /// Chance (0-1) of firing in a week where the trigger holds; 0 for
+    /// events that only arrive as a follow-up, whose trigger is then checked
+    /// when the follow-up comes due
+    pub probability: f64,
+    pub cooldown_weeks: u32,
+    #[serde(default)]
+    pub subject: Option<Subject>,
+    #[serde(default)]
+    pub vars: BTreeMap<String, VarTemplate>,
+    /// Follow-up for automatic events, and for choices that do not name their own
+    #[serde(default)]
+    pub follow_up: Option<FollowUp>,
+    #[serde(flatten)]
+    pub kind: TemplateKind,
+}
+
+/// Automatic events carry effects; dilemmas carry choices
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateKind {
+    Effects(Vec<EffectTemplate>),
+    Choices(Vec<ChoiceTemplate>),
+}
+
+/// Who an event is about
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Subject {
+    /// A random customer, optionally from one segment; when none exists the
+    /// event is skipped unless `generate` names a segment to create one from
+    Customer {
+        #[serde(default)]
+        segment: Option<CustomerSegment>,
+        #[serde(default)]
+        generate: Option<CustomerSegment>,
+    },
+    RandomCompetitor,
+    MostThreateningCompetitor,
+}
+
+/// A value rolled when the event fires
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum VarTemplate {
+    Range { min: f64, max: f64 },
+    Pick(Vec<String>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChoiceTemplate {
+    pub label: String,
+    pub description: String,
+    pub short_term: String,
+    pub long_term: String,
+    pub wisdom: String,
+    pub effects: Vec<EffectTemplate>,
+    #[serde(default)]
+    pub follow_up: Option<FollowUp>,
+    #[serde(default)]
+    pub financing: Option<Financing>,
+    #[serde(default)]
+    pub round_decision: Option<RoundDecision>,
+    #[serde(default)]
+    pub debt_decision: Option<DebtDecision>,
+    #[serde(default)]
+    pub renewal_decision: Option<RenewalDecision>,
+}
+
+/// An effect whose final amount is `amount × per × difficulty`
+///
+/// Only additive and boost effects scale with difficulty; `per` names a
+/// variable or subject number such as `customer.mrr`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EffectTemplate {
+    pub stat: Stat,
+    #[serde(default = "default_op")]
+    pub op: EffectOp,
+    pub amount: f64,
+    #[serde(default)]
+    pub per: Option<String>,
+    #[serde(default)]
+    pub chance: Option<f64>,
+    pub description: String,
+    /// Left out for an effect that lands this week
+    #[serde(default)]
+    pub delay_weeks: Option<u32>,
+    /// Left out for an effect that lands once
+    #[serde(default)]
+    pub duration_weeks: Option<u32>,
+}
+
+fn default_op() -> EffectOp {
+    EffectOp::Add
+}
+
+#[derive(Debug, Clone)]
+enum Binding {
+    Number(f64),
+    Text(String),
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Binding::Number(value) => write!(f, "{:.0}", value),
+            Binding::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+/// Everything a firing event's text and effects can refer to
+struct Bindings<'a> {
+    state: &'a GameState,
+    customer: Option<Customer>,
+    competitor: Option<Competitor>,
+    vars: BTreeMap<String, Binding>,
+}
+
+impl Bindings<'_> {
+    fn lookup(&self, key: &str) -> Option<Binding> {
+        if let Some(value) = self.vars.get(key) {
+            return Some(value.clone());
+        }
+        let text = |s: &str| Some(Binding::Text(s.to_string()));
+        match key.split_once('.') {
+            Some(("customer", field)) => {
+                let customer = self.customer.as_ref()?;
+                match field {
+                    "name" => text(&customer.name),
+                    "company" => text(&customer.company),
+                    "story" => text(&customer.story),
+                    "feedback" => text(&customer.feedback_history.last()?.quote),
+                    "mrr" => Some(Binding::Number(customer.mrr_contribution)),
+                    _ => None,
+                }
+            }
+            Some(("competitor", field)) => {
+                let competitor = self.competitor.as_ref()?;
+                match field {
+                    "name" => text(&competitor.name),
+                    "tagline" => text(&competitor.tagline),
+                    "funding_m" => Some(Binding::Number(competitor.total_funding / 1_000_000.0)),
+                    "exit_m" => Some(Binding::Number(exit_value_millions(&competitor.funding_stage))),
+                    _ => None,
+                }
+            }
+            _ => match key {
+                "runway_months" => Some(Binding::Number(self.state.runway_months)),
+                "week" => Some(Binding::Number(self.state.week as f64)),
+                _ => None,
+            },
+        }
+    }
+
+    fn number(&self, key: &str) -> f64 {
+        match self.lookup(key) {
+            Some(Binding::Number(value)) => value,
+            _ => 0.0,
+        }
+    }
+
+    /// Replace `{key}` placeholders; unknown keys are left as written
+    fn fill(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            match after.find('}').and_then(|end| self.lookup(&after[..end]).map(|value| (end, value))) {
+                Some((end, value)) => {
+                    out.push_str(&value.to_string());
+                    rest = &after[end + 1..];
+                }
+                None => {
+                    out.push('{');
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Price an acquirer would pay for a competitor, by funding stage
+fn exit_value_millions(stage: &FundingStage) -> f64 {
+    match stage {
+        FundingStage::Bootstrapped => 50.0,
+        FundingStage::Seed => 100.0,
+        FundingStage::SeriesA => 150.0,
+        FundingStage::SeriesB => 200.0,
+        _ => 300.0,
+    }
+}
+
+impl EventLibrary {
+    /// The event content shipped with the app
+    pub fn bundled() -> &'static EventLibrary {
+        static LIBRARY: OnceLock<EventLibrary> = OnceLock::new();
+        LIBRARY.get_or_init(|| {
+            let mut library = EventLibrary::default();
+            for (name, json) in BUNDLED_EVENTS {
+                let loaded = EventLibrary::parse(json).unwrap_or_else(|e| panic!("{}: {}", name, e));
+                library.templates.extend(loaded.templates);
+            }
+            // Follow-ups may point into another file, so check the library as a whole
+            library.validate().unwrap_or_else(|e| panic!("bundled events: {}", e));
+            library
+        })
+    }
+
+    /// The library events are drawn from: content loaded at startup, else the bundled copy
+    pub fn active() -> &'static EventLibrary {
+        INSTALLED.get().unwrap_or_else(Self::bundled)
+    }
+
+    /// Draw events from this library for the rest of the run; only the first install counts
+    pub fn install(library: EventLibrary) -> Result<(), String> {
+        INSTALLED.set(library).map_err(|_| "An event library is already installed".to_string())
+    }
+
+    /// Load every `*.json` content file in a directory
+    ///
+    /// Files the app ships with keep their bundled order; any others follow by name.
+    pub fn load_dir(dir: &Path) -> Result<Self, String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .map_err(|e| format!("{}: {}", dir.display(), e))?
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| name.ends_with(".json"))
+            .collect();
+        let rank = |name: &String| BUNDLED_EVENTS.iter().position(|(bundled, _)| bundled == name).unwrap_or(BUNDLED_EVENTS.len());
+        names.sort_by(|a, b| rank(a).cmp(&rank(b)).then_with(|| a.cmp(b)));
+
+        let mut library = EventLibrary::default();
+        for name in names {
+            let json = std::fs::read_to_string(dir.join(&name)).map_err(|e| format!("{}: {}", name, e))?;
+            let loaded = Self::parse(&json).map_err(|e| format!("{}: {}", name, e))?;
+            library.templates.extend(loaded.templates);
+        }
+        library.validate()?;
+        Ok(library)
+    }
+
+    /// Parse and validate a content file: a JSON array of event templates
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let library = Self::parse(json)?;
+        library.validate()?;
+        Ok(library)
+    }
+
+    fn parse(json: &str) -> Result<Self, String> {
+        let templates = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        Ok(Self { templates })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&EventTemplate> {
+        self.templates.iter().find(|t| t.id == id)
+    }
+
+    /// Check references a content file cannot express in its schema
+    pub fn validate(&self) -> Result<(), String> {
+        let mut ids = HashSet::new();
+        for template in &self.templates {
+            if !ids.insert(template.id.as_str()) {
+                return Err(format!("Duplicate event id '{}'", template.id));
+            }
+        }
+
+        for template in &self.templates {
+            for follow_up in template.follow_ups() {
+                if !ids.contains(follow_up.event_id.as_str()) {
+                    return Err(format!("{}: unknown follow-up event '{}'", template.id, follow_up.event_id));
+                }
+                if follow_up.delay_weeks == 0 {
+                    return Err(format!("{}: follow-up '{}' needs a delay of at least one week", template.id, follow_up.event_id));
+                }
+            }
+            if !(0.0..=1.0).contains(&template.probability) {
+                return Err(format!("{}: probability must be between 0 and 1", template.id));
+            }
+            for financing in template.financings() {
+                if financing.amount <= 0.0 || financing.valuation <= 0.0 {
+                    return Err(format!("{}: financing needs a positive amount and valuation", template.id));
+                }
+            }
+            for (name, var) in &template.vars {
+                match var {
+                    VarTemplate::Range { min, max } if min >= max => {
+                        return Err(format!("{}: variable '{}' needs min below max", template.id, name));
+                    }
+                    VarTemplate::Pick(options) if options.is_empty() => {
+                        return Err(format!("{}: variable '{}' has nothing to pick from", template.id, name));
+                    }
+                    _ => {}
+                }
+            }
+            for effect in template.effect_templates() {
+                if effect.chance.is_some_and(|chance| !(0.0..=1.0).contains(&chance)) {
+                    return Err(format!("{}: effect chance must be between 0 and 1", template.id));
+                }
+                if effect.delay_weeks == Some(0) || effect.duration_weeks == Some(0) {
+                    return Err(format!("{}: effect delay and duration must be at least one week", template.id));
+                }
+                // The cap table can only issue new stakes, so equity is given away, never gained
+                let raises = match effect.op {
+                    EffectOp::Add | EffectOp::Boost => effect.amount > 0.0,
+                    EffectOp::Multiply => effect.amount > 1.0,
+                    EffectOp::Set => false,
+                };
+                if effect.stat == Stat::FounderEquity && raises {
+                    return Err(format!("{}: founder equity can only be given away, not added", template.id));
+                }
+                if let Some(per) = &effect.per {
+                    if !template.vars.contains_key(per) && !SUBJECT_NUMBERS.contains(&per.as_str()) {
+                        return Err(format!("{}: effect scales by unknown value '{}'", template.id, per));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EventTemplate {
+    fn effect_templates(&self) -> Box<dyn Iterator<Item = &EffectTemplate> + '_> {
+        match &self.kind {
+            TemplateKind::Effects(effects) => Box::new(effects.iter()),
+            TemplateKind::Choices(choices) => Box::new(choices.iter().flat_map(|c| c.effects.iter())),
+        }
+    }
+
+    fn follow_ups(&self) -> impl Iterator<Item = &FollowUp> + '_ {
+        let choices: &[ChoiceTemplate] = match &self.kind {
+            TemplateKind::Choices(choices) => choices,
+            TemplateKind::Effects(_) => &[],
+        };
+        self.follow_up.iter().chain(choices.iter().filter_map(|c| c.follow_up.as_ref()))
+    }
+
+    fn financings(&self) -> impl Iterator<Item = &Financing> + '_ {
+        let choices: &[ChoiceTemplate] = match &self.kind {
+            TemplateKind::Choices(choices) => choices,
+            TemplateKind::Effects(_) => &[],
+        };
+        choices.iter().filter_map(|c| c.financing.as_ref())
+    }
+
+    /// Roll this template for the current week, building the event if it fires
+    pub fn try_trigger(&self, state: &GameState, rng: &mut GameRng) -> Option<GameEvent> {
+        if self.probability <= 0.0 {
+            return None;
+        }
+        let (customer, competitor) = self.pick_subject(state, rng);
+        let ctx = TriggerContext::new(state, customer, competitor);
+        if !self.trigger.evaluate(&ctx) || !rng.random_bool(self.probability) {
+            return None;
+        }
+        self.bind(state, customer, competitor, rng)
+    }
+
+    /// Build the event unconditionally, as when a scheduled follow-up comes due
+    pub fn fire(&self, state: &GameState, rng: &mut GameRng) -> Option<GameEvent> {
+        let (customer, competitor) = self.pick_subject(state, rng);
+        self.bind(state, customer, competitor, rng)
+    }
+
+    /// Build the event for a follow-up that has come due
+    ///
+    /// Follow-up-only events still need their trigger to hold, so a chain can
+    /// branch on earlier choices; events that also fire on their own skip it.
+    pub fn fire_follow_up(&self, state: &GameState, rng: &mut GameRng) -> Option<GameEvent> {
+        let (customer, competitor) = self.pick_subject(state, rng);
+        if self.probability <= 0.0 && !self.trigger.evaluate(&TriggerContext::new(state, customer, competitor)) {
+            return None;
+        }
+        self.bind(state, customer, competitor, rng)
+    }
+
+    fn bind(&self, state: &GameState, customer: Option<&Customer>, competitor: Option<&Competitor>, rng: &mut GameRng) -> Option<GameEvent> {
+        let customer = match (&self.subject, customer) {
+            (Some(Subject::Customer { .. }), Some(customer)) => Some(customer.clone()),
+            (Some(Subject::Customer { generate: Some(segment), .. }), None) => {
+                Some(generate_customer_persona(segment.clone(), state.week, state, rng))
+            }
+            (Some(Subject::Customer { .. }), None) => return None,
+            _ => None,
+        };
+        let competitor = match &self.subject {
+            Some(Subject::RandomCompetitor | Subject::MostThreateningCompetitor) => Some(competitor?.clone()),
+            _ => None,
+        };
+        let vars = self
+            .vars
+            .iter()
+            .map(|(name, var)| {
+                let value = match var {
+                    VarTemplate::Range { min, max } => Binding::Number(rng.random_range(*min..*max)),
+                    VarTemplate::Pick(options) => Binding::Text(options[rng.random_range(0..options.len())].clone()),
+                };
+                (name.clone(), value)
+            })
+            .collect();
+
+        let bindings = Bindings { state, customer, competitor, vars };
+        Some(self.build(&bindings, state.difficulty.event_modifier()))
+    }
+
+    fn pick_subject<'a>(&self, state: &'a GameState, rng: &mut GameRng) -> (Option<&'a Customer>, Option<&'a Competitor>) {
+        match &self.subject {
+            Some(Subject::Customer { segment, .. }) => (get_random_customer(&state.customers, segment.clone(), rng), None),
+            Some(Subject::RandomCompetitor) => (None, get_random_competitor(&state.competitors, rng)),
+            Some(Subject::MostThreateningCompetitor) => (None, get_most_threatening_competitor(&state.competitors)),
+            None => (None, None),
+        }
+    }
+
+    fn build(&self, bindings: &Bindings, difficulty_mod: f64) -> GameEvent {
+        let effects = |templates: &[EffectTemplate]| {
+            templates.iter().map(|t| t.build(bindings, difficulty_mod)).collect()
+        };
+        let event_type = match &self.kind {
+            TemplateKind::Effects(templates) => EnhancedEventType::Automatic { effects: effects(templates) },
+            TemplateKind::Choices(choices) => EnhancedEventType::Dilemma {
+                choices: choices
+                    .iter()
+                    .map(|c| EventChoice {
+                        label: bindings.fill(&c.label),
+                        description: bindings.fill(&c.description),
+                        short_term: bindings.fill(&c.short_term),
+                        long_term: bindings.fill(&c.long_term),
+                        wisdom: bindings.fill(&c.wisdom),
+                        effects: effects(&c.effects),
+                        follow_up: c.follow_up.clone().or_else(|| self.follow_up.clone()),
+                        financing: c.financing.clone(),
+                        round_decision: c.round_decision,
+                        debt_decision: c.debt_decision,
+                        renewal_decision: c.renewal_decision,
+                    })
+                    .collect(),
+            },
+        };
+
+        GameEvent {
+            id: self.id.clone(),
+            week: bindings.state.week,
+            title: bindings.fill(&self.title),
+            description: bindings.fill(&self.description),
+            event_type,
+            prerequisites: self.trigger.describe(),
+            cooldown_weeks: self.cooldown_weeks,
+            follow_up_event_id: self.follow_up.as_ref().map(|f| f.event_id.clone()),
+            difficulty_modifier: difficulty_mod,
+        }
+    }
+}
+
+impl EffectTemplate {
+    fn build(&self, bindings: &Bindings, difficulty_mod: f64) -> EventEffect {
+        let mut amount = self.amount;
+        if let Some(per) = &self.per {
+            amount *= bindings.number(per);
+        }
+        if matches!(self.op, EffectOp::Add | EffectOp::Boost) {
+            amount *= difficulty_mod;
+        }
+
+        EventEffect {
+            effect: Effect { stat: self.stat, op: self.op, amount },
+            chance: self.chance,
+            description: bindings.fill(&self.description),
+            delay_weeks: self.delay_weeks.unwrap_or(0),
+            duration_weeks: self.duration_weeks.unwrap_or(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::DifficultyMode;
+
+    #[test]
+    fn test_bundled_content_is_valid() {
+        let library = EventLibrary::bundled();
+
+        assert!(library.templates.len() >= 36);
+        library.validate().unwrap();
+    }
+
+    #[test]
+    fn test_content_dir_loads_in_bundled_order() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("content/events");
+        let loaded = EventLibrary::load_dir(&dir).unwrap();
+
+        let ids = |library: &EventLibrary| library.templates.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&loaded), ids(EventLibrary::bundled()));
+    }
+
+    #[test]
+    fn test_template_fills_placeholders_and_scales_effects() {
+        let library = EventLibrary::from_json(r#"[{
+            "id": "test_deal",
+            "title": "{competitor.name} wants ${deal}",
+            "description": "Runway {runway_months}, {unknown} stays",
+            "probability": 1.0,
+            "cooldown_weeks": 3,
+            "subject": "random_competitor",
+            "vars": {"deal": {"min": 100, "max": 101}},
+            "effects": [
+                {"stat": "MRR", "amount": 2, "per": "deal", "description": "Deal"},
+                {"stat": "Morale", "op": "Set", "amount": 50, "description": "Reset"}
+            ]
+        }]"#).unwrap();
+        library.validate().unwrap();
+
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 4);
+        let mut rng = state.next_rng();
+        let event = library.get("test_deal").unwrap().try_trigger(&state, &mut rng).unwrap();
+
+        assert!(event.title.contains(" wants $10"));
+        assert!(!event.title.starts_with('{'));
+        assert!(event.description.ends_with("{unknown} stays"));
+        match event.event_type {
+            EnhancedEventType::Automatic { effects } => {
+                let deal = effects[0].effect.amount;
+                assert!((240.0..=242.4).contains(&deal), "deal effect was {}", deal);
+                assert_eq!(effects[1].effect, Effect::set(Stat::Morale, 50.0));
+            }
+            _ => panic!("expected an automatic event"),
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_follow_up() {
+        let library = EventLibrary::parse(r#"[{
+            "id": "orphan",
+            "title": "Orphan",
+            "description": "",
+            "probability": 0.5,
+            "cooldown_weeks": 1,
+            "follow_up": {"event_id": "missing", "delay_weeks": 2},
+            "effects": []
+        }]"#).unwrap();
+
+        assert!(library.validate().is_err());
+    }
+
+    #[test]
+    fn test_malformed_vars_are_rejected_on_load() {
+        let template = |vars: &str| format!(r#"[{{
+            "id": "bad_vars",
+            "title": "Deal worth {{deal}}",
+            "description": "",
+            "probability": 0.5,
+            "cooldown_weeks": 1,
+            "vars": {{"deal": {}}},
+            "effects": []
+        }}]"#, vars);
+
+        assert!(EventLibrary::from_json(&template(r#"{"min": 10, "max": 10}"#)).is_err());
+        assert!(EventLibrary::from_json(&template(r#"{"min": 20, "max": 10}"#)).is_err());
+        assert!(EventLibrary::from_json(&template("[]")).is_err());
+        assert!(EventLibrary::from_json(&template(r#"["Acme"]"#)).is_ok());
+    }
+
+    fn with_effect(effect: &str) -> String {
+        format!(r#"[{{
+            "id": "ranged",
+            "title": "Ranged",
+            "description": "",
+            "probability": 0.5,
+            "cooldown_weeks": 1,
+            "effects": [{}]
+        }}]"#, effect)
+    }
+
+    #[test]
+    fn test_effect_chance_outside_unit_range_is_rejected() {
+        let effect = |chance: &str| with_effect(&format!(r#"{{"stat": "Morale", "amount": -5, "chance": {}, "description": ""}}"#, chance));
+
+        assert!(EventLibrary::from_json(&effect("1.5")).is_err());
+        assert!(EventLibrary::from_json(&effect("-0.1")).is_err());
+        assert!(EventLibrary::from_json(&effect("0.5")).is_ok());
+    }
+
+    #[test]
+    fn test_founder_equity_can_only_be_given_away() {
+        let effect = |amount: &str| with_effect(&format!(r#"{{"stat": "Founder Equity", "amount": {}, "description": ""}}"#, amount));
+
+        assert!(EventLibrary::from_json(&effect("5")).is_err());
+        assert!(EventLibrary::from_json(&effect("-5")).is_ok());
+    }
+
+    #[test]
+    fn test_week_counts_below_one_are_rejected() {
+        let effect = |weeks: &str| with_effect(&format!(r#"{{"stat": "Morale", "amount": -2, "description": "", {}}}"#, weeks));
+
+        assert!(EventLibrary::from_json(&effect(r#""delay_weeks": 0"#)).is_err());
+        assert!(EventLibrary::from_json(&effect(r#""duration_weeks": 0"#)).is_err());
+        assert!(EventLibrary::from_json(&effect(r#""duration_weeks": -3"#)).is_err());
+        assert!(EventLibrary::from_json(&effect(r#""delay_weeks": 2, "duration_weeks": 6"#)).is_ok());
+
+        let follow_up = EventLibrary::from_json(r#"[{
+            "id": "instant",
+            "title": "Instant",
+            "description": "",
+            "probability": 0.5,
+            "cooldown_weeks": 1,
+            "follow_up": {"event_id": "instant", "delay_weeks": 0},
+            "effects": []
+        }]"#);
+        assert!(follow_up.is_err());
+    }
+}
@@ -1,9 +1,16 @@
 use serde::{Deserialize, Serialize};
 use rand::Rng;
+use super::rng::GameRng;
+use super::cap_table::{close_financing, Financing};
+use super::investors::{apply_round_decision, RoundDecision};
+use super::debt::{apply_debt_decision, DebtDecision};
+use super::billing::{apply_renewal_decision, RenewalDecision};
+use super::chains::{schedule_follow_up, take_due_events, FollowUp};
+use super::effects::{apply_effects, Effect, ScheduledEffect, StatEffect};
+use super::ledger::{record_bank_change, LedgerCategory};
+use super::event_library::EventLibrary;
 use std::collections::HashMap;
-use super::state::{GameState, DifficultyMode, WeekSnapshot};
-use super::customers::{get_random_customer, CustomerSegment, get_at_risk_customers, CustomerLifecycle};
-use super::competitors::{get_most_threatening_competitor, get_random_competitor, CompetitorActionType};
+use super::state::GameState;
 
 fn can_trigger_event(cooldowns: &HashMap<String, u32>, event_id: &str) -> bool {
     cooldowns
@@ -21,7 +28,7 @@ pub struct GameEvent {
     pub event_type: EnhancedEventType,
     pub prerequisites: Vec<String>, // Human-readable conditions for triggering
     pub cooldown_weeks: u32, // Weeks before this event can trigger again
-    pub follow_up_event_id: Option<String>, // Event to unlock after this one
+    pub follow_up_event_id: Option<String>, // Event this one leads to, unless a choice says otherwise
     pub difficulty_modifier: f64, // Multiplier for effects based on difficulty
 }
 
@@ -36,9 +43,33 @@ pub enum EnhancedEventType {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventEffect {
-    pub stat_name: String,
-    pub change: f64,
+    #[serde(flatten)]
+    pub effect: Effect,
+    /// Probability (0-1) that the effect happens; always applied when None
+    #[serde(default)]
+    pub chance: Option<f64>,
     pub description: String,
+    /// Weeks before the effect first lands
+    #[serde(default)]
+    pub delay_weeks: u32,
+    /// Weeks the effect repeats for, once a week; 0 applies it once
+    #[serde(default)]
+    pub duration_weeks: u32,
+}
+
+impl EventEffect {
+    /// The deferred form of this effect, or None when it applies immediately
+    pub fn schedule_from(&self, week: u32) -> Option<ScheduledEffect> {
+        if self.delay_weeks == 0 && self.duration_weeks == 0 {
+            return None;
+        }
+        Some(ScheduledEffect {
+            effect: self.effect.clone(),
+            description: self.description.clone(),
+            start_week: week + self.delay_weeks,
+            remaining_weeks: self.duration_weeks.max(1),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,2014 +80,62 @@ pub struct EventChoice {
     pub long_term: String,
     pub wisdom: String,
     pub effects: Vec<EventEffect>,
+    /// Event this choice leads to, some weeks later
+    #[serde(default)]
+    pub follow_up: Option<FollowUp>,
+    /// Money this choice brings in, closed as a priced round or convertible
+    #[serde(default)]
+    pub financing: Option<Financing>,
+    /// What this choice does with the term sheets of a raise in progress
+    #[serde(default)]
+    pub round_decision: Option<RoundDecision>,
+    /// What this choice does about loans in breach of a covenant
+    #[serde(default)]
+    pub debt_decision: Option<DebtDecision>,
+    /// Terms offered on annual contracts coming up for renewal
+    #[serde(default)]
+    pub renewal_decision: Option<RenewalDecision>,
 }
 
-/// Check if event conditions are met and generate events
-pub fn check_for_events(state: &mut GameState) -> Vec<GameEvent> {
-    let mut rng = rand::thread_rng();
-    let mut events = Vec::new();
-
-    // Helper to get difficulty modifier
-    let difficulty_mod = match state.difficulty {
-        DifficultyMode::IndieBootstrap => 1.0,
-        DifficultyMode::VCTrack => 1.2,
-        DifficultyMode::RegulatedFintech => 1.5,
-        DifficultyMode::InfraDevTool => 1.3,
-    };
-
-    // Helper to check growth stagnation (for pivot opportunity)
-    let growth_stagnant = state.history.len() >= 8 && state.history.iter().rev().take(8).all(|s| s.momentum < 0.03);
-
-    // 1. Technical Debt Crisis (70%+ tech debt)
-    if state.tech_debt > 70.0 && rng.gen_bool(0.3) && can_trigger_event(&state.event_cooldowns, "tech_debt_crisis") {
-        events.push(GameEvent {
-            id: "tech_debt_crisis".to_string(),
-            week: state.week,
-            title: "Production Outage".to_string(),
-            description: "Technical debt caused a critical outage lasting 3 hours. Customers are frustrated and some are threatening to churn.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "All Hands on Deck".to_string(),
-                        description: "Drop everything and fix it right now. Work through the weekend if needed.".to_string(),
-                        short_term: "Outage resolved quickly".to_string(),
-                        long_term: "Team burnout, morale hit, no time to fix root cause".to_string(),
-                        wisdom: "Crisis mode is expensive. You're treating symptoms, not the disease. This will happen again.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: -15.0 * difficulty_mod,
-                                description: "Team exhausted from fire drill".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: -10.0 * difficulty_mod,
-                                description: "Customers lost trust".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Velocity".to_string(),
-                                change: -0.15 * difficulty_mod,
-                                description: "Lost momentum from context switching".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Proper Fix + Communication".to_string(),
-                        description: "Take time to fix it right. Communicate transparently with customers about what happened and what you're doing.".to_string(),
-                        short_term: "Some customers churn, but most appreciate honesty".to_string(),
-                        long_term: "Root cause fixed, trust built through transparency".to_string(),
-                        wisdom: "Transparency and proper fixes build trust even in failures. Customers respect honesty more than perfection.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: -5.0 * difficulty_mod,
-                                description: "Stressful but managed sustainably".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: -10.0 * difficulty_mod,
-                                description: "Actually fixed the root cause".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 5.0 * difficulty_mod,
-                                description: "Transparency builds trust".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "WAU".to_string(),
-                                change: -50.0 * difficulty_mod,
-                                description: "Some customers left".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Tech debt > 70%".to_string()],
-            cooldown_weeks: 8,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("tech_debt_crisis".to_string(), 8);
-    }
-
-    // 2. Viral Growth Opportunity (high NPS + low tech debt)
-    if state.nps > 60.0 && state.tech_debt < 35.0 && state.wau > 200 && rng.gen_bool(0.15) && can_trigger_event(&state.event_cooldowns, "viral_moment") {
-        // Get a random customer to feature in the viral moment
-        let featured_customer = if let Some(customer) = get_random_customer(&state.customers, None) {
-            customer.clone()
-        } else {
-            super::customers::generate_customer_persona(CustomerSegment::SMB, state.week, state)
-        };
-
-        events.push(GameEvent {
-            id: "viral_moment".to_string(),
-            week: state.week,
-            title: format!("{} Loves Your Product!", featured_customer.company),
-            description: format!(
-                "{} from {} just shared their success story on Twitter: \"{} finally solved our problem!\" It's going viral. Traffic is surging but your infrastructure is at 80% capacity.",
-                featured_customer.name, featured_customer.company, featured_customer.story
-            ),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Scale Infrastructure Quickly".to_string(),
-                        description: "Spend to scale servers and handle the load. Capture all this growth.".to_string(),
-                        short_term: "Massive user growth, increased burn".to_string(),
-                        long_term: "Established user base if you can keep them happy".to_string(),
-                        wisdom: "Good engineering foundations let you seize opportunities. This is why you kept tech debt low.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "WAU".to_string(),
-                                change: 5000.0 * difficulty_mod,
-                                description: "Viral growth captured".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Burn".to_string(),
-                                change: 2000.0 * difficulty_mod,
-                                description: "Infrastructure scaling costs".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 15.0 * difficulty_mod,
-                                description: "Handled growth professionally".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Let It Ride".to_string(),
-                        description: "Current infrastructure should handle most of it. Save the money.".to_string(),
-                        short_term: "Some growth captured, some users experience slowness".to_string(),
-                        long_term: "Missed opportunity, some reputation damage".to_string(),
-                        wisdom: "Penny wise, pound foolish. When opportunity knocks, answer. You built for this moment.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "WAU".to_string(),
-                                change: 2000.0 * difficulty_mod,
-                                description: "Partial growth captured".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: -5.0 * difficulty_mod,
-                                description: "Some users had bad experience".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["NPS > 60".to_string(), "Tech debt < 35%".to_string(), "WAU > 200".to_string()],
-            cooldown_weeks: 12,
-            follow_up_event_id: Some("viral_moment_gone_wrong".to_string()),
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("viral_moment".to_string(), 12);
-    }
-
-    // 3. Major Client Deal (requires sacrifice)
-    if state.mrr > 2000.0 && state.reputation > 50.0 && rng.gen_bool(0.2) && can_trigger_event(&state.event_cooldowns, "major_client_deal") {
-        let deal_size = 5000.0 + rng.gen_range(0.0..3000.0);
-
-        // Get a random enterprise customer or generate a new one
-        let customer = if let Some(existing) = get_random_customer(&state.customers, Some(CustomerSegment::Enterprise)) {
-            existing.clone()
-        } else {
-            super::customers::generate_customer_persona(CustomerSegment::Enterprise, state.week, state)
-        };
-
-        events.push(GameEvent {
-            id: "major_client_deal".to_string(),
-            week: state.week,
-            title: format!("{} Wants to Upgrade", customer.company),
-            description: format!(
-                "{} from {} wants to sign for ${:.0}/month, but they need custom features delivered in 4 weeks. It's aggressive but possible if you cut corners.",
-                customer.name, customer.company, deal_size
-            ),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Take the Deal - Ship Fast".to_string(),
-                        description: "Accept and work weekends to hit the deadline. Cut corners where needed.".to_string(),
-                        short_term: format!("${:.0}/mo MRR, team exhausted, tech debt up", deal_size),
-                        long_term: "Maintenance nightmare, team burnout, quality issues".to_string(),
-                        wisdom: "Short-term revenue can create long-term problems. Today's hacks are tomorrow's outages.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "MRR".to_string(),
-                                change: deal_size * difficulty_mod,
-                                description: "Major client signed".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: -20.0 * difficulty_mod,
-                                description: "Team burned out".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: 25.0 * difficulty_mod,
-                                description: "Corners cut everywhere".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 10.0 * difficulty_mod,
-                                description: "Major logo customer".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Negotiate Realistic Timeline".to_string(),
-                        description: "Counter with 8 weeks for proper implementation, or walk away.".to_string(),
-                        short_term: "Maybe they accept, maybe they walk. Less money but healthy team.".to_string(),
-                        long_term: "Sustainable growth, quality codebase, happy team".to_string(),
-                        wisdom: "The best deals are ones where both sides win. Desperation makes bad deals.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "MRR".to_string(),
-                                change: deal_size * 0.6 * difficulty_mod,
-                                description: "Negotiated deal (might be lower or lost)".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: 5.0 * difficulty_mod,
-                                description: "Team respects your boundaries".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: -5.0 * difficulty_mod,
-                                description: "Time to do it right".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["MRR > $2000".to_string(), "Reputation > 50".to_string()],
-            cooldown_weeks: 10,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("major_client_deal".to_string(), 10);
-    }
-
-    // Customer Churn Event
-    if !get_at_risk_customers(&state.customers).is_empty() && rng.gen_bool(0.25) && can_trigger_event(&state.event_cooldowns, "customer_churn_warning") {
-        if let Some(customer) = get_random_customer(&state.customers, None) {
-            if let Some(latest_feedback) = customer.feedback_history.last() {
-                events.push(GameEvent {
-                    id: "customer_churn_warning".to_string(),
-                    week: state.week,
-                    title: format!("{} is Considering Leaving", customer.company),
-                    description: format!(
-                        "{} from {} hasn't been happy lately. Their feedback: '{}'. They're evaluating alternatives.",
-                        customer.name, customer.company, latest_feedback.quote
-                    ),
-                    event_type: EnhancedEventType::Dilemma {
-                        choices: vec![
-                            EventChoice {
-                                label: "Reach out personally".to_string(),
-                                description: "Call them directly to understand their concerns and offer solutions.".to_string(),
-                                short_term: "Time investment, potential save".to_string(),
-                                long_term: "Stronger relationship, customer retention".to_string(),
-                                wisdom: "Most churn can be prevented with communication. Listen more than you talk.".to_string(),
-                                effects: vec![
-                                    EventEffect {
-                                        stat_name: "Morale".to_string(),
-                                        change: 5.0 * difficulty_mod,
-                                        description: "Meaningful customer interaction".to_string(),
-                                    },
-                                    EventEffect {
-                                        stat_name: "NPS".to_string(),
-                                        change: 5.0 * difficulty_mod,
-                                        description: "Personal outreach".to_string(),
-                                    },
-                                ],
-                            },
-                            EventChoice {
-                                label: "Let them go".to_string(),
-                                description: "Focus on acquiring new customers instead of retaining this one.".to_string(),
-                                short_term: "Free up focus, potential MRR loss".to_string(),
-                                long_term: "Focus on growth, churn happens".to_string(),
-                                wisdom: "Not all customers are worth saving. Sometimes it's better to part ways.".to_string(),
-                                effects: vec![
-                                    EventEffect {
-                                        stat_name: "Focus".to_string(),
-                                        change: 1.0 * difficulty_mod,
-                                        description: "Freed up bandwidth".to_string(),
-                                    },
-                                    EventEffect {
-                                        stat_name: "MRR".to_string(),
-                                        change: -customer.mrr_contribution * difficulty_mod,
-                                        description: "Lost customer revenue".to_string(),
-                                    },
-                                ],
-                            },
-                        ],
-                    },
-                    prerequisites: vec!["At-risk customers exist".to_string()],
-                    cooldown_weeks: 6,
-                    follow_up_event_id: None,
-                    difficulty_modifier: difficulty_mod,
-                });
-                state.event_cooldowns.insert("customer_churn_warning".to_string(), 6);
-            }
-        }
-    }
-
-    // 3. Big Logo Signs Event - New high-MRR enterprise customers
-    if let Some(customer) = get_random_customer(&state.customers, Some(CustomerSegment::Enterprise)) {
-        if matches!(customer.lifecycle_stage, CustomerLifecycle::Active) && customer.mrr_contribution > 5000.0 && rng.gen_bool(0.25) && can_trigger_event(&state.event_cooldowns, "big_logo_signs") {
-            events.push(GameEvent {
-                id: "big_logo_signs".to_string(),
-                week: state.week,
-                title: format!("{} Joins Your Customer Roster", customer.company),
-                description: format!(
-                    "{} from {} just signed up! They're contributing ${:.0}/month and could be great for your credibility. Consider featuring them prominently on your website.",
-                    customer.name, customer.company, customer.mrr_contribution
-                ),
-                event_type: EnhancedEventType::Dilemma {
-                    choices: vec![
-                        EventChoice {
-                            label: "Feature Them Prominently".to_string(),
-                            description: "Add their logo to your homepage and case study. Costs 1 focus slot.".to_string(),
-                            short_term: "Reputation boost, credibility signal".to_string(),
-                            long_term: "Attracts similar customers".to_string(),
-                            wisdom: "Social proof is powerful. Big logos on your site signal legitimacy to prospects.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 15.0 * difficulty_mod,
-                                    description: "Big customer validation".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Focus".to_string(),
-                                    change: -1.0 * difficulty_mod,
-                                    description: "Design and integration work".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Mention in Newsletter".to_string(),
-                            description: "Share their story in your next newsletter. Low effort, some impact.".to_string(),
-                            short_term: "Small reputation gain".to_string(),
-                            long_term: "Organic customer attraction".to_string(),
-                            wisdom: "Every customer success story matters. Share them consistently.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 5.0 * difficulty_mod,
-                                    description: "Customer story sharing".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Keep It Quiet".to_string(),
-                            description: "Don't make a big deal. Focus on serving them well instead.".to_string(),
-                            short_term: "No immediate impact".to_string(),
-                            long_term: "Stronger relationship through service".to_string(),
-                            wisdom: "Sometimes the best marketing is just doing great work.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "NPS".to_string(),
-                                    change: 3.0 * difficulty_mod,
-                                    description: "Focused service".to_string(),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                prerequisites: vec!["New high-MRR enterprise customer".to_string()],
-                cooldown_weeks: 8,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("big_logo_signs".to_string(), 8);
-        }
-    }
-
-    // Customer Champion Event - Customer becomes a champion
-    if let Some(customer) = get_random_customer(&state.customers, None) {
-        if matches!(customer.lifecycle_stage, CustomerLifecycle::Champion) && rng.gen_bool(0.2) && can_trigger_event(&state.event_cooldowns, "customer_champion") {
-            events.push(GameEvent {
-                id: "customer_champion".to_string(),
-                week: state.week,
-                title: format!("{} Becomes Your Biggest Advocate", customer.company),
-                description: format!(
-                    "{} from {} is absolutely thrilled! They're telling everyone about you: \"{}\". They want to help you grow.",
-                    customer.name, customer.company, customer.story
-                ),
-                event_type: EnhancedEventType::Dilemma {
-                    choices: vec![
-                        EventChoice {
-                            label: "Partner with Them for Marketing".to_string(),
-                            description: "Co-create content and case studies. They become your marketing partner.".to_string(),
-                            short_term: "Reputation boost, organic growth".to_string(),
-                            long_term: "Ongoing advocacy, customer acquisition".to_string(),
-                            wisdom: "Happy customers are your best marketers. Invest in relationships that compound.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 20.0 * difficulty_mod,
-                                    description: "Champion advocacy".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "WAU".to_string(),
-                                    change: 300.0 * difficulty_mod,
-                                    description: "Organic referrals".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "NPS".to_string(),
-                                    change: 15.0 * difficulty_mod,
-                                    description: "Social proof".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Ask for a Testimonial".to_string(),
-                            description: "Get a written testimonial for your website. Simple but effective.".to_string(),
-                            short_term: "Small reputation gain".to_string(),
-                            long_term: "Credibility boost for prospects".to_string(),
-                            wisdom: "Testimonials convert browsers to buyers. Collect them systematically.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 8.0 * difficulty_mod,
-                                    description: "Customer testimonial".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "NPS".to_string(),
-                                    change: 5.0 * difficulty_mod,
-                                    description: "Public endorsement".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Focus on Serving Them Well".to_string(),
-                            description: "Keep delivering exceptional service. Let their satisfaction speak for itself.".to_string(),
-                            short_term: "No immediate impact".to_string(),
-                            long_term: "Loyal champion, potential referrals".to_string(),
-                            wisdom: "Sometimes the best marketing is just doing great work consistently.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "NPS".to_string(),
-                                    change: 8.0 * difficulty_mod,
-                                    description: "Continued satisfaction".to_string(),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                prerequisites: vec!["Champion customer exists".to_string()],
-                cooldown_weeks: 10,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("customer_champion".to_string(), 10);
-        }
-    }
+/// Check which events from the active content fire this week
+pub fn check_for_events(state: &mut GameState, rng: &mut GameRng) -> Vec<GameEvent> {
+    let library = EventLibrary::active();
 
-    // Competitor Feature Launch Event
-    if let Some(competitor) = get_most_threatening_competitor(&state.competitors) {
-        if competitor.feature_parity > 70.0 && state.velocity < 1.0 && rng.gen_bool(0.20) && can_trigger_event(&state.event_cooldowns, "competitor_feature_launch") {
-            let features = vec![
-                "advanced analytics", "mobile app", "API integrations", "enterprise SSO",
-                "real-time collaboration", "AI-powered insights", "automated workflows",
-                "advanced security", "custom dashboards", "integrations marketplace"
-            ];
-            let feature_name = features[rng.gen_range(0..features.len())];
-
-            events.push(GameEvent {
-                id: "competitor_feature_launch".to_string(),
-                week: state.week,
-                title: format!("{} Launches Feature You Don't Have", competitor.name),
-                description: format!("{} just shipped {} - a feature your customers have been requesting. They're gaining ground. Your feature parity is falling behind.", competitor.name, feature_name),
-                event_type: EnhancedEventType::Dilemma {
-                    choices: vec![
-                        EventChoice {
-                            label: "Rush to match their feature".to_string(),
-                            description: "Ship fast to stay competitive, but cut corners.".to_string(),
-                            short_term: "Stay competitive quickly".to_string(),
-                            long_term: "Technical debt increases, team burnout".to_string(),
-                            wisdom: "Shipping fast often means shipping debt. Know when speed matters more than quality.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Tech Debt".to_string(),
-                                    change: 15.0 * difficulty_mod,
-                                    description: "Rushed implementation".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Velocity".to_string(),
-                                    change: 0.2 * difficulty_mod,
-                                    description: "Short-term speed boost".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: -5.0 * difficulty_mod,
-                                    description: "Crunch mode".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Build it properly, take time".to_string(),
-                            description: "Do it right, even if it takes longer.".to_string(),
-                            short_term: "Customers notice delay".to_string(),
-                            long_term: "Better product, sustainable velocity".to_string(),
-                            wisdom: "Your long-term competitive advantage is building better software, not matching features.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Velocity".to_string(),
-                                    change: 0.1 * difficulty_mod,
-                                    description: "Proper implementation".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: -10.0 * difficulty_mod,
-                                    description: "Feels slow".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: -5.0 * difficulty_mod,
-                                    description: "Customers notice delay".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Ignore it, focus on differentiation".to_string(),
-                            description: "Double down on what makes you unique.".to_string(),
-                            short_term: "Risk losing customers to competitor".to_string(),
-                            long_term: "Strong positioning, loyal users".to_string(),
-                            wisdom: "You can't be everything to everyone. Focus on being uniquely valuable to your best customers.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: 10.0 * difficulty_mod,
-                                    description: "Confident in differentiation".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 5.0 * difficulty_mod,
-                                    description: "Bold positioning".to_string(),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                prerequisites: vec!["Competitor feature parity > 70%".to_string(), "Your velocity < 1.0".to_string()],
-                cooldown_weeks: 8,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("competitor_feature_launch".to_string(), 8);
-        }
+    // Follow-ups from earlier events always arrive and take the week's first slots
+    let chained = take_due_events(state, library, rng);
+    for event in &chained {
+        state.event_cooldowns.insert(event.id.clone(), event.cooldown_weeks);
     }
 
-    // Pricing War Event
-    if let Some(competitor) = get_random_competitor(&state.competitors) {
-        if matches!(competitor.pricing_strategy, super::competitors::PricingStrategy::Undercut) && rng.gen_bool(0.15) && can_trigger_event(&state.event_cooldowns, "pricing_war") {
-            events.push(GameEvent {
-                id: "pricing_war".to_string(),
-                week: state.week,
-                title: format!("{} Slashes Prices", competitor.name),
-                description: format!("{} just cut their prices by 30%. Your customers are asking why you're more expensive. Some are threatening to switch.", competitor.name),
-                event_type: EnhancedEventType::Dilemma {
-                    choices: vec![
-                        EventChoice {
-                            label: "Match their pricing".to_string(),
-                            description: "Protect market share at the cost of margins.".to_string(),
-                            short_term: "Maintain market share".to_string(),
-                            long_term: "Pressure on profitability".to_string(),
-                            wisdom: "Price wars destroy margins. Only fight them if you have deeper pockets or can operate more efficiently.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "MRR".to_string(),
-                                    change: -0.2 * state.mrr * difficulty_mod,
-                                    description: "Price cut impact".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "NPS".to_string(),
-                                    change: 5.0 * difficulty_mod,
-                                    description: "Customers happy with price".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: -10.0 * difficulty_mod,
-                                    description: "Race to bottom".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Hold pricing, emphasize value".to_string(),
-                            description: "You're worth the premium. Prove it.".to_string(),
-                            short_term: "Lose price-sensitive customers".to_string(),
-                            long_term: "Premium positioning, higher margins".to_string(),
-                            wisdom: "Premium products need premium positioning. Cheap is a strategy, not an accident.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 10.0 * difficulty_mod,
-                                    description: "Premium positioning".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Churn Rate".to_string(),
-                                    change: 10.0 * difficulty_mod,
-                                    description: "Lose price-sensitive customers".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: 5.0 * difficulty_mod,
-                                    description: "Confidence in value".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Raise prices, go upmarket".to_string(),
-                            description: "Bold move: position as premium alternative.".to_string(),
-                            short_term: "Lose SMB customers, gain enterprise".to_string(),
-                            long_term: "Higher MRR per customer, focused sales".to_string(),
-                            wisdom: "Moving upmarket is hard but profitable. You need the sales skills and product to support enterprise customers.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "MRR".to_string(),
-                                    change: 0.15 * state.mrr * difficulty_mod,
-                                    description: "Higher prices".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Churn Rate".to_string(),
-                                    change: -20.0 * difficulty_mod,
-                                    description: "Lose SMB, keep enterprise".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 15.0 * difficulty_mod,
-                                    description: "Premium brand".to_string(),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                prerequisites: vec!["Competitor uses undercut pricing".to_string()],
-                cooldown_weeks: 10,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("pricing_war".to_string(), 10);
-        }
-    }
-
-    // Competitor Funding Announcement Event
-    if let Some(competitor) = get_random_competitor(&state.competitors) {
-        if competitor.action_history.iter().any(|a| matches!(a.action_type, CompetitorActionType::FundingRound)) && rng.gen_bool(0.25) && can_trigger_event(&state.event_cooldowns, "competitor_funding") {
-            let funding_amount = competitor.total_funding / 1_000_000.0;
-
-            events.push(GameEvent {
-                id: "competitor_funding".to_string(),
-                week: state.week,
-                title: format!("{} Raises ${:.0}M", competitor.name, funding_amount),
-                description: format!("{} just announced a ${:.0}M funding round. They're hiring aggressively and planning a major marketing push. Your investors are asking about your plans.", competitor.name, funding_amount),
-                event_type: EnhancedEventType::Dilemma {
-                    choices: vec![
-                        EventChoice {
-                            label: "Accelerate fundraising".to_string(),
-                            description: "Use their news as urgency to close your round.".to_string(),
-                            short_term: "Momentum for fundraising".to_string(),
-                            long_term: "Pressure to grow fast".to_string(),
-                            wisdom: "Competition creates fundraising urgency. Use it, but don't let it control your timeline.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 10.0 * difficulty_mod,
-                                    description: "Fundraising momentum".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: -5.0 * difficulty_mod,
-                                    description: "Pressure".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Focus on profitability".to_string(),
-                            description: "Prove you don't need to raise. Build a sustainable business.".to_string(),
-                            short_term: "Investor skepticism".to_string(),
-                            long_term: "Customer-funded independence".to_string(),
-                            wisdom: "Bootstrapping is harder but creates real optionality. Funded companies often can't say no to growth.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: 15.0 * difficulty_mod,
-                                    description: "Independence pride".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: -10.0 * difficulty_mod,
-                                    description: "Investor worries".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Velocity".to_string(),
-                                    change: 0.1 * difficulty_mod,
-                                    description: "Focus on product".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Ignore the noise".to_string(),
-                            description: "Don't commit either way. Preserve optionality.".to_string(),
-                            short_term: "No immediate impact".to_string(),
-                            long_term: "Keep all options open".to_string(),
-                            wisdom: "Sometimes the best strategy is patience. Let others define themselves before you react.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: 5.0 * difficulty_mod,
-                                    description: "Zen approach".to_string(),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                prerequisites: vec!["Competitor recently raised funding".to_string()],
-                cooldown_weeks: 12,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("competitor_funding".to_string(), 12);
+    let mut events = Vec::new();
+    for template in &library.templates {
+        if !can_trigger_event(&state.event_cooldowns, &template.id) {
+            continue;
         }
-    }
-
-    // Competitor Acquisition Event
-    if state.mrr > 50_000.0 && state.reputation > 60.0 && state.nps > 40.0 && rng.gen_bool(0.10) && can_trigger_event(&state.event_cooldowns, "competitor_acquisition_opportunity") {
-        if let Some(competitor) = get_random_competitor(&state.competitors) {
-            let acquisition_amount = match competitor.funding_stage {
-                super::competitors::FundingStage::Bootstrapped => 50.0,
-                super::competitors::FundingStage::Seed => 100.0,
-                super::competitors::FundingStage::SeriesA => 150.0,
-                super::competitors::FundingStage::SeriesB => 200.0,
-                _ => 300.0,
-            };
-
-            events.push(GameEvent {
-                id: "competitor_acquisition_opportunity".to_string(),
-                week: state.week,
-                title: format!("{} Acquired for ${:.0}M", competitor.name, acquisition_amount),
-                description: format!("{} was just acquired by [BigCorp] for ${:.0}M. The industry is consolidating. Your investors are asking if you'd consider acquisition offers.", competitor.name, acquisition_amount),
-                event_type: EnhancedEventType::Dilemma {
-                    choices: vec![
-                        EventChoice {
-                            label: "Signal openness to acquisition".to_string(),
-                            description: "Let it be known you're open to the right offer.".to_string(),
-                            short_term: "Acquisition interest increases".to_string(),
-                            long_term: "Potential acquisition offers".to_string(),
-                            wisdom: "Being open to acquisition can be strategic, but it changes how people interact with you.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 20.0 * difficulty_mod,
-                                    description: "Acquisition interest".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: -10.0 * difficulty_mod,
-                                    description: "Team worries".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Publicly commit to independence".to_string(),
-                            description: "You're building for the long term, not a quick exit.".to_string(),
-                            short_term: "Some investors exit".to_string(),
-                            long_term: "Focused on long-term vision".to_string(),
-                            wisdom: "Public commitments matter. Saying you're independent signals you're serious about the long game.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: 15.0 * difficulty_mod,
-                                    description: "Mission-driven".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Reputation".to_string(),
-                                    change: 10.0 * difficulty_mod,
-                                    description: "Bold independence".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Stay quiet, keep options open".to_string(),
-                            description: "Don't commit either way. Preserve optionality.".to_string(),
-                            short_term: "No immediate impact".to_string(),
-                            long_term: "Maximum flexibility".to_string(),
-                            wisdom: "Optionality is valuable. Don't burn bridges or close doors prematurely.".to_string(),
-                            effects: vec![
-                                // No effects - preserve optionality
-                            ],
-                        },
-                    ],
-                },
-                prerequisites: vec!["Strong company metrics".to_string(), "Industry consolidation".to_string()],
-                cooldown_weeks: 16,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("competitor_acquisition_opportunity".to_string(), 16);
+        if let Some(event) = template.try_trigger(state, rng) {
+            state.event_cooldowns.insert(template.id.clone(), template.cooldown_weeks);
+            events.push(event);
         }
     }
 
-    // Talent Poaching Event
-    if let Some(competitor) = get_random_competitor(&state.competitors) {
-        if matches!(competitor.funding_stage, super::competitors::FundingStage::SeriesA | super::competitors::FundingStage::SeriesB | super::competitors::FundingStage::SeriesC | super::competitors::FundingStage::PublicCompany) && state.morale > 70.0 && rng.gen_bool(0.12) && can_trigger_event(&state.event_cooldowns, "talent_poaching") {
-            events.push(GameEvent {
-                id: "talent_poaching".to_string(),
-                week: state.week,
-                title: format!("{} Poaching Your Team", competitor.name),
-                description: format!("{} is recruiting your engineers with 50% salary bumps and equity packages. You've already lost one person. Others are getting calls.", competitor.name),
-                event_type: EnhancedEventType::Dilemma {
-                    choices: vec![
-                        EventChoice {
-                            label: "Match their offers".to_string(),
-                            description: "Expensive, but keeps the team intact.".to_string(),
-                            short_term: "Team stays, burn increases".to_string(),
-                            long_term: "Sustainable but costly".to_string(),
-                            wisdom: "Talent wars are expensive. Sometimes it's cheaper to let people go and hire differently.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Burn".to_string(),
-                                    change: 0.3 * state.burn * difficulty_mod,
-                                    description: "Salary increases".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: 10.0 * difficulty_mod,
-                                    description: "Feel valued".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Improve culture, not compensation".to_string(),
-                            description: "People stay for mission, not just money.".to_string(),
-                            short_term: "Some team members leave".to_string(),
-                            long_term: "More committed remaining team".to_string(),
-                            wisdom: "Culture beats compensation long-term. The best people want to work on something meaningful.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: 5.0 * difficulty_mod,
-                                    description: "Mission focus".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Velocity".to_string(),
-                                    change: 0.1 * difficulty_mod,
-                                    description: "More committed team".to_string(),
-                                },
-                            ],
-                        },
-                        EventChoice {
-                            label: "Let them go, hire differently".to_string(),
-                            description: "Painful transition, but opportunity to rebuild.".to_string(),
-                            short_term: "Team disruption, velocity hit".to_string(),
-                            long_term: "Fresh perspectives, cost control".to_string(),
-                            wisdom: "Sometimes you need to let go to grow. New people bring new energy and ideas.".to_string(),
-                            effects: vec![
-                                EventEffect {
-                                    stat_name: "Morale".to_string(),
-                                    change: -20.0 * difficulty_mod,
-                                    description: "Feels like giving up".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Velocity".to_string(),
-                                    change: -0.2 * difficulty_mod,
-                                    description: "Short-term disruption".to_string(),
-                                },
-                                EventEffect {
-                                    stat_name: "Burn".to_string(),
-                                    change: -0.1 * state.burn * difficulty_mod,
-                                    description: "Hire junior talent".to_string(),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                prerequisites: vec!["Well-funded competitor".to_string(), "High team morale".to_string()],
-                cooldown_weeks: 10,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("talent_poaching".to_string(), 10);
-        }
+    // Allow 0-2 events per week
+    let open_slots = 2usize.saturating_sub(chained.len());
+    if events.len() > open_slots {
+        // Randomly select the events that fit
+        use rand::seq::SliceRandom;
+        events.shuffle(rng);
+        events.truncate(open_slots);
     }
+    let events = [chained, events].concat();
 
-    // Competitor Product Pivot Event
-    if let Some(competitor) = get_random_competitor(&state.competitors) {
-        if competitor.feature_parity < 40.0 && rng.gen_bool(0.08) && can_trigger_event(&state.event_cooldowns, "competitor_pivot") {
-            events.push(GameEvent {
-                id: "competitor_pivot".to_string(),
-                week: state.week,
-                title: format!("{} Pivots Away from Your Market", competitor.name),
-                description: format!("{} announced they're pivoting to a different market. One less competitor to worry about - or a sign that your market isn't as attractive as you thought?", competitor.name),
-                event_type: EnhancedEventType::Automatic {
-                    effects: vec![
-                        EventEffect {
-                            stat_name: "Morale".to_string(),
-                            change: 10.0 * difficulty_mod,
-                            description: "One less threat".to_string(),
-                        },
-                        EventEffect {
-                            stat_name: "Reputation".to_string(),
-                            change: 5.0 * difficulty_mod,
-                            description: "Market validation".to_string(),
-                        },
-                    ],
-                },
-                prerequisites: vec!["Struggling competitor".to_string()],
-                cooldown_weeks: 20,
-                follow_up_event_id: None,
-                difficulty_modifier: difficulty_mod,
-            });
-            state.event_cooldowns.insert("competitor_pivot".to_string(), 20);
+    // Automatic events have no choice to carry their follow-up, so queue it now
+    for event in &events {
+        if let EnhancedEventType::Automatic { .. } = event.event_type {
+            if let Some(follow_up) = library.get(&event.id).and_then(|t| t.follow_up.as_ref()) {
+                schedule_follow_up(state, &event.id, follow_up);
+            }
         }
     }
-    if state.runway_months > 18.0 && state.wau > 500 && state.reputation > 60.0 && rng.gen_bool(0.15) && can_trigger_event(&state.event_cooldowns, "vc_offer") {
-        let offer_amount = 2_000_000.0;
-        let valuation = 10_000_000.0;
-
-        events.push(GameEvent {
-            id: "vc_offer".to_string(),
-            week: state.week,
-            title: "VC Term Sheet".to_string(),
-            description: format!(
-                "A reputable VC offers ${:.1}M at ${:.0}M valuation. You have {} months runway. Do you need the money?",
-                offer_amount / 1_000_000.0,
-                valuation / 1_000_000.0,
-                state.runway_months as u32
-            ),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Take the Money - Growth Mode".to_string(),
-                        description: "Accept the funding. Hire fast, spend on growth, go big.".to_string(),
-                        short_term: "Huge cash injection, pressure to grow fast".to_string(),
-                        long_term: "Treadmill of fundraising, lose control, exit pressure".to_string(),
-                        wisdom: "Funding is jet fuel: powerful but expensive. Once you take VC money, you're on their timeline. Make sure you want the ride.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Bank".to_string(),
-                                change: offer_amount * difficulty_mod,
-                                description: "Cash in bank".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Founder Equity".to_string(),
-                                change: -20.0 * difficulty_mod,
-                                description: "Dilution".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Burn".to_string(),
-                                change: state.burn * 2.0 * difficulty_mod,
-                                description: "Growth spending".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 15.0 * difficulty_mod,
-                                description: "VC backing validates you".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Stay Bootstrapped".to_string(),
-                        description: "Pass on the offer. Keep building sustainably with customers funding you.".to_string(),
-                        short_term: "Keep full control, slower growth, more runway stress".to_string(),
-                        long_term: "Full control, customer-funded, own your destiny".to_string(),
-                        wisdom: "Constraints breed creativity. Profitability is a superpower. Customer-funded growth is sustainable growth.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: 10.0 * difficulty_mod,
-                                description: "Team proud of independence".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Focus".to_string(),
-                                change: 1.0 * difficulty_mod,
-                                description: "Clarity without external pressure".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Runway > 18 months".to_string(), "WAU > 500".to_string(), "Reputation > 60".to_string()],
-            cooldown_weeks: 16,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("vc_offer".to_string(), 16);
-    }
-
-    // 5. Key Employee Burnout
-    if state.morale < 50.0 && state.week > 12 && rng.gen_bool(0.25) && can_trigger_event(&state.event_cooldowns, "key_employee_burnout") {
-        events.push(GameEvent {
-            id: "key_employee_burnout".to_string(),
-            week: state.week,
-            title: "Senior Engineer Exhausted".to_string(),
-            description: "Your best engineer, who built most of the core system, comes to you looking exhausted. They're on the edge of quitting.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Push Through - We're So Close".to_string(),
-                        description: "Motivate them to stick it out. Offer bonus, equity bump, promise it'll get better.".to_string(),
-                        short_term: "They stay but are disengaged".to_string(),
-                        long_term: "They quit in 3 months, but bitter. Bad Glassdoor review. Others demoralized.".to_string(),
-                        wisdom: "You can't buy back burned out people. Money doesn't fix exhaustion. They'll leave anyway, just later and angrier.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: -15.0 * difficulty_mod,
-                                description: "Team sees you don't care about health".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Velocity".to_string(),
-                                change: -0.2 * difficulty_mod,
-                                description: "Disengaged engineer slows everything".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: -10.0 * difficulty_mod,
-                                description: "Word spreads about culture".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Give Them a Real Break".to_string(),
-                        description: "Mandate 2 weeks PTO. Tell them you value them healthy over heroic. Mean it.".to_string(),
-                        short_term: "Velocity dip while they're gone".to_string(),
-                        long_term: "They come back refreshed and loyal. Team sees you care. Culture strengthened.".to_string(),
-                        wisdom: "Rest isn't weakness. It's strategic. Better decisions come from rested minds. You can't pour from an empty cup.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: 25.0 * difficulty_mod,
-                                description: "Team sees you care about people".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Velocity".to_string(),
-                                change: -0.1 * difficulty_mod,
-                                description: "Short-term hit while they're out".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 5.0 * difficulty_mod,
-                                description: "Word spreads about good culture".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Morale < 50".to_string(), "Week > 12".to_string()],
-            cooldown_weeks: 12,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("key_employee_burnout".to_string(), 12);
-    }
-
-    // 6. Competitor Launch (random at any time)
-    if state.week > 8 && rng.gen_bool(0.1) && can_trigger_event(&state.event_cooldowns, "competitor_launch") {
-        events.push(GameEvent {
-            id: "competitor_launch".to_string(),
-            week: state.week,
-            title: "Well-Funded Competitor Launches".to_string(),
-            description: "A competitor with $10M in funding just launched. They're undercutting your price and have flashy marketing.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "WAU Growth".to_string(),
-                        change: -5.0 * difficulty_mod,
-                        description: "Market attention split".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Morale".to_string(),
-                        change: -5.0 * difficulty_mod,
-                        description: "Team worried about competition".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec!["Week > 8".to_string()],
-            cooldown_weeks: 6,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("competitor_launch".to_string(), 6);
-    }
-
-    // New Strategic Dilemmas
-
-    // 1. Pivot Opportunity
-    if growth_stagnant && rng.gen_bool(0.4) && can_trigger_event(&state.event_cooldowns, "pivot_opportunity") {
-        events.push(GameEvent {
-            id: "pivot_opportunity".to_string(),
-            week: state.week,
-            title: "Growth Stagnation Crisis".to_string(),
-            description: "Your growth has been below 3% for 8 weeks. The market might be signaling it's time for a change.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Pivot to New Market".to_string(),
-                        description: "Reset WAU to 50% and start fresh in a new market segment.".to_string(),
-                        short_term: "WAU halved, reputation boost".to_string(),
-                        long_term: "Fresh start, potential new growth".to_string(),
-                        wisdom: "Pivots are expensive but sometimes necessary. Know when to persevere vs pivot.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "WAU".to_string(),
-                                change: -(state.wau as f64 * 0.5) * difficulty_mod,
-                                description: "Reset to new market".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 50.0 * difficulty_mod,
-                                description: "Bold strategic move".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: -20.0 * difficulty_mod,
-                                description: "Uncertainty from pivot".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Double Down on Current Strategy".to_string(),
-                        description: "Commit fully to your current path with extra focus slots.".to_string(),
-                        short_term: "Extra focus slot, reputation hit".to_string(),
-                        long_term: "Either breakthrough or failure".to_string(),
-                        wisdom: "Sometimes perseverance pays off. But know when it's stubbornness.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Focus".to_string(),
-                                change: 1.0 * difficulty_mod,
-                                description: "Extra focus for strategy".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: -10.0 * difficulty_mod,
-                                description: "Market sees indecision".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Growth < 3% for 8 weeks".to_string()],
-            cooldown_weeks: 16,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("pivot_opportunity".to_string(), 16);
-    }
-
-    // 2. Acquisition Offer
-    if state.reputation > 70.0 && state.mrr > 50_000.0 && rng.gen_bool(0.2) && can_trigger_event(&state.event_cooldowns, "acquisition_offer") {
-        events.push(GameEvent {
-            id: "acquisition_offer".to_string(),
-            week: state.week,
-            title: "Strategic Acquisition Offer".to_string(),
-            description: "A larger company offers $2M to acquire your startup. It's a life-changing amount.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Accept the Offer".to_string(),
-                        description: "Take the $2M and end the game. Calculate your final score.".to_string(),
-                        short_term: "Game ends with acquisition".to_string(),
-                        long_term: "Financial security, but journey ends".to_string(),
-                        wisdom: "Every founder faces this. There's no wrong answer, only what's right for you.".to_string(),
-                        effects: vec![
-                            // Special handling for game end
-                            EventEffect {
-                                stat_name: "Game End".to_string(),
-                                change: 1.0,
-                                description: "Acquisition exit".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Decline and Keep Building".to_string(),
-                        description: "Reject the offer and continue your entrepreneurial journey.".to_string(),
-                        short_term: "Morale boost, reputation gain".to_string(),
-                        long_term: "Continued pressure to perform".to_string(),
-                        wisdom: "The journey is the reward. Some stories are worth finishing.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: 20.0 * difficulty_mod,
-                                description: "Proud of independence".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 15.0 * difficulty_mod,
-                                description: "Rejected acquisition".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Reputation > 70".to_string(), "MRR > $50k".to_string()],
-            cooldown_weeks: 20,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("acquisition_offer".to_string(), 20);
-    }
-
-    // 3. Key Partnership
-    if state.reputation > 60.0 && rng.gen_bool(0.15) && can_trigger_event(&state.event_cooldowns, "key_partnership") {
-        events.push(GameEvent {
-            id: "key_partnership".to_string(),
-            week: state.week,
-            title: "Strategic Partnership Opportunity".to_string(),
-            description: "A complementary company offers a partnership. Exclusive deal for $20k MRR but locks you in.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Accept Exclusive Partnership".to_string(),
-                        description: "Take the $20k MRR but give up 30% equity and flexibility.".to_string(),
-                        short_term: "Revenue boost, equity dilution".to_string(),
-                        long_term: "Locked in partnership".to_string(),
-                        wisdom: "Strategic partnerships can accelerate growth but limit optionality.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "MRR".to_string(),
-                                change: 20_000.0 * difficulty_mod,
-                                description: "Partnership revenue".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Founder Equity".to_string(),
-                                change: -30.0 * difficulty_mod,
-                                description: "Equity for partnership".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Non-Exclusive Agreement".to_string(),
-                        description: "Take $8k MRR but keep full flexibility and equity.".to_string(),
-                        short_term: "Less revenue, keep options open".to_string(),
-                        long_term: "Flexible but slower growth".to_string(),
-                        wisdom: "Flexibility is valuable. Don't trade long-term options for short-term gains.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "MRR".to_string(),
-                                change: 8_000.0 * difficulty_mod,
-                                description: "Non-exclusive revenue".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Reputation > 60".to_string()],
-            cooldown_weeks: 12,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("key_partnership".to_string(), 12);
-    }
-
-    // 4. Team Conflict
-    if state.morale < 60.0 && state.team_size > 3 && rng.gen_bool(0.3) && can_trigger_event(&state.event_cooldowns, "team_conflict") {
-        events.push(GameEvent {
-            id: "team_conflict".to_string(),
-            week: state.week,
-            title: "Major Team Conflict".to_string(),
-            description: "Your sales lead and engineering lead are in a heated disagreement about product direction.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Side with Engineer".to_string(),
-                        description: "Support the technical vision, keep velocity but lose sales person.".to_string(),
-                        short_term: "Velocity maintained, sales person quits".to_string(),
-                        long_term: "Technical excellence, revenue dip".to_string(),
-                        wisdom: "Culture conflicts compound. Address early or they metastasize.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Velocity".to_string(),
-                                change: 0.1 * difficulty_mod,
-                                description: "Technical focus".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "MRR".to_string(),
-                                change: -5_000.0 * difficulty_mod,
-                                description: "Lost sales person".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Side with Sales".to_string(),
-                        description: "Support the revenue focus, keep sales but lose engineer.".to_string(),
-                        short_term: "Revenue maintained, engineer quits".to_string(),
-                        long_term: "Revenue growth, technical debt".to_string(),
-                        wisdom: "Sometimes you have to choose between competing priorities.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "MRR".to_string(),
-                                change: 5_000.0 * difficulty_mod,
-                                description: "Sales focus".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: 15.0 * difficulty_mod,
-                                description: "Lost technical leadership".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Mediate and Find Compromise".to_string(),
-                        description: "Spend time mediating, both stay but morale hit and focus reduced.".to_string(),
-                        short_term: "Both stay, morale drop, less focus".to_string(),
-                        long_term: "Team learns conflict resolution".to_string(),
-                        wisdom: "Great leaders don't pick sides, they find solutions.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: -15.0 * difficulty_mod,
-                                description: "Conflict resolution stress".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Focus".to_string(),
-                                change: -1.0 * difficulty_mod,
-                                description: "Time spent mediating".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Morale < 60".to_string(), "Team size > 3".to_string()],
-            cooldown_weeks: 10,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("team_conflict".to_string(), 10);
-    }
-
-    // 5. Press Opportunity
-    if state.wau > 1000 && state.reputation > 50.0 && rng.gen_bool(0.2) && can_trigger_event(&state.event_cooldowns, "press_opportunity") {
-        events.push(GameEvent {
-            id: "press_opportunity".to_string(),
-            week: state.week,
-            title: "Major Press Interview".to_string(),
-            description: "A top-tier publication wants to interview you. It could be huge exposure.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Accept the Interview".to_string(),
-                        description: "Spend 2 focus slots on preparation and the interview.".to_string(),
-                        short_term: "Reputation boost, WAU growth".to_string(),
-                        long_term: "Increased visibility".to_string(),
-                        wisdom: "Press is powerful but time-consuming. Choose your moments.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: 30.0 * difficulty_mod,
-                                description: "Major press coverage".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "WAU".to_string(),
-                                change: 500.0 * difficulty_mod,
-                                description: "Press-driven growth".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Focus".to_string(),
-                                change: -2.0 * difficulty_mod,
-                                description: "Time spent on press".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Decline Politely".to_string(),
-                        description: "Pass on the interview to focus on shipping.".to_string(),
-                        short_term: "Small reputation hit, velocity boost".to_string(),
-                        long_term: "Stay focused on product".to_string(),
-                        wisdom: "Not all opportunities are worth pursuing. Focus is your scarcest resource.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: -5.0 * difficulty_mod,
-                                description: "Missed opportunity".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Velocity".to_string(),
-                                change: 0.1 * difficulty_mod,
-                                description: "Extra focus on product".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["WAU > 1000".to_string(), "Reputation > 50".to_string()],
-            cooldown_weeks: 14,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("press_opportunity".to_string(), 14);
-    }
-
-    // 6. Technical Rewrite
-    if state.tech_debt > 80.0 && state.velocity < 0.5 && rng.gen_bool(0.35) && can_trigger_event(&state.event_cooldowns, "technical_rewrite") {
-        events.push(GameEvent {
-            id: "technical_rewrite".to_string(),
-            week: state.week,
-            title: "Technical Debt Crisis".to_string(),
-            description: "Your codebase is a mess. Velocity is suffering. Time for a major rewrite?".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Full Rewrite".to_string(),
-                        description: "4 weeks of no progress to rebuild from scratch.".to_string(),
-                        short_term: "Tech debt cleared, WAU growth halted".to_string(),
-                        long_term: "Clean codebase, high velocity".to_string(),
-                        wisdom: "Rewrites are tempting but risky. Usually incremental wins.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: -60.0 * difficulty_mod,
-                                description: "Complete rewrite".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "WAU Growth".to_string(),
-                                change: -40.0 * difficulty_mod,
-                                description: "No progress during rewrite".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Incremental Refactor".to_string(),
-                        description: "8 weeks of slower progress to gradually improve.".to_string(),
-                        short_term: "Partial debt reduction, velocity hit".to_string(),
-                        long_term: "Steady improvement".to_string(),
-                        wisdom: "Slow and steady often wins the technical debt race.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: -30.0 * difficulty_mod,
-                                description: "Incremental improvements".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Velocity".to_string(),
-                                change: -0.1 * difficulty_mod,
-                                description: "Slower during refactor".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Keep Patching".to_string(),
-                        description: "Continue with band-aids. Tech debt will worsen.".to_string(),
-                        short_term: "No immediate changes".to_string(),
-                        long_term: "Increasing incidents, velocity decline".to_string(),
-                        wisdom: "Sometimes the cost of fixing exceeds the cost of living with it.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: 5.0 * difficulty_mod,
-                                description: "More debt from patches".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Tech debt > 80".to_string(), "Velocity < 0.5".to_string()],
-            cooldown_weeks: 18,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("technical_rewrite".to_string(), 18);
-    }
-
-    // 7. Competitor Acquisition
-    if state.week > 20 && rng.gen_bool(0.1) && can_trigger_event(&state.event_cooldowns, "competitor_acquisition") {
-        events.push(GameEvent {
-            id: "competitor_acquisition".to_string(),
-            week: state.week,
-            title: "Competitor Acquisition Opportunity".to_string(),
-            description: "You can acquire a struggling competitor for $100k. They have 500 users.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Acquire the Competitor".to_string(),
-                        description: "Spend $100k to buy them out and integrate their users.".to_string(),
-                        short_term: "WAU boost, burn increase, tech debt".to_string(),
-                        long_term: "Market consolidation".to_string(),
-                        wisdom: "Acquisitions are complex. Integration is harder than the deal.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "WAU".to_string(),
-                                change: 500.0 * difficulty_mod,
-                                description: "Acquired users".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Bank".to_string(),
-                                change: -100_000.0 * difficulty_mod,
-                                description: "Acquisition cost".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Burn".to_string(),
-                                change: 15_000.0 * difficulty_mod,
-                                description: "Integration costs".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Tech Debt".to_string(),
-                                change: 20.0 * difficulty_mod,
-                                description: "Integration complexity".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Compete Head-On".to_string(),
-                        description: "Let them fail and capture their market share organically.".to_string(),
-                        short_term: "WAU growth hit temporarily".to_string(),
-                        long_term: "Organic growth".to_string(),
-                        wisdom: "Sometimes the best acquisitions are the ones you don't make.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "WAU Growth".to_string(),
-                                change: -10.0 * difficulty_mod,
-                                description: "Market competition".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Week > 20".to_string()],
-            cooldown_weeks: 15,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("competitor_acquisition".to_string(), 15);
-    }
-
-    // 8. Regulatory Audit
-    if matches!(state.difficulty, DifficultyMode::RegulatedFintech) && state.compliance_risk > 60.0 && rng.gen_bool(0.4) && can_trigger_event(&state.event_cooldowns, "regulatory_audit") {
-        events.push(GameEvent {
-            id: "regulatory_audit".to_string(),
-            week: state.week,
-            title: "Regulatory Audit".to_string(),
-            description: "Regulators are auditing your compliance. Risk of fines or shutdown.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Full Compliance Sprint".to_string(),
-                        description: "Dedicate 3 focus slots and $30k to pass with flying colors.".to_string(),
-                        short_term: "Compliance risk cleared, costs".to_string(),
-                        long_term: "Regulatory approval".to_string(),
-                        wisdom: "Compliance isn't optional. Cutting corners creates existential risk.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Compliance Risk".to_string(),
-                                change: -50.0 * difficulty_mod,
-                                description: "Full compliance".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Bank".to_string(),
-                                change: -30_000.0 * difficulty_mod,
-                                description: "Compliance costs".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Focus".to_string(),
-                                change: -3.0 * difficulty_mod,
-                                description: "Compliance work".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Minimal Compliance".to_string(),
-                        description: "Do the bare minimum. 30% chance of fine.".to_string(),
-                        short_term: "Partial risk reduction, possible fine".to_string(),
-                        long_term: "Ongoing regulatory risk".to_string(),
-                        wisdom: "Sometimes you roll the dice. But know the stakes.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Compliance Risk".to_string(),
-                                change: -20.0 * difficulty_mod,
-                                description: "Minimal compliance".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Bank".to_string(),
-                                change: -10_000.0 * difficulty_mod,
-                                description: "Basic compliance costs".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Difficulty: RegulatedFintech".to_string(), "Compliance risk > 60".to_string()],
-            cooldown_weeks: 12,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("regulatory_audit".to_string(), 12);
-    }
-
-    // 9. Viral Moment Gone Wrong
-    if state.wau_growth_rate > 30.0 && rng.gen_bool(0.25) && can_trigger_event(&state.event_cooldowns, "viral_moment_gone_wrong") {
-        events.push(GameEvent {
-            id: "viral_moment_gone_wrong".to_string(),
-            week: state.week,
-            title: "Viral Growth Overload".to_string(),
-            description: "Your viral moment is overwhelming your infrastructure. Users are experiencing outages.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Scale Infrastructure Fast".to_string(),
-                        description: "Spend $50k immediately to handle the load.".to_string(),
-                        short_term: "Growth sustained, high costs".to_string(),
-                        long_term: "Captured viral users".to_string(),
-                        wisdom: "Viral growth is a blessing and curse. Infrastructure matters.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Bank".to_string(),
-                                change: -50_000.0 * difficulty_mod,
-                                description: "Emergency scaling".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Let It Crash".to_string(),
-                        description: "Can't afford to scale. Many users will leave.".to_string(),
-                        short_term: "40% user loss, reputation hit".to_string(),
-                        long_term: "Sustainable but smaller base".to_string(),
-                        wisdom: "Not all growth is worth capturing. Quality over quantity.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "WAU".to_string(),
-                                change: -(state.wau as f64 * 0.4) * difficulty_mod,
-                                description: "Lost users from outages".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "Reputation".to_string(),
-                                change: -25.0 * difficulty_mod,
-                                description: "Failed to handle growth".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["WAU growth > 30%".to_string()],
-            cooldown_weeks: 10,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("viral_moment_gone_wrong".to_string(), 10);
-    }
-
-    // 10. Founder Health Crisis
-    let morale_low_weeks = state.history.iter().rev().take(4).all(|s| s.morale < 30.0);
-    if morale_low_weeks && rng.gen_bool(0.3) && can_trigger_event(&state.event_cooldowns, "founder_health_crisis") {
-        events.push(GameEvent {
-            id: "founder_health_crisis".to_string(),
-            week: state.week,
-            title: "Founder Burnout Crisis".to_string(),
-            description: "You've been running on empty for months. Your health is failing.".to_string(),
-            event_type: EnhancedEventType::Dilemma {
-                choices: vec![
-                    EventChoice {
-                        label: "Take Extended Break".to_string(),
-                        description: "4 weeks off to recover. Morale boost but revenue hit.".to_string(),
-                        short_term: "Morale recovery, revenue loss".to_string(),
-                        long_term: "Sustainable founder".to_string(),
-                        wisdom: "Your health is the business's health. You can't pour from an empty cup.".to_string(),
-                        effects: vec![
-                            EventEffect {
-                                stat_name: "Morale".to_string(),
-                                change: 40.0 * difficulty_mod,
-                                description: "Recovery time".to_string(),
-                            },
-                            EventEffect {
-                                stat_name: "MRR".to_string(),
-                                change: -10_000.0 * difficulty_mod,
-                                description: "Lost revenue during break".to_string(),
-                            },
-                        ],
-                    },
-                    EventChoice {
-                        label: "Push Through".to_string(),
-                        description: "Keep going. 50% chance of complete burnout (game over).".to_string(),
-                        short_term: "Continue working, risk burnout".to_string(),
-                        long_term: "Either survive or game over".to_string(),
-                        wisdom: "Sometimes you have to gamble everything. But know when to fold.".to_string(),
-                        effects: vec![
-                            // Special handling for burnout risk
-                            EventEffect {
-                                stat_name: "Burnout Risk".to_string(),
-                                change: 50.0,
-                                description: "50% chance of game over".to_string(),
-                            },
-                        ],
-                    },
-                ],
-            },
-            prerequisites: vec!["Morale < 30 for 4 weeks".to_string()],
-            cooldown_weeks: 20,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("founder_health_crisis".to_string(), 20);
-    }
-
-    // Automatic Events
-
-    // Positive automatic events
-    if rng.gen_bool(0.05) && can_trigger_event(&state.event_cooldowns, "press_mention") {
-        events.push(GameEvent {
-            id: "press_mention".to_string(),
-            week: state.week,
-            title: "Positive Press Mention".to_string(),
-            description: "A respected blog wrote favorably about your product.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Reputation".to_string(),
-                        change: 5.0 * difficulty_mod,
-                        description: "Positive coverage".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "WAU".to_string(),
-                        change: 50.0 * difficulty_mod,
-                        description: "Traffic from article".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 4,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("press_mention".to_string(), 4);
-    }
-
-    if rng.gen_bool(0.03) && can_trigger_event(&state.event_cooldowns, "customer_testimonial") {
-        events.push(GameEvent {
-            id: "customer_testimonial".to_string(),
-            week: state.week,
-            title: "Glowing Customer Testimonial".to_string(),
-            description: "A happy customer shared their success story publicly.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "NPS".to_string(),
-                        change: 10.0 * difficulty_mod,
-                        description: "Social proof".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Reputation".to_string(),
-                        change: 3.0 * difficulty_mod,
-                        description: "Customer advocacy".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 6,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("customer_testimonial".to_string(), 6);
-    }
-
-    if rng.gen_bool(0.02) && can_trigger_event(&state.event_cooldowns, "competitor_failure") {
-        events.push(GameEvent {
-            id: "competitor_failure".to_string(),
-            week: state.week,
-            title: "Competitor Shuts Down".to_string(),
-            description: "A direct competitor ran out of money and closed their doors.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "WAU".to_string(),
-                        change: 200.0 * difficulty_mod,
-                        description: "Captured competitor users".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Morale".to_string(),
-                        change: 5.0 * difficulty_mod,
-                        description: "Competitive win".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 8,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("competitor_failure".to_string(), 8);
-    }
-
-    if rng.gen_bool(0.04) && can_trigger_event(&state.event_cooldowns, "talent_joins") {
-        events.push(GameEvent {
-            id: "talent_joins".to_string(),
-            week: state.week,
-            title: "Star Talent Joins Team".to_string(),
-            description: "An experienced engineer from a top company joined your team.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Velocity".to_string(),
-                        change: 0.15 * difficulty_mod,
-                        description: "Expert addition".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Morale".to_string(),
-                        change: 8.0 * difficulty_mod,
-                        description: "Team excited".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 10,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("talent_joins".to_string(), 10);
-    }
-
-    // Negative automatic events
-    if rng.gen_bool(0.06) && can_trigger_event(&state.event_cooldowns, "server_outage") {
-        events.push(GameEvent {
-            id: "server_outage".to_string(),
-            week: state.week,
-            title: "Unexpected Server Outage".to_string(),
-            description: "A cloud provider issue caused 2 hours of downtime.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Reputation".to_string(),
-                        change: -5.0 * difficulty_mod,
-                        description: "Service disruption".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "WAU".to_string(),
-                        change: -20.0 * difficulty_mod,
-                        description: "Users frustrated".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 3,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("server_outage".to_string(), 3);
-    }
-
-    if rng.gen_bool(0.04) && can_trigger_event(&state.event_cooldowns, "customer_complaint") {
-        events.push(GameEvent {
-            id: "customer_complaint".to_string(),
-            week: state.week,
-            title: "Public Customer Complaint".to_string(),
-            description: "An unhappy customer tweeted about their bad experience.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "NPS".to_string(),
-                        change: -8.0 * difficulty_mod,
-                        description: "Public complaint".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Reputation".to_string(),
-                        change: -3.0 * difficulty_mod,
-                        description: "Negative publicity".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 5,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("customer_complaint".to_string(), 5);
-    }
-
-    if rng.gen_bool(0.03) && can_trigger_event(&state.event_cooldowns, "competitor_feature") {
-        events.push(GameEvent {
-            id: "competitor_feature".to_string(),
-            week: state.week,
-            title: "Competitor Launches Key Feature".to_string(),
-            description: "A competitor shipped a feature your customers have been requesting.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Churn Rate".to_string(),
-                        change: 2.0 * difficulty_mod,
-                        description: "Feature competition".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Morale".to_string(),
-                        change: -3.0 * difficulty_mod,
-                        description: "Feeling behind".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 7,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("competitor_feature".to_string(), 7);
-    }
-
-    if rng.gen_bool(0.02) && can_trigger_event(&state.event_cooldowns, "key_person_sick") {
-        events.push(GameEvent {
-            id: "key_person_sick".to_string(),
-            week: state.week,
-            title: "Key Team Member Out Sick".to_string(),
-            description: "Your lead developer is out for a week with illness.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Velocity".to_string(),
-                        change: -0.1 * difficulty_mod,
-                        description: "Lost productivity".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Morale".to_string(),
-                        change: -2.0 * difficulty_mod,
-                        description: "Team concern".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 9,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("key_person_sick".to_string(), 9);
-    }
-
-    // Neutral automatic events
-    if rng.gen_bool(0.03) && can_trigger_event(&state.event_cooldowns, "market_shift") {
-        events.push(GameEvent {
-            id: "market_shift".to_string(),
-            week: state.week,
-            title: "Market Trend Shift".to_string(),
-            description: "Industry trends are shifting toward a new technology paradigm.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Tech Debt".to_string(),
-                        change: 5.0 * difficulty_mod,
-                        description: "Need to adapt".to_string(),
-                    },
-                    EventEffect {
-                        stat_name: "Morale".to_string(),
-                        change: -2.0 * difficulty_mod,
-                        description: "Uncertainty".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 12,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("market_shift".to_string(), 12);
-    }
-
-    if rng.gen_bool(0.02) && can_trigger_event(&state.event_cooldowns, "new_regulation") {
-        events.push(GameEvent {
-            id: "new_regulation".to_string(),
-            week: state.week,
-            title: "New Industry Regulation".to_string(),
-            description: "New regulations will increase compliance requirements.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Compliance Risk".to_string(),
-                        change: 10.0 * difficulty_mod,
-                        description: "New requirements".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 15,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("new_regulation".to_string(), 15);
-    }
-
-    if rng.gen_bool(0.04) && can_trigger_event(&state.event_cooldowns, "industry_trend") {
-        events.push(GameEvent {
-            id: "industry_trend".to_string(),
-            week: state.week,
-            title: "Industry Trend Emerges".to_string(),
-            description: "A new industry trend could benefit your product positioning.".to_string(),
-            event_type: EnhancedEventType::Automatic {
-                effects: vec![
-                    EventEffect {
-                        stat_name: "Reputation".to_string(),
-                        change: 2.0 * difficulty_mod,
-                        description: "Trend alignment".to_string(),
-                    },
-                ],
-            },
-            prerequisites: vec![],
-            cooldown_weeks: 10,
-            follow_up_event_id: None,
-            difficulty_modifier: difficulty_mod,
-        });
-        state.event_cooldowns.insert("industry_trend".to_string(), 10);
-    }
-
-    // Allow 0-2 events per week
-    if events.len() > 2 {
-        // Randomly select 2 events
-        use rand::seq::SliceRandom;
-        events.shuffle(&mut rng);
-        events.truncate(2);
-    }
 
     // Decrement cooldowns for next week
     for (_, cooldown) in state.event_cooldowns.iter_mut() {
@@ -2068,54 +147,56 @@ pub fn check_for_events(state: &mut GameState) -> Vec<GameEvent> {
     events
 }
 
-/// Apply event choice to game state
-pub fn apply_event_choice(state: &mut GameState, choice: &EventChoice) {
-    for effect in &choice.effects {
-        match effect.stat_name.as_str() {
-            "Morale" => state.morale += effect.change,
-            "Reputation" => state.reputation += effect.change,
-            "Tech Debt" => state.tech_debt += effect.change,
-            "Velocity" => state.velocity += effect.change,
-            "WAU" => state.wau = (state.wau as f64 + effect.change).max(0.0) as u32,
-            "WAU Growth" => state.wau_growth_rate += effect.change,
-            "MRR" => state.mrr += effect.change,
-            "Burn" => state.burn += effect.change,
-            "Bank" => state.bank += effect.change,
-            "Founder Equity" => state.founder_equity += effect.change,
-            "Churn Rate" => state.churn_rate += effect.change,
-            "Focus" => state.focus_slots = (state.focus_slots as i8 + effect.change as i8).max(2) as u8,
-            "Compliance Risk" => state.compliance_risk += effect.change,
-            "NPS" => state.nps += effect.change,
-            "Game End" => {
-                // Special handling for acquisition
-                state.morale = 100.0; // Mark as won
-            }
-            "Burnout Risk" => {
-                if rand::random::<f64>() < (effect.change / 100.0) {
-                    state.morale = -100.0; // Game over from burnout
-                }
-            }
-            _ => {}
+/// Apply event choice to game state, queueing delayed and recurring effects for later weeks
+///
+/// Returns the effects applied right away.
+pub fn apply_event_choice(state: &mut GameState, choice: &EventChoice, rng: &mut GameRng) -> Vec<StatEffect> {
+    let mut immediate = Vec::new();
+    for effect in choice.effects.iter().filter(|e| e.chance.is_none_or(|chance| rng.random::<f64>() < chance)) {
+        match effect.schedule_from(state.week) {
+            Some(scheduled) => state.scheduled_effects.push(scheduled),
+            None => immediate.push(&effect.effect),
         }
     }
 
-    state.update_derived_metrics();
+    let mut old_bank = state.bank;
+    let mut applied = apply_effects(state, immediate);
+    record_bank_change(state, old_bank, LedgerCategory::Other, &choice.label);
+    old_bank = state.bank;
+    if let Some(financing) = &choice.financing {
+        applied.extend(close_financing(state, financing).0);
+    }
+    if let Some(decision) = choice.round_decision {
+        applied.extend(apply_round_decision(state, decision, rng));
+    }
+    record_bank_change(state, old_bank, LedgerCategory::Equity, &choice.label);
+    old_bank = state.bank;
+    if let Some(decision) = choice.debt_decision {
+        applied.extend(apply_debt_decision(state, decision));
+    }
+    record_bank_change(state, old_bank, LedgerCategory::Debt, &choice.label);
+    if let Some(decision) = choice.renewal_decision {
+        applied.extend(apply_renewal_decision(state, decision));
+    }
+    applied
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::state::DifficultyMode;
+    use crate::game::effects::Stat;
+    use crate::game::state::{DifficultyMode, WeekSnapshot};
 
     #[test]
     fn test_tech_debt_crisis_triggers() {
-        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut rng = state.next_rng();
         state.tech_debt = 75.0;
 
         // Run multiple times to account for probability
         let mut found_event = false;
         for _ in 0..20 {
-            let events = check_for_events(&mut state);
+            let events = check_for_events(&mut state, &mut rng);
             if events.iter().any(|e| e.id == "tech_debt_crisis") {
                 found_event = true;
                 break;
@@ -2127,7 +208,8 @@ mod tests {
 
     #[test]
     fn test_viral_moment_requires_quality() {
-        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut rng = state.next_rng();
         state.nps = 70.0;
         state.tech_debt = 20.0; // Low debt
         state.wau = 300;
@@ -2135,7 +217,7 @@ mod tests {
         // This should be possible to trigger
         let mut found_event = false;
         for _ in 0..50 {
-            let events = check_for_events(&mut state);
+            let events = check_for_events(&mut state, &mut rng);
             if events.iter().any(|e| e.id == "viral_moment") {
                 found_event = true;
                 break;
@@ -2148,7 +230,8 @@ mod tests {
 
     #[test]
     fn test_apply_event_choice() {
-        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut rng = state.next_rng();
         let initial_morale = state.morale;
 
         let choice = EventChoice {
@@ -2158,20 +241,86 @@ mod tests {
             long_term: "Test".to_string(),
             wisdom: "Test".to_string(),
             effects: vec![EventEffect {
-                stat_name: "Morale".to_string(),
-                change: 10.0,
+                effect: Effect::add(Stat::Morale, 10.0),
+                chance: None,
                 description: "Test boost".to_string(),
+                delay_weeks: 0,
+                duration_weeks: 0,
             }],
+            follow_up: None,
+            financing: None,
+            round_decision: None,
+            debt_decision: None,
+            renewal_decision: None,
         };
 
-        apply_event_choice(&mut state, &choice);
+        apply_event_choice(&mut state, &choice, &mut rng);
 
         assert_eq!(state.morale, initial_morale + 10.0);
     }
 
+    #[test]
+    fn test_deferred_effects_wait_for_advance_week() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut rng = state.next_rng();
+        state.mrr = 0.0;
+
+        let choice = EventChoice {
+            label: "Test".to_string(),
+            description: "Test".to_string(),
+            short_term: "Test".to_string(),
+            long_term: "Test".to_string(),
+            wisdom: "Test".to_string(),
+            effects: vec![EventEffect {
+                effect: Effect::add(Stat::Mrr, 5000.0),
+                chance: None,
+                description: "Partner revenue".to_string(),
+                delay_weeks: 4,
+                duration_weeks: 0,
+            }],
+            follow_up: None,
+            financing: None,
+            round_decision: None,
+            debt_decision: None,
+            renewal_decision: None,
+        };
+
+        assert!(apply_event_choice(&mut state, &choice, &mut rng).is_empty());
+        assert_eq!(state.scheduled_effects.len(), 1);
+
+        let mut mrr_by_week = Vec::new();
+        for _ in 0..4 {
+            state.advance_week(&mut rng);
+            mrr_by_week.push(state.mrr);
+        }
+
+        assert_eq!(mrr_by_week[2], 0.0);
+        assert_eq!(mrr_by_week[3], 5000.0);
+        assert!(state.scheduled_effects.is_empty());
+    }
+
+    #[test]
+    fn test_term_sheet_safe_defers_dilution() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 3);
+        let mut rng = state.next_rng();
+        let event = EventLibrary::bundled().get("vc_offer").unwrap().fire(&state, &mut rng).unwrap();
+        let choices = match event.event_type {
+            EnhancedEventType::Dilemma { choices } => choices,
+            _ => panic!("vc_offer should be a dilemma"),
+        };
+        let bank = state.bank;
+
+        apply_event_choice(&mut state, &choices[2], &mut rng);
+
+        assert_eq!(state.bank, bank + 750_000.0);
+        assert_eq!(state.founder_equity, 100.0);
+        assert_eq!(state.cap_table.convertibles.len(), 1);
+    }
+
     #[test]
     fn test_event_limit_two_per_week() {
-        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut rng = state.next_rng();
         // Set up conditions for multiple events
         state.tech_debt = 75.0;
         state.morale = 40.0;
@@ -2179,7 +328,7 @@ mod tests {
         state.reputation = 75.0;
         state.mrr = 60_000.0;
 
-        let events = check_for_events(&mut state);
+        let events = check_for_events(&mut state, &mut rng);
 
         // Should return at most 2 events
         assert!(events.len() <= 2);
@@ -2187,11 +336,12 @@ mod tests {
 
     #[test]
     fn test_cooldown_prevents_spam() {
-        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut rng = state.next_rng();
         state.tech_debt = 75.0;
         state.event_cooldowns.insert("tech_debt_crisis".to_string(), 5);
 
-        let events = check_for_events(&mut state);
+        let events = check_for_events(&mut state, &mut rng);
 
         // Should not trigger while on cooldown
         assert!(!events.iter().any(|e| e.id == "tech_debt_crisis"));
@@ -2199,7 +349,8 @@ mod tests {
 
     #[test]
     fn test_new_dilemmas_trigger() {
-        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut rng = state.next_rng();
         // Pivot opportunity
         for _ in 0..8 {
             state.history.push(WeekSnapshot {
@@ -2215,7 +366,7 @@ mod tests {
             state.week += 1;
         }
 
-        let events = check_for_events(&mut state);
+        let events = check_for_events(&mut state, &mut rng);
         assert!(events.iter().any(|e| e.id == "pivot_opportunity"));
     }
 }
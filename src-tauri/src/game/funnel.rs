@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use super::cohorts::{add_signups, add_users};
+use super::customers::{calculate_segment_from_mrr, generate_customer_persona};
+use super::mrr_bridge::{record_mrr, MrrMovement};
+use super::pricing::{conversion_factor, PlanTier};
+use super::rng::GameRng;
+use super::state::GameState;
+
+/// Weeks of funnel history kept
+const FUNNEL_HISTORY_WEEKS: u32 = 52;
+/// Seats a small business buys when it converts
+const SMB_SEATS: f64 = 5.0;
+
+/// Share of each funnel stage that makes it to the next
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConversionRates {
+    pub visitor_to_signup: f64,
+    pub signup_to_activation: f64,
+    pub activation_to_paid: f64,
+}
+
+impl ConversionRates {
+    /// Paying customers per visitor
+    pub fn visitor_to_paid(&self) -> f64 {
+        self.visitor_to_signup * self.signup_to_activation * self.activation_to_paid
+    }
+}
+
+/// Everyone who moved through the funnel in one week
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunnelWeek {
+    pub week: u32,
+    pub visitors: f64,
+    pub signups: f64,
+    /// Signups who became weekly active users
+    pub activated: f64,
+    pub paid: u32,
+    pub new_mrr: f64,
+}
+
+impl FunnelWeek {
+    fn empty(week: u32) -> Self {
+        Self { week, visitors: 0.0, signups: 0.0, activated: 0.0, paid: 0, new_mrr: 0.0 }
+    }
+}
+
+/// This week's conversion rates
+///
+/// Reputation brings visitors to sign up and to trust the product with a card,
+/// onboarding decides how many signups become active, and the Pro price gates payment.
+pub fn conversion_rates(state: &GameState) -> ConversionRates {
+    let reputation = state.reputation / 100.0;
+    ConversionRates {
+        visitor_to_signup: (0.05 + 0.1 * reputation).clamp(0.01, 0.5),
+        signup_to_activation: (0.2 + 0.6 * state.onboarding_quality / 100.0).clamp(0.05, 0.95),
+        activation_to_paid: (0.05 * (0.75 + reputation / 2.0) * conversion_factor(state, PlanTier::Pro)).clamp(0.0, 0.5),
+    }
+}
+
+/// Send visitors through the funnel
+///
+/// Activated users join this week's cohort and each paid conversion becomes a
+/// customer on the Pro plan, so WAU, customers and MRR move together.
+/// `smb_share` is the chance a conversion is a small business buying several
+/// seats; what they end up paying decides their segment.
+pub fn run_funnel(state: &mut GameState, visitors: f64, smb_share: f64, rng: &mut GameRng) -> FunnelWeek {
+    let rates = conversion_rates(state);
+    let signups = visitors.max(0.0) * rates.visitor_to_signup;
+    let activated = signups * rates.signup_to_activation;
+    let expected_paid = activated * rates.activation_to_paid;
+    let paid = expected_paid.floor() as u32 + rng.random_bool(expected_paid.fract()) as u32;
+
+    add_users(state, activated);
+    let mut new_mrr = 0.0;
+    for _ in 0..paid {
+        let seats = if rng.random_bool(smb_share.clamp(0.0, 1.0)) { SMB_SEATS } else { 1.0 };
+        let price = state.pricing.pro.monthly_price(seats);
+        let segment = calculate_segment_from_mrr(price);
+        let mut customer = generate_customer_persona(segment.clone(), state.week, state, rng);
+        customer.mrr_contribution = price;
+        add_signups(state, segment, price);
+        state.add_customer(customer);
+        new_mrr += price;
+    }
+    record_mrr(state, MrrMovement::NewBusiness, new_mrr);
+
+    let result = FunnelWeek { week: state.week, visitors, signups, activated, paid, new_mrr };
+    record_funnel(state, &result);
+    result
+}
+
+/// Add a funnel run to the current week's totals
+fn record_funnel(state: &mut GameState, run: &FunnelWeek) {
+    let week = state.week;
+    if state.funnel_history.last().is_none_or(|w| w.week != week) {
+        state.funnel_history.push(FunnelWeek::empty(week));
+    }
+    if let Some(total) = state.funnel_history.last_mut() {
+        total.visitors += run.visitors;
+        total.signups += run.signups;
+        total.activated += run.activated;
+        total.paid += run.paid;
+        total.new_mrr += run.new_mrr;
+    }
+    let oldest = week.saturating_sub(FUNNEL_HISTORY_WEEKS - 1);
+    state.funnel_history.retain(|w| w.week >= oldest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::DifficultyMode;
+
+    #[test]
+    fn test_funnel_keeps_wau_customers_and_mrr_consistent() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let mut rng = state.next_rng();
+        let wau = state.wau;
+
+        let first = run_funnel(&mut state, 2_000.0, 0.0, &mut rng);
+        let second = run_funnel(&mut state, 2_000.0, 0.0, &mut rng);
+
+        assert_eq!(state.wau, wau + (first.activated + second.activated).round() as u32);
+        assert_eq!(state.customers.len() as u32, first.paid + second.paid);
+        assert_eq!(state.mrr, (first.paid + second.paid) as f64 * state.pricing.pro.list_price);
+        assert_eq!(state.funnel_history.len(), 1);
+        assert_eq!(state.funnel_history[0].visitors, 4_000.0);
+    }
+
+    #[test]
+    fn test_rates_respond_to_reputation_onboarding_and_price() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let base = conversion_rates(&state);
+
+        state.pricing.pro.list_price *= 2.0;
+        assert!(conversion_rates(&state).activation_to_paid < base.activation_to_paid);
+        state.pricing.pro.list_price /= 2.0;
+
+        state.reputation = 90.0;
+        state.onboarding_quality = 90.0;
+        let improved = conversion_rates(&state);
+
+        assert!(improved.visitor_to_signup > base.visitor_to_signup);
+        assert!(improved.signup_to_activation > base.signup_to_activation);
+        assert!(improved.visitor_to_paid() > base.visitor_to_paid());
+    }
+}
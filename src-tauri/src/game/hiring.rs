@@ -0,0 +1,404 @@
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use super::competitors::CompetitorActionType;
+use super::actions::Action;
+use super::market_conditions::{action_effectiveness, Effectiveness};
+use super::rng::GameRng;
+use super::state::GameState;
+use super::team::{self, random_name, Employee, Role, Seniority};
+
+/// Candidates an unknown company hears from at any one time
+const BASE_POOL_SIZE: usize = 3;
+/// Reputation it takes to draw one more candidate into the pool
+const REPUTATION_PER_CANDIDATE: f64 = 25.0;
+/// How many more or fewer candidates a tech boom or a talent war brings
+const MARKET_POOL_SHIFT: usize = 2;
+/// Weeks a candidate stays on the market before taking another job
+const CANDIDATE_EXPIRY_WEEKS: u32 = 3;
+/// Weeks an interviewed candidate waits for an offer
+const OFFER_WINDOW_WEEKS: u32 = 2;
+/// Weeks a competitor keeps poaching after a talent push
+const POACH_WINDOW_WEEKS: u32 = 4;
+/// How much an unmet equity ask weighs on accepting
+const EQUITY_SHORTFALL_WEIGHT: f64 = 0.3;
+/// Equity asks are this much higher while a talent war is on
+const TALENT_WAR_EQUITY_PREMIUM: f64 = 1.5;
+
+/// How far along a candidate is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CandidateStage {
+    /// Applied or was sourced; skill is only a guess until the interview
+    Sourced,
+    /// Met the team and is waiting on an offer
+    Interviewed,
+}
+
+/// Someone on the job market who might join
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Candidate {
+    /// Becomes their employee id if they join
+    pub id: u32,
+    pub name: String,
+    pub role: Role,
+    pub seniority: Seniority,
+    /// 0-100
+    pub skill: f64,
+    /// Monthly salary they are asking for
+    pub salary_ask: f64,
+    /// Options they are asking for, in percent of the company
+    pub equity_ask: f64,
+    /// 0-100; how keen they are on the company
+    pub interest: f64,
+    pub stage: CandidateStage,
+    /// Last week they are still on the market
+    pub expires_week: u32,
+}
+
+/// How an offer compares to what the candidate asked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OfferTerms {
+    /// Less cash than asked
+    Lowball,
+    /// Exactly the ask
+    AtAsk,
+    /// More cash than asked, which holds up better against counter-offers
+    AboveAsk,
+}
+
+impl OfferTerms {
+    pub const ALL: [OfferTerms; 3] = [OfferTerms::Lowball, OfferTerms::AtAsk, OfferTerms::AboveAsk];
+
+    /// Salary offered as a share of the ask
+    pub fn salary_factor(&self) -> f64 {
+        match self {
+            OfferTerms::Lowball => 0.85,
+            OfferTerms::AtAsk => 1.0,
+            OfferTerms::AboveAsk => 1.15,
+        }
+    }
+
+    /// Chance a neutral candidate says yes with no counter-offer
+    fn base_acceptance(&self) -> f64 {
+        match self {
+            OfferTerms::Lowball => 0.35,
+            OfferTerms::AtAsk => 0.7,
+            OfferTerms::AboveAsk => 0.9,
+        }
+    }
+
+    /// Share of the acceptance chance left after a competitor counters
+    fn counter_resistance(&self) -> f64 {
+        match self {
+            OfferTerms::Lowball => 0.3,
+            OfferTerms::AtAsk => 0.5,
+            OfferTerms::AboveAsk => 0.75,
+        }
+    }
+}
+
+/// How a candidate answered an offer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OfferOutcome {
+    pub candidate: Candidate,
+    pub accepted: bool,
+    /// Monthly salary offered
+    pub salary: f64,
+    /// Options granted from the pool, in percent; zero if they declined
+    pub equity_granted: f64,
+    /// Competitor whose counter-offer was on the table
+    pub counter_offer_from: Option<String>,
+}
+
+fn market_condition_active(state: &GameState, id: &str) -> bool {
+    state.active_market_conditions.iter().any(|c| c.id == id)
+}
+
+/// How many candidates the company hears from: more with reputation and in a
+/// tech boom, fewer while everyone fights over talent
+pub fn pool_size(state: &GameState) -> usize {
+    let mut size = BASE_POOL_SIZE + (state.reputation.max(0.0) / REPUTATION_PER_CANDIDATE) as usize;
+    if market_condition_active(state, "TechBoom") {
+        size += MARKET_POOL_SHIFT;
+    }
+    if market_condition_active(state, "TalentWar") {
+        size = size.saturating_sub(MARKET_POOL_SHIFT);
+    }
+    size.max(1)
+}
+
+/// Options a candidate at this level asks for, in percent of the company
+fn equity_range(seniority: Seniority) -> (f64, f64) {
+    match seniority {
+        Seniority::Junior => (0.05, 0.2),
+        Seniority::Mid => (0.2, 0.5),
+        Seniority::Senior => (0.5, 1.5),
+    }
+}
+
+/// A new candidate for the pool
+///
+/// Reputation and a tech boom bring more senior people; the hiring market in
+/// `effectiveness` sets what they ask for and how good they are.
+fn source_candidate(state: &mut GameState, effectiveness: &Effectiveness, rng: &mut GameRng) -> Candidate {
+    let mut senior_chance = (state.reputation / 200.0).clamp(0.05, 0.5);
+    if market_condition_active(state, "TechBoom") {
+        senior_chance += 0.1;
+    }
+    let roll = rng.random::<f64>();
+    let seniority = if roll < senior_chance {
+        Seniority::Senior
+    } else if roll < senior_chance + 0.4 {
+        Seniority::Mid
+    } else {
+        Seniority::Junior
+    };
+    let role = *Role::HIRABLE.choose(rng).unwrap();
+    let (low, high) = seniority.skill_range();
+    let (equity_low, equity_high) = equity_range(seniority);
+    let equity_premium = if market_condition_active(state, "TalentWar") { TALENT_WAR_EQUITY_PREMIUM } else { 1.0 };
+
+    let candidate = Candidate {
+        id: state.next_employee_id,
+        name: random_name(rng),
+        role,
+        seniority,
+        skill: (rng.random_range(low..high) * effectiveness.hire_velocity).min(100.0),
+        salary_ask: role.base_salary() * seniority.salary_factor() * effectiveness.hiring_cost * rng.random_range(0.9..1.1),
+        equity_ask: rng.random_range(equity_low..equity_high) * equity_premium,
+        interest: (30.0 + state.reputation / 4.0 + rng.random_range(0.0..30.0)).clamp(0.0, 100.0),
+        stage: CandidateStage::Sourced,
+        expires_week: state.week + CANDIDATE_EXPIRY_WEEKS,
+    };
+    state.next_employee_id += 1;
+    candidate
+}
+
+/// What the hiring market does to salaries and skill this week
+fn hiring_market(state: &GameState) -> Effectiveness {
+    action_effectiveness(&Action::Hire { candidate_id: 0, offer: OfferTerms::AtAsk }, &state.active_market_conditions)
+}
+
+/// A week on the job market: candidates who waited too long take other jobs and new ones apply
+pub fn refresh_candidates(state: &mut GameState, rng: &mut GameRng) {
+    let week = state.week;
+    state.candidates.retain(|c| c.expires_week >= week);
+    let effectiveness = hiring_market(state);
+    while state.candidates.len() < pool_size(state) {
+        let candidate = source_candidate(state, &effectiveness, rng);
+        state.candidates.push(candidate);
+    }
+}
+
+/// Meet a candidate: their real skill shows and their interest moves with how the company comes across
+pub fn interview(state: &mut GameState, candidate_id: u32, rng: &mut GameRng) -> Result<Candidate, String> {
+    let week = state.week;
+    let reputation = state.reputation;
+    let morale = state.morale;
+    let candidate = state
+        .candidates
+        .iter_mut()
+        .find(|c| c.id == candidate_id)
+        .ok_or_else(|| format!("No candidate with id {}", candidate_id))?;
+    if candidate.stage != CandidateStage::Sourced {
+        return Err(format!("{} has already been interviewed", candidate.name));
+    }
+
+    candidate.interest = (candidate.interest + rng.random_range(-10.0..15.0) + (reputation - 50.0) / 10.0 + (morale - 50.0) / 10.0)
+        .clamp(0.0, 100.0);
+    candidate.stage = CandidateStage::Interviewed;
+    candidate.expires_week = candidate.expires_week.max(week + OFFER_WINDOW_WEEKS);
+    Ok(candidate.clone())
+}
+
+/// Competitors that have been poaching talent recently
+pub fn poaching_competitors(state: &GameState) -> Vec<String> {
+    state
+        .competitors
+        .iter()
+        .filter(|c| !c.is_acquired)
+        .filter(|c| {
+            c.action_history
+                .iter()
+                .any(|a| matches!(a.action_type, CompetitorActionType::TalentPoach) && a.week + POACH_WINDOW_WEEKS >= state.week)
+        })
+        .map(|c| c.name.clone())
+        .collect()
+}
+
+/// Make an interviewed candidate an offer and hear back
+///
+/// The candidate leaves the pool either way. Acceptance depends on the terms,
+/// their interest, the hiring market (`effectiveness.output`), whether the
+/// option pool can cover their equity ask, and any competitor that is
+/// poaching and counters. If they accept they join payroll and their grant
+/// comes out of the option pool.
+pub fn make_offer(
+    state: &mut GameState,
+    candidate_id: u32,
+    terms: OfferTerms,
+    effectiveness: &Effectiveness,
+    rng: &mut GameRng,
+) -> Result<OfferOutcome, String> {
+    let position = state
+        .candidates
+        .iter()
+        .position(|c| c.id == candidate_id)
+        .ok_or_else(|| format!("No candidate with id {}", candidate_id))?;
+    if state.candidates[position].stage != CandidateStage::Interviewed {
+        return Err(format!("Interview {} before making an offer", state.candidates[position].name));
+    }
+    let candidate = state.candidates.remove(position);
+    let salary = candidate.salary_ask * terms.salary_factor();
+
+    let mut chance = (terms.base_acceptance() + (candidate.interest - 50.0) / 200.0) * effectiveness.output;
+    if candidate.equity_ask > 0.0 {
+        let shortfall = (1.0 - state.option_pool / candidate.equity_ask).clamp(0.0, 1.0);
+        chance *= 1.0 - EQUITY_SHORTFALL_WEIGHT * shortfall;
+    }
+
+    // Poaching competitors may counter; the first to do so is the one they weigh up
+    let counter_offer_from = poaching_competitors(state).into_iter().find(|name| {
+        let aggressiveness = state.competitors.iter().find(|c| &c.name == name).map_or(0.0, |c| c.aggressiveness);
+        rng.random_bool((aggressiveness * 0.6).clamp(0.0, 1.0))
+    });
+    if counter_offer_from.is_some() {
+        chance *= terms.counter_resistance();
+    }
+
+    let accepted = rng.random_bool(chance.clamp(0.05, 0.95));
+    let equity_granted = if accepted {
+        let granted = state.cap_table.grant_options(&candidate.name, candidate.equity_ask);
+        state.option_pool = state.cap_table.option_pool_ownership();
+        let employee = Employee::new_hire(candidate.id, candidate.name.clone(), candidate.role, candidate.seniority, salary, candidate.skill);
+        team::hire(state, employee);
+        granted
+    } else {
+        0.0
+    };
+
+    Ok(OfferOutcome { candidate, accepted, salary, equity_granted, counter_offer_from })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::competitors::CompetitorAction;
+    use crate::game::market_conditions::MarketCondition;
+    use crate::game::rng::rng_for_stream;
+    use crate::game::state::DifficultyMode;
+
+    fn condition(id: &str) -> MarketCondition {
+        MarketCondition {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            duration_weeks: 4,
+            modifiers: Vec::new(),
+        }
+    }
+
+    fn interviewed(state: &mut GameState) -> u32 {
+        let candidate = &mut state.candidates[0];
+        candidate.stage = CandidateStage::Interviewed;
+        candidate.interest = 100.0;
+        candidate.id
+    }
+
+    #[test]
+    fn test_new_game_has_a_candidate_pool() {
+        let state = GameState::with_seed(DifficultyMode::VCTrack, 4);
+
+        assert_eq!(state.candidates.len(), pool_size(&state));
+        assert!(state.candidates.iter().all(|c| c.stage == CandidateStage::Sourced && c.id > state.team.len() as u32));
+        assert!(state.candidates.iter().all(|c| c.id < state.next_employee_id));
+    }
+
+    #[test]
+    fn test_market_conditions_skew_the_pool() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let normal = pool_size(&state);
+
+        state.active_market_conditions.push(condition("TechBoom"));
+        assert_eq!(pool_size(&state), normal + MARKET_POOL_SHIFT);
+
+        state.active_market_conditions.clear();
+        state.active_market_conditions.push(condition("TalentWar"));
+        assert!(pool_size(&state) < normal);
+    }
+
+    #[test]
+    fn test_hiring_cost_raises_salary_asks() {
+        let mut normal = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let mut talent_war = normal.clone();
+        let expensive = Effectiveness { hiring_cost: 1.6, ..Effectiveness::default() };
+
+        let cheap = source_candidate(&mut normal, &Effectiveness::default(), &mut rng_for_stream(4, 9));
+        let pricey = source_candidate(&mut talent_war, &expensive, &mut rng_for_stream(4, 9));
+
+        assert!((pricey.salary_ask - cheap.salary_ask * 1.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_candidates_expire_and_the_pool_refills() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let mut rng = state.next_rng();
+        let first_ids: Vec<u32> = state.candidates.iter().map(|c| c.id).collect();
+
+        state.week += CANDIDATE_EXPIRY_WEEKS + 1;
+        refresh_candidates(&mut state, &mut rng);
+
+        assert_eq!(state.candidates.len(), pool_size(&state));
+        assert!(state.candidates.iter().all(|c| !first_ids.contains(&c.id)));
+    }
+
+    #[test]
+    fn test_offer_needs_an_interview() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 4);
+        let mut rng = state.next_rng();
+        let id = state.candidates[0].id;
+
+        assert!(make_offer(&mut state, id, OfferTerms::AtAsk, &Effectiveness::default(), &mut rng).is_err());
+        assert_eq!(interview(&mut state, id, &mut rng).unwrap().stage, CandidateStage::Interviewed);
+        assert!(interview(&mut state, id, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_accepted_offer_joins_payroll_and_draws_the_pool() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 4);
+        state.cap_table = crate::game::cap_table::CapTable::from_ownership(90.0, 10.0);
+        state.option_pool = state.cap_table.option_pool_ownership();
+        let mut rng = state.next_rng();
+        let id = interviewed(&mut state);
+        let burn = state.burn;
+
+        let outcome = make_offer(&mut state, id, OfferTerms::AboveAsk, &Effectiveness::default(), &mut rng).unwrap();
+
+        assert!(outcome.accepted);
+        assert!((outcome.salary - outcome.candidate.salary_ask * 1.15).abs() < 1e-6);
+        assert!((state.burn - burn - outcome.salary).abs() < 1e-6);
+        assert_eq!(state.team.last().unwrap().id, id);
+        assert!((state.option_pool - (10.0 - outcome.equity_granted)).abs() < 0.01);
+        assert!(state.candidates.iter().all(|c| c.id != id));
+    }
+
+    #[test]
+    fn test_poaching_competitor_counters() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 4);
+        let mut rng = state.next_rng();
+        state.competitors.truncate(1);
+        state.competitors[0].aggressiveness = 2.0;
+        state.competitors[0].action_history.push(CompetitorAction {
+            week: state.week,
+            action_type: CompetitorActionType::TalentPoach,
+            description: String::new(),
+            impact_on_player: String::new(),
+            amount: None,
+        });
+        let id = interviewed(&mut state);
+
+        let outcome = make_offer(&mut state, id, OfferTerms::Lowball, &Effectiveness::default(), &mut rng).unwrap();
+
+        assert_eq!(outcome.counter_offer_from.as_ref(), Some(&state.competitors[0].name));
+        assert_eq!(poaching_competitors(&state), vec![state.competitors[0].name.clone()]);
+    }
+}
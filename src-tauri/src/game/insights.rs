@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use super::state::GameState;
 use super::competitors::{get_most_threatening_competitor, get_shipping_velocity_ratio};
+use super::metrics::unit_economics;
 
 /// Educational insight about player's decisions and game state
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -191,6 +192,55 @@ pub fn generate_weekly_insights(prev_state: &GameState, curr_state: &GameState)
         });
     }
 
+    // 12. Revenue bridge: what moved MRR this week
+    if let Some(bridge) = curr_state.mrr_history.last().filter(|b| b.week == prev_state.week) {
+        let gained = bridge.new_business + bridge.expansion + bridge.reactivated;
+        let lost = bridge.churned + bridge.contraction;
+        if lost > gained && lost > 0.0 {
+            insights.push(WeeklyInsight {
+                category: InsightCategory::Growth,
+                title: "Churn Outpacing New Revenue".to_string(),
+                observation: format!("Lost ${:.0} of MRR to churn and downgrades but added only ${:.0} from new, expansion and returning customers", lost, gained),
+                insight: "Revenue is a bucket: new business pours in, churn leaks out. When the leak is bigger than the pour, no amount of selling fixes it for long - every new customer just replaces one you lost.".to_string(),
+                action_suggestion: "Look at who left and why before spending more on acquisition. Retention work, better onboarding and win-back calls to lapsed customers all plug the leak.".to_string(),
+                severity: InsightSeverity::Warning,
+            });
+        } else if bridge.expansion + bridge.reactivated > lost && bridge.churned > 0.0 {
+            insights.push(WeeklyInsight {
+                category: InsightCategory::Growth,
+                title: "Expansion Covers Churn".to_string(),
+                observation: format!("Existing customers added ${:.0} of MRR, more than the ${:.0} lost to churn and downgrades", bridge.expansion + bridge.reactivated, lost),
+                insight: "Net negative churn: the customers you keep grow faster than the ones you lose shrink. Revenue now compounds even without new sales, which is why investors prize net revenue retention above 100%.".to_string(),
+                action_suggestion: "Find out what your expanding customers have in common and sell to more of them. Pricing and upsell experiments pay off most right now.".to_string(),
+                severity: InsightSeverity::Info,
+            });
+        }
+    }
+
+    // 13. Unit economics: what a customer costs against what they're worth
+    let metrics = unit_economics(curr_state);
+    if let (Some(ltv), Some(cac), Some(ratio)) = (metrics.ltv, metrics.blended_cac, metrics.ltv_to_cac) {
+        if ratio < 1.0 {
+            insights.push(WeeklyInsight {
+                category: InsightCategory::Growth,
+                title: "Buying Customers at a Loss".to_string(),
+                observation: format!("Each customer costs ${:.0} to acquire but is worth only ${:.0} over their lifetime", cac, ltv),
+                insight: "LTV:CAC below 1 means every sale destroys value: the more you grow, the more you lose. Healthy SaaS businesses aim for 3:1 - enough margin to pay for the team, the product and the customers you'll lose.".to_string(),
+                action_suggestion: "Shift effort to cheaper channels, raise prices or cut churn. Check CAC by channel - one expensive channel often drags down the blend.".to_string(),
+                severity: InsightSeverity::Warning,
+            });
+        } else if ratio >= 3.0 && metrics.cac_payback_months.is_some_and(|months| months <= 12.0) {
+            insights.push(WeeklyInsight {
+                category: InsightCategory::Growth,
+                title: "Efficient Growth Engine".to_string(),
+                observation: format!("LTV:CAC of {:.1}:1, with customers paying back their acquisition cost in {:.0} months", ratio, metrics.cac_payback_months.unwrap_or_default()),
+                insight: "This is the profile investors look for: customers are worth several times what they cost and pay it back within a year. Every dollar put into acquisition comes back with interest.".to_string(),
+                action_suggestion: "This is when spending more on acquisition makes sense. Scale the channels with the best CAC, and watch that CAC doesn't climb as you do.".to_string(),
+                severity: InsightSeverity::Info,
+            });
+        }
+    }
+
     // Competitive Intelligence Insights
 
     // Competitor Out-Shipping
@@ -283,6 +333,7 @@ fn check_weeks_since_break(_state: &GameState) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::mrr_bridge::MrrBridge;
     use crate::game::state::DifficultyMode;
 
     #[test]
@@ -299,6 +350,17 @@ mod tests {
         assert!(insights.iter().any(|i| i.category == InsightCategory::Morale));
     }
 
+    #[test]
+    fn test_churn_outpacing_new_revenue_insight() {
+        let prev_state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        let mut curr_state = prev_state.clone();
+        curr_state.mrr_history.push(MrrBridge { new_business: 200.0, churned: 500.0, ..MrrBridge::opening(prev_state.week, 5_000.0) });
+
+        let insights = generate_weekly_insights(&prev_state, &curr_state);
+
+        assert!(insights.iter().any(|i| i.title == "Churn Outpacing New Revenue"));
+    }
+
     #[test]
     fn test_runway_warning() {
         let prev_state = GameState::new(DifficultyMode::IndieBootstrap);
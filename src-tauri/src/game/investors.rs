@@ -0,0 +1,344 @@
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use super::actions::Action;
+use super::cap_table::{close_financing, pre_money_valuation, Financing, Instrument};
+use super::effects::StatEffect;
+use super::market_conditions::{action_effectiveness, Effectiveness, MarketCondition};
+use super::rng::GameRng;
+use super::state::{DifficultyMode, GameState};
+
+/// Interest below which an investor stops taking meetings
+const PASS_THRESHOLD: f64 = 20.0;
+/// Weeks a term sheet stays open before it lapses
+const OFFER_EXPIRY_WEEKS: u32 = 2;
+/// Valuation bump when investors are made to compete
+const BIDDING_BUMP: f64 = 0.10;
+
+const INVESTOR_NAMES: [&str; 10] = [
+    "Northwind Ventures", "Foundry Lane", "Harbor Point Capital", "Redline Partners", "Bluebird Seed",
+    "Keystone VC", "Tidewater Capital", "Summit & Grove", "Lattice Ventures", "Pioneer Square Partners",
+];
+
+/// What kind of company an investor likes to back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Thesis {
+    Generalist,
+    Saas,
+    Fintech,
+    DevTools,
+}
+
+impl Thesis {
+    /// Whether this company is squarely in the investor's thesis
+    pub fn fits(&self, difficulty: &DifficultyMode) -> bool {
+        matches!(
+            (self, difficulty),
+            (Thesis::Saas, DifficultyMode::IndieBootstrap | DifficultyMode::VCTrack)
+                | (Thesis::Fintech, DifficultyMode::RegulatedFintech)
+                | (Thesis::DevTools, DifficultyMode::InfraDevTool)
+        )
+    }
+}
+
+/// How far along an investor is with the company
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PipelineStage {
+    Intro,
+    Pitch,
+    PartnerMeeting,
+    Diligence,
+    TermSheet,
+    Passed,
+}
+
+impl PipelineStage {
+    /// The stage after this one; SAFEs and notes go straight from pitch to terms
+    fn next(&self, instrument: Instrument) -> PipelineStage {
+        match (self, instrument) {
+            (PipelineStage::Intro, _) => PipelineStage::Pitch,
+            (PipelineStage::Pitch, Instrument::PricedRound) => PipelineStage::PartnerMeeting,
+            (PipelineStage::Pitch, _) => PipelineStage::TermSheet,
+            (PipelineStage::PartnerMeeting, _) => PipelineStage::Diligence,
+            (PipelineStage::Diligence, _) => PipelineStage::TermSheet,
+            (stage, _) => *stage,
+        }
+    }
+}
+
+/// Terms an investor has put in writing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TermSheet {
+    /// Pre-money valuation, or the cap for a SAFE or note
+    pub valuation: f64,
+    /// Last week the offer can be signed
+    pub expires_week: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Investor {
+    pub name: String,
+    pub thesis: Thesis,
+    pub check_size: f64,
+    /// 0-100; rises with every meeting that goes well and falls when the process stalls
+    pub interest: f64,
+    pub stage: PipelineStage,
+    pub offer: Option<TermSheet>,
+}
+
+/// The investors being worked for the current raise
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestorPipeline {
+    pub target: f64,
+    pub instrument: Instrument,
+    pub opened_week: u32,
+    pub investors: Vec<Investor>,
+}
+
+impl InvestorPipeline {
+    /// Investors holding a live term sheet
+    pub fn offers(&self) -> impl Iterator<Item = &Investor> + '_ {
+        self.investors.iter().filter(|i| i.offer.is_some())
+    }
+
+    /// The offer with the highest valuation
+    pub fn best_offer(&self) -> Option<&Investor> {
+        self.offers().max_by(|a, b| offer_valuation(a).total_cmp(&offer_valuation(b)))
+    }
+
+    fn effectiveness(&self, market_conditions: &[MarketCondition]) -> Effectiveness {
+        action_effectiveness(&Action::Fundraise { target: self.target, instrument: self.instrument }, market_conditions)
+    }
+}
+
+fn offer_valuation(investor: &Investor) -> f64 {
+    investor.offer.as_ref().map_or(0.0, |o| o.valuation)
+}
+
+/// What a dilemma choice does to a raise with term sheets on the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoundDecision {
+    /// Sign the best term sheet now and close the round early
+    SignBestOffer,
+    /// Keep every conversation going: offers may be pulled, the rest improve
+    ShopAround,
+}
+
+/// Line up investors for a new raise
+pub fn open_pipeline(state: &mut GameState, target: f64, instrument: Instrument, rng: &mut GameRng) {
+    let count = rng.random_range(3..=5);
+    let investors = INVESTOR_NAMES
+        .choose_multiple(rng, count)
+        .map(|name| {
+            let thesis = *[Thesis::Generalist, Thesis::Saas, Thesis::Fintech, Thesis::DevTools].choose(rng).unwrap();
+            let check_size = ((target * rng.random_range(0.25..1.5)) / 25_000.0).round().max(1.0) * 25_000.0;
+            let mut interest = 20.0 + rng.random_range(0.0..30.0) + state.reputation / 5.0;
+            if thesis.fits(&state.difficulty) {
+                interest += 15.0;
+            }
+            // Investors who can write most of the check are keener to lead
+            if check_size >= target * 0.5 {
+                interest += 10.0;
+            }
+            Investor {
+                name: name.to_string(),
+                thesis,
+                check_size,
+                interest: interest.clamp(0.0, 100.0),
+                stage: PipelineStage::Intro,
+                offer: None,
+            }
+        })
+        .collect();
+
+    state.investor_pipeline = Some(InvestorPipeline {
+        target,
+        instrument,
+        opened_week: state.week,
+        investors,
+    });
+}
+
+/// A week of meetings: each investor moves a stage forward, cools off or passes
+///
+/// Reputation, momentum and the market set the pace; term sheets lapse when
+/// they are not signed in time.
+pub fn advance_pipeline(state: &mut GameState, market_conditions: &[MarketCondition], rng: &mut GameRng) {
+    let Some(mut pipeline) = state.investor_pipeline.take() else {
+        return;
+    };
+    let effectiveness = pipeline.effectiveness(market_conditions);
+    let pace = (0.7 + state.reputation / 200.0 + state.momentum * 0.1) * effectiveness.fundraising_success * effectiveness.output;
+
+    for investor in &mut pipeline.investors {
+        match investor.stage {
+            PipelineStage::Passed => {}
+            PipelineStage::TermSheet => {
+                if investor.offer.as_ref().is_some_and(|o| o.expires_week < state.week) {
+                    investor.offer = None;
+                    investor.stage = PipelineStage::Passed;
+                }
+            }
+            stage => {
+                let chance = (investor.interest / 100.0 * pace).clamp(0.05, 0.9);
+                if rng.random_bool(chance) {
+                    investor.stage = stage.next(pipeline.instrument);
+                    investor.interest = (investor.interest + 5.0).min(100.0);
+                    if investor.stage == PipelineStage::TermSheet {
+                        // Keen investors pay up
+                        let valuation = pre_money_valuation(state, &effectiveness) * (0.8 + investor.interest / 250.0);
+                        investor.offer = Some(TermSheet { valuation, expires_week: state.week + OFFER_EXPIRY_WEEKS });
+                    }
+                } else {
+                    investor.interest -= rng.random_range(5.0..15.0);
+                    if investor.interest < PASS_THRESHOLD {
+                        investor.stage = PipelineStage::Passed;
+                    }
+                }
+            }
+        }
+    }
+    state.investor_pipeline = Some(pipeline);
+}
+
+/// Number of live term sheets, for event triggers
+pub fn live_offers(state: &GameState) -> usize {
+    state.investor_pipeline.as_ref().map_or(0, |p| p.offers().count())
+}
+
+/// End the raise: sign the best term sheet if there is one
+///
+/// The best offer sets the price and every other live offer follows on at it;
+/// the round raises their combined checks, up to the target. The pipeline is
+/// cleared either way; returns the stat changes and a summary when money came in.
+pub fn close_round(state: &mut GameState) -> Option<(Vec<StatEffect>, String)> {
+    let pipeline = state.investor_pipeline.take()?;
+    let lead = pipeline.best_offer()?;
+    let signed: f64 = pipeline.offers().map(|i| i.check_size).sum();
+    let financing = Financing {
+        instrument: pipeline.instrument,
+        amount: signed.min(pipeline.target),
+        valuation: offer_valuation(lead),
+    };
+    let (effects, summary) = close_financing(state, &financing);
+    Some((effects, format!("{} led the round. {}", lead.name, summary)))
+}
+
+/// Apply a dilemma's decision about the live raise; returns the stat changes
+pub fn apply_round_decision(state: &mut GameState, decision: RoundDecision, rng: &mut GameRng) -> Vec<StatEffect> {
+    match decision {
+        RoundDecision::SignBestOffer => {
+            if live_offers(state) == 0 {
+                return Vec::new();
+            }
+            // Signing early ends the raise and frees its focus
+            state.projects.retain(|p| !matches!(p.action, Action::Fundraise { .. }));
+            close_round(state).map(|(effects, _)| effects).unwrap_or_default()
+        }
+        RoundDecision::ShopAround => {
+            let offers = live_offers(state);
+            if let Some(pipeline) = &mut state.investor_pipeline {
+                // A lone offer is easier to lose than one with competition
+                let withdraw_chance = if offers > 1 { 0.2 } else { 0.5 };
+                for investor in &mut pipeline.investors {
+                    if let Some(offer) = &mut investor.offer {
+                        if rng.random_bool(withdraw_chance) {
+                            investor.offer = None;
+                            investor.stage = PipelineStage::Passed;
+                        } else {
+                            offer.valuation *= 1.0 + BIDDING_BUMP;
+                        }
+                    } else if investor.stage != PipelineStage::Passed {
+                        investor.interest = (investor.interest + 10.0).min(100.0);
+                    }
+                }
+            }
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::projects::start_project;
+
+    fn raise(state: &mut GameState, instrument: Instrument) -> GameRng {
+        let mut rng = state.next_rng();
+        let action = Action::Fundraise { target: 500_000.0, instrument };
+        start_project(state, &action);
+        open_pipeline(state, 500_000.0, instrument, &mut rng);
+        rng
+    }
+
+    #[test]
+    fn test_strong_company_gets_term_sheets() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 11);
+        state.reputation = 90.0;
+        let mut rng = raise(&mut state, Instrument::PricedRound);
+        let investors = state.investor_pipeline.as_ref().unwrap().investors.len();
+        assert!((3..=5).contains(&investors));
+
+        for _ in 0..6 {
+            advance_pipeline(&mut state, &[], &mut rng);
+        }
+
+        assert!(live_offers(&state) > 0);
+        let best = state.investor_pipeline.as_ref().unwrap().best_offer().unwrap();
+        assert_eq!(best.stage, PipelineStage::TermSheet);
+        assert!(best.offer.as_ref().unwrap().valuation > 0.0);
+    }
+
+    #[test]
+    fn test_unsigned_offer_lapses() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 3);
+        let mut rng = raise(&mut state, Instrument::Safe);
+        let pipeline = state.investor_pipeline.as_mut().unwrap();
+        pipeline.investors[0].stage = PipelineStage::TermSheet;
+        pipeline.investors[0].offer = Some(TermSheet { valuation: 8_000_000.0, expires_week: state.week });
+
+        advance_pipeline(&mut state, &[], &mut rng);
+        assert_eq!(live_offers(&state), 1);
+        state.week += 1;
+        advance_pipeline(&mut state, &[], &mut rng);
+
+        let investor = &state.investor_pipeline.as_ref().unwrap().investors[0];
+        assert_eq!(investor.stage, PipelineStage::Passed);
+        assert!(investor.offer.is_none());
+    }
+
+    #[test]
+    fn test_signing_closes_round_and_frees_focus() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        let mut rng = raise(&mut state, Instrument::PricedRound);
+        let bank = state.bank;
+        let pipeline = state.investor_pipeline.as_mut().unwrap();
+        for (investor, valuation) in pipeline.investors.iter_mut().zip([6_000_000.0, 9_000_000.0]) {
+            investor.stage = PipelineStage::TermSheet;
+            investor.offer = Some(TermSheet { valuation, expires_week: 10 });
+        }
+
+        let signed: f64 = pipeline.investors.iter().take(2).map(|i| i.check_size).sum();
+
+        apply_round_decision(&mut state, RoundDecision::SignBestOffer, &mut rng);
+
+        assert_eq!(state.bank, bank + signed.min(500_000.0));
+        assert_eq!(state.cap_table.rounds[0].pre_money, 9_000_000.0);
+        assert!(state.investor_pipeline.is_none());
+        assert!(state.projects.is_empty());
+    }
+
+    #[test]
+    fn test_round_raises_only_signed_checks() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        raise(&mut state, Instrument::PricedRound);
+        let bank = state.bank;
+        let pipeline = state.investor_pipeline.as_mut().unwrap();
+        pipeline.investors[0].check_size = 150_000.0;
+        pipeline.investors[0].stage = PipelineStage::TermSheet;
+        pipeline.investors[0].offer = Some(TermSheet { valuation: 6_000_000.0, expires_week: 10 });
+
+        close_round(&mut state).unwrap();
+
+        assert_eq!(state.bank, bank + 150_000.0);
+    }
+}
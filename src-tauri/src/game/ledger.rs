@@ -0,0 +1,370 @@
+use serde::{Deserialize, Serialize};
+use super::actions::Action;
+use super::billing::{contracted_mrr, recognize_revenue};
+use super::state::{DifficultyMode, GameState};
+use super::team::Employee;
+
+/// Weeks of transactions kept, matching the weekly history
+const LEDGER_WEEKS: u32 = 52;
+/// Payroll line for the founder, who can't be fired
+pub const FOUNDER_SALARY: &str = "Founder salary";
+
+/// Where money comes from or goes to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum LedgerCategory {
+    Revenue,
+    Payroll,
+    Infrastructure,
+    Tools,
+    Marketing,
+    Office,
+    Compliance,
+    /// Interest and other costs of borrowing
+    Interest,
+    /// One-off cash from events and actions that fit nowhere else
+    Other,
+    /// Equity raised: priced rounds, SAFEs and notes
+    Equity,
+    /// Loan proceeds and principal repaid
+    Debt,
+    /// Cash prepaid for annual contracts, less the revenue earned from it since
+    DeferredRevenue,
+}
+
+impl LedgerCategory {
+    /// Categories a monthly expense line can belong to
+    pub const EXPENSES: [LedgerCategory; 6] = [
+        LedgerCategory::Payroll,
+        LedgerCategory::Infrastructure,
+        LedgerCategory::Tools,
+        LedgerCategory::Marketing,
+        LedgerCategory::Office,
+        LedgerCategory::Compliance,
+    ];
+
+    /// Cash from raising or repaying capital, which stays off the P&L
+    pub fn is_financing(&self) -> bool {
+        matches!(self, LedgerCategory::Equity | LedgerCategory::Debt)
+    }
+}
+
+/// One recurring monthly cost that makes up the burn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpenseLine {
+    pub category: LedgerCategory,
+    pub label: String,
+    pub monthly: f64,
+}
+
+/// One movement of cash in or out of the bank
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    pub week: u32,
+    pub category: LedgerCategory,
+    /// Positive for money in, negative for money out
+    pub amount: f64,
+    pub reason: String,
+}
+
+/// Every bank movement over the last year
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CategoryTotal {
+    pub category: LedgerCategory,
+    pub amount: f64,
+}
+
+/// Income statement over a range of weeks
+///
+/// Annual contracts count as revenue as the service is delivered, not when
+/// the cash comes in; the difference shows up as the change in deferred revenue.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProfitAndLoss {
+    pub from_week: u32,
+    pub to_week: u32,
+    pub revenue: f64,
+    /// Costs by category, positive for money spent
+    pub operating_expenses: Vec<CategoryTotal>,
+    pub operating_income: f64,
+    pub interest: f64,
+    pub net_income: f64,
+    /// Net cash from equity and debt, outside the P&L
+    pub financing: f64,
+    /// Cash collected upfront less revenue earned from prepayments
+    pub deferred_revenue: f64,
+    pub net_cash_flow: f64,
+}
+
+impl Ledger {
+    /// Add a transaction, dropping those older than a year
+    pub fn record(&mut self, week: u32, category: LedgerCategory, amount: f64, reason: &str) {
+        if amount.abs() < 0.005 {
+            return;
+        }
+        self.transactions.push(Transaction { week, category, amount, reason: reason.to_string() });
+        let oldest = week.saturating_sub(LEDGER_WEEKS - 1);
+        self.transactions.retain(|t| t.week >= oldest);
+    }
+
+    /// Net amount per category over the weeks `from..=to`
+    pub fn totals(&self, from_week: u32, to_week: u32) -> Vec<CategoryTotal> {
+        let mut totals: Vec<CategoryTotal> = Vec::new();
+        for transaction in self.transactions.iter().filter(|t| (from_week..=to_week).contains(&t.week)) {
+            match totals.iter_mut().find(|t| t.category == transaction.category) {
+                Some(total) => total.amount += transaction.amount,
+                None => totals.push(CategoryTotal { category: transaction.category, amount: transaction.amount }),
+            }
+        }
+        totals.sort_by_key(|t| t.category);
+        totals
+    }
+
+    pub fn profit_and_loss(&self, from_week: u32, to_week: u32) -> ProfitAndLoss {
+        let totals = self.totals(from_week, to_week);
+        let sum = |keep: &dyn Fn(LedgerCategory) -> bool| totals.iter().filter(|t| keep(t.category)).map(|t| t.amount).sum::<f64>();
+
+        let revenue = sum(&|c| c == LedgerCategory::Revenue);
+        let interest = -sum(&|c| c == LedgerCategory::Interest);
+        let financing = sum(&|c| c.is_financing());
+        let deferred_revenue = sum(&|c| c == LedgerCategory::DeferredRevenue);
+        let operating_expenses: Vec<CategoryTotal> = totals
+            .iter()
+            .filter(|t| {
+                !t.category.is_financing()
+                    && !matches!(t.category, LedgerCategory::Revenue | LedgerCategory::Interest | LedgerCategory::DeferredRevenue)
+            })
+            .map(|t| CategoryTotal { category: t.category, amount: -t.amount })
+            .collect();
+        let operating_income = revenue - operating_expenses.iter().map(|t| t.amount).sum::<f64>();
+        let net_income = operating_income - interest;
+
+        ProfitAndLoss {
+            from_week,
+            to_week,
+            revenue,
+            operating_expenses,
+            operating_income,
+            interest,
+            net_income,
+            financing,
+            deferred_revenue,
+            net_cash_flow: net_income + financing + deferred_revenue,
+        }
+    }
+}
+
+/// The burn a new company starts with, line by line
+pub fn starting_expenses(difficulty: &DifficultyMode) -> Vec<ExpenseLine> {
+    use LedgerCategory::*;
+    let lines: &[(LedgerCategory, &str, f64)] = match difficulty {
+        DifficultyMode::IndieBootstrap => &[
+            (Payroll, FOUNDER_SALARY, 5_000.0),
+            (Infrastructure, "Hosting", 1_000.0),
+            (Tools, "Software subscriptions", 800.0),
+            (Marketing, "Marketing", 700.0),
+            (Office, "Coworking desk", 500.0),
+        ],
+        DifficultyMode::VCTrack => &[
+            (Payroll, FOUNDER_SALARY, 12_000.0),
+            (Payroll, "Founding engineer", 14_000.0),
+            (Payroll, "Founding engineer", 14_000.0),
+            (Payroll, "Designer", 10_000.0),
+            (Infrastructure, "Cloud hosting", 8_000.0),
+            (Tools, "Software subscriptions", 5_000.0),
+            (Marketing, "Marketing", 10_000.0),
+            (Office, "Office lease", 7_000.0),
+        ],
+        DifficultyMode::RegulatedFintech => &[
+            (Payroll, FOUNDER_SALARY, 8_000.0),
+            (Payroll, "Engineer", 12_000.0),
+            (Infrastructure, "Hosting and security", 4_000.0),
+            (Tools, "Software subscriptions", 3_000.0),
+            (Marketing, "Marketing", 2_000.0),
+            (Office, "Office", 3_000.0),
+            (Compliance, "Legal and compliance", 8_000.0),
+        ],
+        DifficultyMode::InfraDevTool => &[
+            (Payroll, FOUNDER_SALARY, 8_000.0),
+            (Payroll, "Engineer", 10_000.0),
+            (Infrastructure, "Cloud and CI", 4_000.0),
+            (Tools, "Software subscriptions", 1_500.0),
+            (Marketing, "Marketing", 500.0),
+            (Office, "Coworking", 1_000.0),
+        ],
+    };
+    lines
+        .iter()
+        .map(|(category, label, monthly)| ExpenseLine { category: *category, label: label.to_string(), monthly: *monthly })
+        .collect()
+}
+
+/// Starting expense lines scaled to an existing burn, for games saved before the ledger
+pub fn expenses_for_burn(difficulty: &DifficultyMode, burn: f64) -> Vec<ExpenseLine> {
+    let mut lines = starting_expenses(difficulty);
+    let factor = burn / difficulty.starting_burn();
+    for line in &mut lines {
+        line.monthly *= factor;
+    }
+    lines
+}
+
+/// Monthly burn for one category
+pub fn category_burn(state: &GameState, category: LedgerCategory) -> f64 {
+    state.expenses.iter().filter(|l| l.category == category).map(|l| l.monthly).sum()
+}
+
+fn sync_burn(state: &mut GameState) {
+    state.burn = state.expenses.iter().map(|l| l.monthly).sum();
+}
+
+/// Add a recurring cost and raise the burn to match
+pub fn add_expense(state: &mut GameState, category: LedgerCategory, label: &str, monthly: f64) {
+    state.expenses.push(ExpenseLine { category, label: label.to_string(), monthly });
+    sync_burn(state);
+}
+
+/// Expense lines with payroll replaced by one line per person on the team
+pub fn with_payroll(expenses: &[ExpenseLine], team: &[Employee]) -> Vec<ExpenseLine> {
+    let payroll = team.iter().map(|employee| ExpenseLine {
+        category: LedgerCategory::Payroll,
+        label: if employee.is_founder() {
+            FOUNDER_SALARY.to_string()
+        } else {
+            format!("{} ({})", employee.name, employee.role.label())
+        },
+        monthly: employee.salary,
+    });
+    payroll.chain(expenses.iter().filter(|l| l.category != LedgerCategory::Payroll).cloned()).collect()
+}
+
+/// Rebuild the payroll lines from the roster and the burn with them
+pub fn sync_payroll(state: &mut GameState) {
+    state.expenses = with_payroll(&state.expenses, &state.team);
+    sync_burn(state);
+}
+
+/// Move the burn to `total` by scaling every line, as when an event cuts or boosts spending
+pub fn scale_expenses(state: &mut GameState, total: f64) {
+    let current: f64 = state.expenses.iter().map(|l| l.monthly).sum();
+    if current > 0.0 {
+        for line in &mut state.expenses {
+            line.monthly *= total / current;
+        }
+        // Payroll lines follow salaries, so a cut or raise lands on the people too
+        for employee in &mut state.team {
+            employee.salary *= total / current;
+        }
+    } else if total > 0.0 {
+        state.expenses.push(ExpenseLine { category: LedgerCategory::Office, label: "Operations".to_string(), monthly: total });
+    }
+    state.burn = total;
+}
+
+/// Record whatever the bank moved since `old_bank`
+pub fn record_bank_change(state: &mut GameState, old_bank: f64, category: LedgerCategory, reason: &str) {
+    let week = state.week;
+    state.ledger.record(week, category, state.bank - old_bank, reason);
+}
+
+/// Ledger category for the cash an action moves
+pub fn action_category(action: &Action) -> LedgerCategory {
+    match action {
+        Action::Hire { .. } | Action::Fire { .. } => LedgerCategory::Payroll,
+        Action::PaidAds { .. } | Action::ContentLaunch { .. } | Action::DevRel { .. } => LedgerCategory::Marketing,
+        Action::ComplianceWork { .. } => LedgerCategory::Compliance,
+        Action::Fundraise { .. } => LedgerCategory::Equity,
+        Action::TakeLoan { .. } => LedgerCategory::Debt,
+        _ => LedgerCategory::Other,
+    }
+}
+
+/// Pay a week of every expense category and bank a week of revenue
+///
+/// The week's spend is the burn split by each category's share of the expense
+/// lines, so the bank always moves by exactly a quarter of the monthly burn.
+/// Monthly customers pay as they go; annual contracts were paid upfront and
+/// only earn their revenue now.
+pub fn settle_week(state: &mut GameState) {
+    let week = state.week;
+    let weekly_burn = state.burn / 4.0;
+    let lines_total: f64 = state.expenses.iter().map(|l| l.monthly).sum();
+
+    for category in LedgerCategory::EXPENSES {
+        let share = if lines_total > 0.0 { category_burn(state, category) / lines_total } else { 0.0 };
+        state.ledger.record(week, category, -weekly_burn * share, "Weekly expenses");
+    }
+    if lines_total <= 0.0 {
+        state.ledger.record(week, LedgerCategory::Other, -weekly_burn, "Weekly expenses");
+    }
+    state.bank -= weekly_burn;
+
+    let weekly_mrr = (state.mrr - contracted_mrr(state, None)).max(0.0) / 4.0;
+    state.bank += weekly_mrr;
+    state.ledger.record(week, LedgerCategory::Revenue, weekly_mrr, "Subscription revenue");
+    recognize_revenue(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_expenses_add_up_to_burn() {
+        for difficulty in [DifficultyMode::IndieBootstrap, DifficultyMode::VCTrack, DifficultyMode::RegulatedFintech, DifficultyMode::InfraDevTool] {
+            let total: f64 = starting_expenses(&difficulty).iter().map(|l| l.monthly).sum();
+            assert_eq!(total, difficulty.starting_burn(), "{:?}", difficulty);
+        }
+    }
+
+    #[test]
+    fn test_week_is_recorded_by_category() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        state.mrr = 4_000.0;
+        let bank = state.bank;
+        state.week = 1;
+
+        settle_week(&mut state);
+
+        let totals = state.ledger.totals(1, 1);
+        let net: f64 = totals.iter().map(|t| t.amount).sum();
+        assert!((state.bank - bank - net).abs() < 1e-9);
+        let payroll = totals.iter().find(|t| t.category == LedgerCategory::Payroll).unwrap();
+        assert_eq!(payroll.amount, -1_250.0);
+    }
+
+    #[test]
+    fn test_profit_and_loss_separates_financing() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, LedgerCategory::Revenue, 1_000.0, "Revenue");
+        ledger.record(1, LedgerCategory::Payroll, -600.0, "Payroll");
+        ledger.record(2, LedgerCategory::Interest, -100.0, "Loan interest");
+        ledger.record(2, LedgerCategory::Equity, 50_000.0, "Seed round");
+
+        let pnl = ledger.profit_and_loss(1, 2);
+
+        assert_eq!(pnl.operating_income, 400.0);
+        assert_eq!(pnl.net_income, 300.0);
+        assert_eq!(pnl.financing, 50_000.0);
+        assert_eq!(pnl.net_cash_flow, 50_300.0);
+    }
+
+    #[test]
+    fn test_payroll_follows_the_team() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        let mut engineer = state.team[0].clone();
+        engineer.id = 2;
+        engineer.role = crate::game::team::Role::Engineer;
+        engineer.salary = 9_000.0;
+        state.team.push(engineer);
+
+        sync_payroll(&mut state);
+
+        assert_eq!(state.burn, 17_000.0);
+        assert_eq!(category_burn(&state, LedgerCategory::Payroll), 14_000.0);
+        assert_eq!(state.expenses[0].label, FOUNDER_SALARY);
+    }
+}
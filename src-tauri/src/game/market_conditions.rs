@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 use rand::Rng;
-use std::collections::HashMap;
 use super::state::GameState;
 use super::actions::Action;
 use super::competitors::{Competitor, get_random_competitor};
+use super::rng::GameRng;
+use super::effects::{apply_effects, Effect, Stat, StatEffect};
 
 /// Represents a market condition that affects gameplay
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,11 +19,90 @@ pub struct MarketCondition {
 /// Individual modifier applied by a market condition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketModifier {
-    pub stat_affected: String,
+    #[serde(rename = "stat_affected")]
+    pub target: ModifierTarget,
     pub multiplier: f64,
     pub description: String,
 }
 
+/// What a market modifier scales: a game stat, or how well a kind of action works
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ModifierTarget {
+    Stat(Stat),
+    FundraisingSuccess,
+    HiringCost,
+    HireVelocity,
+    MrrGrowth,
+}
+
+impl ModifierTarget {
+    /// Snake-case key used in saves and by the UI
+    pub fn key(&self) -> &'static str {
+        match self {
+            ModifierTarget::Stat(stat) => match stat {
+                Stat::Bank => "bank",
+                Stat::Burn => "burn",
+                Stat::Mrr => "mrr",
+                Stat::Wau => "wau",
+                Stat::WauGrowth => "wau_growth",
+                Stat::ChurnRate => "churn_rate",
+                Stat::Morale => "morale",
+                Stat::Reputation => "reputation",
+                Stat::Nps => "nps",
+                Stat::TechDebt => "tech_debt",
+                Stat::ComplianceRisk => "compliance_risk",
+                Stat::Velocity => "velocity",
+                Stat::FounderEquity => "founder_equity",
+                Stat::FocusSlots => "focus_slots",
+                Stat::OnboardingQuality => "onboarding_quality",
+            },
+            ModifierTarget::FundraisingSuccess => "fundraising_success",
+            ModifierTarget::HiringCost => "hiring_cost",
+            ModifierTarget::HireVelocity => "hire_velocity_bonus",
+            ModifierTarget::MrrGrowth => "mrr_growth",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        let actions = [
+            ModifierTarget::FundraisingSuccess,
+            ModifierTarget::HiringCost,
+            ModifierTarget::HireVelocity,
+            ModifierTarget::MrrGrowth,
+        ];
+        Stat::ALL
+            .into_iter()
+            .map(ModifierTarget::Stat)
+            .chain(actions)
+            .find(|target| target.key() == key)
+    }
+}
+
+impl TryFrom<String> for ModifierTarget {
+    type Error = String;
+
+    fn try_from(key: String) -> Result<Self, Self::Error> {
+        Self::from_key(&key).ok_or_else(|| format!("Unknown market modifier target '{}'", key))
+    }
+}
+
+impl From<ModifierTarget> for String {
+    fn from(target: ModifierTarget) -> Self {
+        target.key().to_string()
+    }
+}
+
+impl MarketModifier {
+    /// The stat effect this modifier applies, if it targets a stat
+    pub fn effect(&self) -> Option<Effect> {
+        match self.target {
+            ModifierTarget::Stat(stat) => Some(Effect::multiply(stat, self.multiplier)),
+            _ => None,
+        }
+    }
+}
+
 /// Types of market events that can occur
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MarketEvent {
@@ -44,9 +124,8 @@ pub enum MarketEvent {
 }
 
 /// Generate a random market condition with 15% probability
-pub fn generate_market_condition(state: &GameState, week: u32) -> Option<MarketCondition> {
-    let mut rng = rand::thread_rng();
-    if !rng.gen_bool(0.15) {
+pub fn generate_market_condition(state: &GameState, week: u32, rng: &mut GameRng) -> Option<MarketCondition> {
+    if !rng.random_bool(0.15) {
         return None;
     }
 
@@ -66,10 +145,10 @@ pub fn generate_market_condition(state: &GameState, week: u32) -> Option<MarketC
         MarketEvent::DataBreachScare,
         // CompetitorFundingRound, CompetitorAcquisition, CompetitorPricingWar removed - now triggered by actions
     ];
-    let event = events[rng.gen_range(0..events.len())].clone();
+    let event = events[rng.random_range(0..events.len())].clone();
 
     // Random duration 4-8 weeks
-    let duration = 4 + rng.gen_range(0..5);
+    let duration = 4 + rng.random_range(0..5);
 
     let (name, description, modifiers) = match event {
         MarketEvent::BullMarket => (
@@ -83,7 +162,7 @@ pub fn generate_market_condition(state: &GameState, week: u32) -> Option<MarketC
             get_modifiers_for_event(&event),
         ),
         MarketEvent::CompetitorLaunch => {
-            if let Some(competitor) = get_random_competitor(&state.competitors) {
+            if let Some(competitor) = get_random_competitor(&state.competitors, rng) {
                 // Scale modifiers based on competitor's funding stage
                 let scale_factor = match competitor.funding_stage {
                     super::competitors::FundingStage::Bootstrapped => 0.8,
@@ -161,7 +240,7 @@ pub fn generate_market_condition(state: &GameState, week: u32) -> Option<MarketC
             get_modifiers_for_event(&event),
         ),
         MarketEvent::CompetitorFundingRound => {
-            if let Some(competitor) = get_random_competitor(&state.competitors) {
+            if let Some(competitor) = get_random_competitor(&state.competitors, rng) {
                 (
                     format!("{} Raises ${}M", competitor.name, competitor.total_funding / 1_000_000.0),
                     format!("{} just announced a funding round. They're hiring aggressively and planning a major marketing push.", competitor.name),
@@ -176,7 +255,7 @@ pub fn generate_market_condition(state: &GameState, week: u32) -> Option<MarketC
             }
         },
         MarketEvent::CompetitorAcquisition => {
-            if let Some(competitor) = get_random_competitor(&state.competitors) {
+            if let Some(competitor) = get_random_competitor(&state.competitors, rng) {
                 (
                     format!("{} Acquired", competitor.name),
                     format!("{} was acquired by a larger company. Market consolidation may affect your positioning.", competitor.name),
@@ -191,7 +270,7 @@ pub fn generate_market_condition(state: &GameState, week: u32) -> Option<MarketC
             }
         },
         MarketEvent::CompetitorPricingWar => {
-            if let Some(competitor) = get_random_competitor(&state.competitors) {
+            if let Some(competitor) = get_random_competitor(&state.competitors, rng) {
                 (
                     format!("Pricing War with {}", competitor.name),
                     format!("{} slashed prices by 30%. Your customers are asking why you're more expensive.", competitor.name),
@@ -221,210 +300,210 @@ fn get_modifiers_for_event(event: &MarketEvent) -> Vec<MarketModifier> {
     match event {
         MarketEvent::BullMarket => vec![
             MarketModifier {
-                stat_affected: "fundraising_success".to_string(),
+                target: ModifierTarget::FundraisingSuccess,
                 multiplier: 1.3,
                 description: "+30% fundraising success".to_string(),
             },
             MarketModifier {
-                stat_affected: "wau_growth".to_string(),
+                target: ModifierTarget::Stat(Stat::WauGrowth),
                 multiplier: 1.2,
                 description: "+20% WAU growth".to_string(),
             },
             MarketModifier {
-                stat_affected: "burn".to_string(),
+                target: ModifierTarget::Stat(Stat::Burn),
                 multiplier: 1.15,
                 description: "+15% burn (hiring expensive)".to_string(),
             },
         ],
         MarketEvent::Recession => vec![
             MarketModifier {
-                stat_affected: "fundraising_success".to_string(),
+                target: ModifierTarget::FundraisingSuccess,
                 multiplier: 0.6,
                 description: "-40% fundraising success".to_string(),
             },
             MarketModifier {
-                stat_affected: "wau_growth".to_string(),
+                target: ModifierTarget::Stat(Stat::WauGrowth),
                 multiplier: 0.9,
                 description: "-10% WAU growth".to_string(),
             },
             MarketModifier {
-                stat_affected: "churn_rate".to_string(),
+                target: ModifierTarget::Stat(Stat::ChurnRate),
                 multiplier: 1.3,
                 description: "+30% churn".to_string(),
             },
             MarketModifier {
-                stat_affected: "burn".to_string(),
+                target: ModifierTarget::Stat(Stat::Burn),
                 multiplier: 0.8,
                 description: "-20% burn (talent cheaper)".to_string(),
             },
         ],
         MarketEvent::CompetitorLaunch => vec![
             MarketModifier {
-                stat_affected: "wau_growth".to_string(),
+                target: ModifierTarget::Stat(Stat::WauGrowth),
                 multiplier: 0.85,
                 description: "-15% WAU growth".to_string(),
             },
             MarketModifier {
-                stat_affected: "reputation".to_string(),
+                target: ModifierTarget::Stat(Stat::Reputation),
                 multiplier: 0.9,
                 description: "-10 reputation".to_string(),
             },
             MarketModifier {
-                stat_affected: "churn_rate".to_string(),
+                target: ModifierTarget::Stat(Stat::ChurnRate),
                 multiplier: 1.05,
                 description: "+5% churn".to_string(),
             },
         ],
         MarketEvent::TechBoom => vec![
             MarketModifier {
-                stat_affected: "hiring_cost".to_string(),
+                target: ModifierTarget::HiringCost,
                 multiplier: 1.5,
                 description: "+50% hiring cost".to_string(),
             },
             MarketModifier {
-                stat_affected: "velocity".to_string(),
+                target: ModifierTarget::Stat(Stat::Velocity),
                 multiplier: 1.2,
                 description: "+20% velocity (talent available)".to_string(),
             },
             MarketModifier {
-                stat_affected: "fundraising_success".to_string(),
+                target: ModifierTarget::FundraisingSuccess,
                 multiplier: 1.25,
                 description: "+25% fundraising success".to_string(),
             },
         ],
         MarketEvent::RegulationChange => vec![
             MarketModifier {
-                stat_affected: "compliance_risk".to_string(),
+                target: ModifierTarget::Stat(Stat::ComplianceRisk),
                 multiplier: 1.4,
                 description: "+40% compliance risk".to_string(),
             },
             MarketModifier {
-                stat_affected: "velocity".to_string(),
+                target: ModifierTarget::Stat(Stat::Velocity),
                 multiplier: 0.85,
                 description: "-15% velocity".to_string(),
             },
         ],
         MarketEvent::TalentWar => vec![
             MarketModifier {
-                stat_affected: "hiring_cost".to_string(),
+                target: ModifierTarget::HiringCost,
                 multiplier: 1.6,
                 description: "+60% hiring cost".to_string(),
             },
             MarketModifier {
-                stat_affected: "morale".to_string(),
+                target: ModifierTarget::Stat(Stat::Morale),
                 multiplier: 0.9,
                 description: "-10 morale (poaching)".to_string(),
             },
             MarketModifier {
-                stat_affected: "hire_velocity_bonus".to_string(),
+                target: ModifierTarget::HireVelocity,
                 multiplier: 1.2,
                 description: "+0.2 velocity if you hire".to_string(),
             },
         ],
         MarketEvent::ViralTrend => vec![
             MarketModifier {
-                stat_affected: "wau_growth".to_string(),
+                target: ModifierTarget::Stat(Stat::WauGrowth),
                 multiplier: 1.4,
                 description: "+40% WAU growth".to_string(),
             },
             MarketModifier {
-                stat_affected: "reputation".to_string(),
+                target: ModifierTarget::Stat(Stat::Reputation),
                 multiplier: 1.1,
                 description: "+10 reputation".to_string(),
             },
         ],
         MarketEvent::SupplyChainDisruption => vec![
             MarketModifier {
-                stat_affected: "velocity".to_string(),
+                target: ModifierTarget::Stat(Stat::Velocity),
                 multiplier: 0.8,
                 description: "-20% velocity".to_string(),
             },
             MarketModifier {
-                stat_affected: "burn".to_string(),
+                target: ModifierTarget::Stat(Stat::Burn),
                 multiplier: 1.1,
                 description: "+10% burn".to_string(),
             },
         ],
         MarketEvent::EconomicStimulus => vec![
             MarketModifier {
-                stat_affected: "fundraising_success".to_string(),
+                target: ModifierTarget::FundraisingSuccess,
                 multiplier: 1.2,
                 description: "+20% fundraising success".to_string(),
             },
             MarketModifier {
-                stat_affected: "wau_growth".to_string(),
+                target: ModifierTarget::Stat(Stat::WauGrowth),
                 multiplier: 1.1,
                 description: "+10% WAU growth".to_string(),
             },
         ],
         MarketEvent::IndustryConsolidation => vec![
             MarketModifier {
-                stat_affected: "fundraising_success".to_string(),
+                target: ModifierTarget::FundraisingSuccess,
                 multiplier: 1.15,
                 description: "+15% fundraising success".to_string(),
             },
             MarketModifier {
-                stat_affected: "reputation".to_string(),
+                target: ModifierTarget::Stat(Stat::Reputation),
                 multiplier: 0.95,
                 description: "-5 reputation".to_string(),
             },
         ],
         MarketEvent::TechCrunch => vec![
             MarketModifier {
-                stat_affected: "reputation".to_string(),
+                target: ModifierTarget::Stat(Stat::Reputation),
                 multiplier: 1.2,
                 description: "+20 reputation".to_string(),
             },
             MarketModifier {
-                stat_affected: "wau_growth".to_string(),
+                target: ModifierTarget::Stat(Stat::WauGrowth),
                 multiplier: 1.15,
                 description: "+15% WAU growth".to_string(),
             },
         ],
         MarketEvent::DataBreachScare => vec![
             MarketModifier {
-                stat_affected: "compliance_risk".to_string(),
+                target: ModifierTarget::Stat(Stat::ComplianceRisk),
                 multiplier: 1.3,
                 description: "+30% compliance risk".to_string(),
             },
             MarketModifier {
-                stat_affected: "reputation".to_string(),
+                target: ModifierTarget::Stat(Stat::Reputation),
                 multiplier: 0.95,
                 description: "-5 reputation".to_string(),
             },
         ],
         MarketEvent::CompetitorFundingRound => vec![
             MarketModifier {
-                stat_affected: "reputation".to_string(),
+                target: ModifierTarget::Stat(Stat::Reputation),
                 multiplier: 0.95,
                 description: "-5 reputation".to_string(),
             },
             MarketModifier {
-                stat_affected: "churn_rate".to_string(),
+                target: ModifierTarget::Stat(Stat::ChurnRate),
                 multiplier: 1.1,
                 description: "+10% churn".to_string(),
             },
         ],
         MarketEvent::CompetitorAcquisition => vec![
             MarketModifier {
-                stat_affected: "fundraising_success".to_string(),
+                target: ModifierTarget::FundraisingSuccess,
                 multiplier: 0.85,
                 description: "-15% fundraising success".to_string(),
             },
             MarketModifier {
-                stat_affected: "reputation".to_string(),
+                target: ModifierTarget::Stat(Stat::Reputation),
                 multiplier: 1.2,
                 description: "+20 reputation (acquisition interest)".to_string(),
             },
         ],
         MarketEvent::CompetitorPricingWar => vec![
             MarketModifier {
-                stat_affected: "mrr_growth".to_string(),
+                target: ModifierTarget::MrrGrowth,
                 multiplier: 0.9,
                 description: "-10% MRR growth".to_string(),
             },
             MarketModifier {
-                stat_affected: "churn_rate".to_string(),
+                target: ModifierTarget::Stat(Stat::ChurnRate),
                 multiplier: 1.15,
                 description: "+15% churn".to_string(),
             },
@@ -433,40 +512,16 @@ fn get_modifiers_for_event(event: &MarketEvent) -> Vec<MarketModifier> {
 }
 
 /// Apply ongoing effects of active market conditions to the game state
-pub fn apply_market_modifiers(state: &mut GameState, conditions: &[MarketCondition]) {
-    // Create a map of stat multipliers
-    let mut multipliers = HashMap::new();
-    for condition in conditions {
-        for modifier in &condition.modifiers {
-            let entry = multipliers.entry(modifier.stat_affected.clone()).or_insert(1.0);
-            *entry *= modifier.multiplier;
-        }
-    }
-
-    // Apply multipliers to relevant stats
-    if let Some(m) = multipliers.get("wau_growth") {
-        state.wau_growth_rate *= *m;
-    }
-    if let Some(m) = multipliers.get("burn") {
-        state.burn *= *m;
-    }
-    if let Some(m) = multipliers.get("churn_rate") {
-        state.churn_rate *= *m;
-    }
-    if let Some(m) = multipliers.get("velocity") {
-        state.velocity *= *m;
-    }
-    if let Some(m) = multipliers.get("morale") {
-        state.morale *= *m;
-    }
-    if let Some(m) = multipliers.get("reputation") {
-        state.reputation *= *m;
-    }
-    if let Some(m) = multipliers.get("compliance_risk") {
-        state.compliance_risk *= *m;
-    }
-
-    // Note: Other modifiers like fundraising_success, hiring_cost are used in action resolution
+///
+/// Only stat modifiers apply here; action modifiers like fundraising_success
+/// and hiring_cost reach actions through `action_effectiveness`.
+pub fn apply_market_modifiers(state: &mut GameState, conditions: &[MarketCondition]) -> Vec<StatEffect> {
+    let effects: Vec<Effect> = conditions
+        .iter()
+        .flat_map(|c| &c.modifiers)
+        .filter_map(|m| m.effect())
+        .collect();
+    apply_effects(state, &effects)
 }
 
 /// Get the list of active market conditions
@@ -493,12 +548,12 @@ pub fn generate_competitor_funding_condition(competitor: &Competitor, amount: Op
     let amount_display = amount.map(|a| format!("${}M", a / 1_000_000.0)).unwrap_or_else(|| format!("${}M", competitor.total_funding / 1_000_000.0));
     let modifiers = vec![
         MarketModifier {
-            stat_affected: "reputation".to_string(),
+            target: ModifierTarget::Stat(Stat::Reputation),
             multiplier: 0.95,
             description: "-5 reputation".to_string(),
         },
         MarketModifier {
-            stat_affected: "churn_rate".to_string(),
+            target: ModifierTarget::Stat(Stat::ChurnRate),
             multiplier: 1.1,
             description: "+10% churn".to_string(),
         },
@@ -522,12 +577,12 @@ pub fn generate_competitor_acquisition_condition(competitor: &Competitor, amount
     let amount_display = amount.map(|a| format!("${}M", a / 1_000_000.0)).unwrap_or("significant amount".to_string());
     let modifiers = vec![
         MarketModifier {
-            stat_affected: "fundraising_success".to_string(),
+            target: ModifierTarget::FundraisingSuccess,
             multiplier: 0.85,
             description: "-15% fundraising success".to_string(),
         },
         MarketModifier {
-            stat_affected: "reputation".to_string(),
+            target: ModifierTarget::Stat(Stat::Reputation),
             multiplier: 1.2,
             description: "+20 reputation (acquisition interest)".to_string(),
         },
@@ -550,12 +605,12 @@ pub fn generate_pricing_war_condition(competitor: &Competitor) -> MarketConditio
     let duration = 4; // Pricing wars are intense but shorter
     let modifiers = vec![
         MarketModifier {
-            stat_affected: "wau_growth".to_string(),
+            target: ModifierTarget::Stat(Stat::WauGrowth),
             multiplier: 0.9,
             description: "-10% WAU growth".to_string(),
         },
         MarketModifier {
-            stat_affected: "churn_rate".to_string(),
+            target: ModifierTarget::Stat(Stat::ChurnRate),
             multiplier: 1.15,
             description: "+15% churn".to_string(),
         },
@@ -573,6 +628,53 @@ pub fn generate_pricing_war_condition(competitor: &Competitor) -> MarketConditio
     }
 }
 
+/// How active market conditions scale a single action's outcome
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Effectiveness {
+    /// General output multiplier: reach, ad yield, conversions, fundraising terms
+    pub output: f64,
+    /// Multiplier on the chance a fundraise closes (`fundraising_success`)
+    pub fundraising_success: f64,
+    /// Multiplier on the salary a hire adds to burn (`hiring_cost`)
+    pub hiring_cost: f64,
+    /// Multiplier on the velocity a hire adds (`hire_velocity_bonus`)
+    pub hire_velocity: f64,
+    /// Multiplier on the size of new deals (`mrr_growth`)
+    pub mrr_growth: f64,
+}
+
+impl Default for Effectiveness {
+    fn default() -> Self {
+        Self {
+            output: 1.0,
+            fundraising_success: 1.0,
+            hiring_cost: 1.0,
+            hire_velocity: 1.0,
+            mrr_growth: 1.0,
+        }
+    }
+}
+
+/// Combine every active condition into the effectiveness context for one action
+pub fn action_effectiveness(action: &Action, conditions: &[MarketCondition]) -> Effectiveness {
+    let mut effectiveness = Effectiveness {
+        output: get_action_effectiveness_modifier(action, conditions),
+        ..Effectiveness::default()
+    };
+
+    for modifier in conditions.iter().flat_map(|c| &c.modifiers) {
+        match modifier.target {
+            ModifierTarget::FundraisingSuccess => effectiveness.fundraising_success *= modifier.multiplier,
+            ModifierTarget::HiringCost => effectiveness.hiring_cost *= modifier.multiplier,
+            ModifierTarget::HireVelocity => effectiveness.hire_velocity *= modifier.multiplier,
+            ModifierTarget::MrrGrowth => effectiveness.mrr_growth *= modifier.multiplier,
+            ModifierTarget::Stat(_) => {}
+        }
+    }
+
+    effectiveness
+}
+
 /// Get effectiveness modifier for an action based on active market conditions
 pub fn get_action_effectiveness_modifier(action: &Action, conditions: &[MarketCondition]) -> f64 {
     let mut modifier: f64 = 1.0;
@@ -586,7 +688,7 @@ pub fn get_action_effectiveness_modifier(action: &Action, conditions: &[MarketCo
             },
             "Recession" => match action {
                 Action::Fundraise { .. } => modifier *= 0.6,
-                Action::Hire => modifier *= 0.8, // cheaper but harder?
+                Action::Hire { .. } => modifier *= 0.8, // cheaper but harder?
                 _ => {}
             },
             "CompetitorLaunch" => match action {
@@ -595,7 +697,7 @@ pub fn get_action_effectiveness_modifier(action: &Action, conditions: &[MarketCo
                 _ => {}
             },
             "TechBoom" => match action {
-                Action::Hire => modifier *= 1.2, // better talent
+                Action::Hire { .. } => modifier *= 1.2, // better talent
                 Action::Fundraise { .. } => modifier *= 1.25,
                 _ => {}
             },
@@ -605,7 +707,7 @@ pub fn get_action_effectiveness_modifier(action: &Action, conditions: &[MarketCo
                 _ => {}
             },
             "TalentWar" => match action {
-                Action::Hire => modifier *= 0.7, // expensive
+                Action::Hire { .. } => modifier *= 0.7, // expensive
                 Action::Coach { .. } => modifier *= 1.1, // retain talent
                 _ => {}
             },
@@ -664,12 +766,15 @@ pub fn get_action_effectiveness_modifier(action: &Action, conditions: &[MarketCo
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::cap_table::Instrument;
     use crate::game::state::DifficultyMode;
+    use crate::game::hiring::OfferTerms;
 
     #[test]
     fn test_generate_market_condition() {
-        let state = GameState::new(DifficultyMode::IndieBootstrap);
-        let condition = generate_market_condition(&state, 1);
+        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        let mut rng = state.next_rng();
+        let condition = generate_market_condition(&state, 1, &mut rng);
         // Since 15% chance, might be None, but if Some, check structure
         if let Some(c) = condition {
             assert!(!c.id.is_empty());
@@ -683,7 +788,32 @@ mod tests {
     fn test_get_modifiers_for_event() {
         let modifiers = get_modifiers_for_event(&MarketEvent::BullMarket);
         assert!(!modifiers.is_empty());
-        assert!(modifiers.iter().any(|m| m.stat_affected == "fundraising_success"));
+        assert!(modifiers.iter().any(|m| m.target == ModifierTarget::FundraisingSuccess));
+    }
+
+    #[test]
+    fn test_action_effectiveness_reads_modifier_keys() {
+        let conditions = vec![MarketCondition {
+            id: "TalentWar".to_string(),
+            name: "Talent War".to_string(),
+            description: "".to_string(),
+            duration_weeks: 5,
+            modifiers: get_modifiers_for_event(&MarketEvent::TalentWar),
+        }];
+
+        let hire = action_effectiveness(&Action::Hire { candidate_id: 1, offer: OfferTerms::AtAsk }, &conditions);
+
+        assert_eq!(hire.output, 0.7);
+        assert_eq!(hire.hiring_cost, 1.6);
+        assert_eq!(hire.fundraising_success, 1.0);
+    }
+
+    #[test]
+    fn test_modifier_target_keys_round_trip() {
+        let json = serde_json::to_value(ModifierTarget::Stat(Stat::WauGrowth)).unwrap();
+        assert_eq!(json, "wau_growth");
+        assert_eq!(ModifierTarget::from_key("hiring_cost"), Some(ModifierTarget::HiringCost));
+        assert!(serde_json::from_str::<ModifierTarget>("\"wau_grwoth\"").is_err());
     }
 
     #[test]
@@ -695,7 +825,7 @@ mod tests {
             duration_weeks: 5,
             modifiers: vec![],
         }];
-        let action = Action::Fundraise { target: 100000.0 };
+        let action = Action::Fundraise { target: 100000.0, instrument: Instrument::PricedRound };
         let modifier = get_action_effectiveness_modifier(&action, &conditions);
         assert_eq!(modifier, 1.5);
     }
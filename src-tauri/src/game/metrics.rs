@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use super::actions::AdChannel;
+use super::ledger::LedgerCategory;
+use super::state::GameState;
+
+/// Weeks of acquisition records kept
+const ACQUISITION_HISTORY_WEEKS: u32 = 52;
+/// Trailing window the metrics are computed over: one quarter
+pub const METRICS_WINDOW_WEEKS: u32 = 12;
+/// Founder time and tooling charged as acquisition cost per sales call
+pub const SALES_CALL_COST: f64 = 150.0;
+
+/// Where a customer came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AcquisitionChannel {
+    Ads(AdChannel),
+    FounderLedSales,
+    Content,
+}
+
+/// Spend and customers won through one channel in one week
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcquisitionRecord {
+    pub week: u32,
+    pub channel: AcquisitionChannel,
+    pub spend: f64,
+    pub customers: u32,
+    pub new_mrr: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChannelEconomics {
+    pub channel: AcquisitionChannel,
+    pub spend: f64,
+    pub customers: u32,
+    /// Spend per customer; None until the channel has won one
+    pub cac: Option<f64>,
+}
+
+/// The numbers investors ask about, over the trailing quarter
+///
+/// Each is None while there isn't enough data to compute it honestly.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnitEconomics {
+    pub channels: Vec<ChannelEconomics>,
+    pub blended_cac: Option<f64>,
+    /// Monthly revenue per paying customer
+    pub arpu: Option<f64>,
+    /// ARPU over monthly churn
+    pub ltv: Option<f64>,
+    pub ltv_to_cac: Option<f64>,
+    /// Months of a customer's revenue to earn back their CAC
+    pub cac_payback_months: Option<f64>,
+    /// Net burn per dollar of net new ARR
+    pub burn_multiple: Option<f64>,
+    /// Net new ARR per dollar of sales and marketing
+    pub magic_number: Option<f64>,
+    /// Annualized growth plus profit margin, in percent
+    pub rule_of_40: Option<f64>,
+}
+
+/// Log what a week's acquisition effort cost and brought in
+pub fn record_acquisition(state: &mut GameState, channel: AcquisitionChannel, spend: f64, customers: u32, new_mrr: f64) {
+    let week = state.week;
+    state.acquisition_log.push(AcquisitionRecord { week, channel, spend, customers, new_mrr });
+    let oldest = week.saturating_sub(ACQUISITION_HISTORY_WEEKS - 1);
+    state.acquisition_log.retain(|r| r.week >= oldest);
+}
+
+fn ratio(numerator: f64, denominator: f64) -> Option<f64> {
+    (denominator > 0.0).then(|| numerator / denominator)
+}
+
+/// Compute unit economics from the acquisition log, ledger and MRR bridge
+pub fn unit_economics(state: &GameState) -> UnitEconomics {
+    let from_week = state.week.saturating_sub(METRICS_WINDOW_WEEKS - 1);
+    let records: Vec<&AcquisitionRecord> = state.acquisition_log.iter().filter(|r| r.week >= from_week).collect();
+
+    let mut channels: Vec<ChannelEconomics> = Vec::new();
+    for record in &records {
+        match channels.iter_mut().find(|c| c.channel == record.channel) {
+            Some(channel) => {
+                channel.spend += record.spend;
+                channel.customers += record.customers;
+            }
+            None => channels.push(ChannelEconomics { channel: record.channel.clone(), spend: record.spend, customers: record.customers, cac: None }),
+        }
+    }
+    for channel in &mut channels {
+        channel.cac = ratio(channel.spend, channel.customers as f64);
+    }
+    let acquisition_spend: f64 = channels.iter().map(|c| c.spend).sum();
+    let customers_won: u32 = channels.iter().map(|c| c.customers).sum();
+    let blended_cac = ratio(acquisition_spend, customers_won as f64);
+
+    let paying = state.customers.iter().filter(|c| c.mrr_contribution > 0.0).count();
+    let arpu = ratio(state.mrr, paying as f64);
+    let ltv = arpu.and_then(|arpu| ratio(arpu, state.churn_rate / 100.0));
+    let ltv_to_cac = ltv.zip(blended_cac).and_then(|(ltv, cac)| ratio(ltv, cac));
+    let cac_payback_months = blended_cac.zip(arpu).and_then(|(cac, arpu)| ratio(cac, arpu));
+
+    // Bridges and the P&L over the same window
+    let bridges: Vec<_> = state.mrr_history.iter().filter(|b| b.week >= from_week).collect();
+    let starting_mrr = bridges.first().map_or(state.mrr, |b| b.starting_mrr);
+    let net_new_arr = bridges.iter().map(|b| b.net_new_mrr()).sum::<f64>() * 12.0;
+    let pnl = state.ledger.profit_and_loss(from_week, state.week);
+    let net_burn = (-pnl.net_income).max(0.0);
+    // Ad spend already shows up as marketing in the ledger; only the founder's sales time is extra
+    let marketing = pnl.operating_expenses.iter().filter(|t| t.category == LedgerCategory::Marketing).map(|t| t.amount).sum::<f64>();
+    let sales_effort: f64 = records.iter().filter(|r| r.channel == AcquisitionChannel::FounderLedSales).map(|r| r.spend).sum();
+
+    let burn_multiple = if net_new_arr > 0.0 { Some(net_burn / net_new_arr) } else { None };
+    let magic_number = ratio(net_new_arr, marketing + sales_effort);
+    let weeks = bridges.len() as f64;
+    let growth = ratio(starting_mrr + net_new_arr / 12.0, starting_mrr)
+        .filter(|_| weeks > 0.0)
+        .map(|multiple| (multiple.powf(52.0 / weeks) - 1.0) * 100.0);
+    let margin = ratio(pnl.net_income, pnl.revenue).map(|m| m * 100.0);
+    let rule_of_40 = growth.zip(margin).map(|(growth, margin)| growth + margin);
+
+    UnitEconomics {
+        channels,
+        blended_cac,
+        arpu,
+        ltv,
+        ltv_to_cac,
+        cac_payback_months,
+        burn_multiple,
+        magic_number,
+        rule_of_40,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::customers::{generate_customer_persona, CustomerSegment};
+    use crate::game::mrr_bridge::MrrBridge;
+    use crate::game::state::DifficultyMode;
+
+    #[test]
+    fn test_cac_ltv_and_payback() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 5);
+        let mut rng = state.next_rng();
+        for _ in 0..4 {
+            let mut customer = generate_customer_persona(CustomerSegment::SMB, 0, &state, &mut rng);
+            customer.mrr_contribution = 500.0;
+            state.add_customer(customer);
+        }
+        state.mrr = 2_000.0;
+        state.churn_rate = 5.0;
+        record_acquisition(&mut state, AcquisitionChannel::Ads(AdChannel::Google), 3_000.0, 2, 0.0);
+        record_acquisition(&mut state, AcquisitionChannel::FounderLedSales, 1_000.0, 2, 1_000.0);
+
+        let metrics = unit_economics(&state);
+
+        assert_eq!(metrics.channels[0].cac, Some(1_500.0));
+        assert_eq!(metrics.blended_cac, Some(1_000.0));
+        assert_eq!(metrics.arpu, Some(500.0));
+        assert_eq!(metrics.ltv, Some(10_000.0));
+        assert_eq!(metrics.ltv_to_cac, Some(10.0));
+        assert_eq!(metrics.cac_payback_months, Some(2.0));
+    }
+
+    #[test]
+    fn test_burn_multiple_and_magic_number() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 5);
+        state.week = 12;
+        state.mrr_history = (1..=12).map(|week| MrrBridge { new_business: 100.0, ..MrrBridge::opening(week, 1_000.0 + 100.0 * (week - 1) as f64) }).collect();
+        state.ledger.record(12, LedgerCategory::Revenue, 10_000.0, "Revenue");
+        state.ledger.record(12, LedgerCategory::Marketing, -4_800.0, "Ads");
+        state.ledger.record(12, LedgerCategory::Payroll, -19_600.0, "Payroll");
+
+        let metrics = unit_economics(&state);
+
+        // 1,200 of new MRR is 14,400 of new ARR against 14,400 of net burn
+        assert_eq!(metrics.burn_multiple, Some(1.0));
+        assert_eq!(metrics.magic_number, Some(3.0));
+        assert!(metrics.rule_of_40.is_some());
+    }
+
+    #[test]
+    fn test_no_data_means_no_metrics() {
+        let metrics = unit_economics(&GameState::with_seed(DifficultyMode::VCTrack, 5));
+
+        assert!(metrics.channels.is_empty());
+        assert_eq!(metrics.blended_cac, None);
+        assert_eq!(metrics.ltv, None);
+        assert_eq!(metrics.burn_multiple, None);
+    }
+}
@@ -1,7 +1,11 @@
 // Game module - Founder's Dilemma simulation engine
 
+// Seeded random number generation
+pub mod rng;
 // Core game state and simulation
 pub mod state;
+// Typed stats and the shared effect applier
+pub mod effects;
 // Action definitions and resolution
 pub mod actions;
 // Event system and dilemmas
@@ -18,6 +22,38 @@ pub mod compounding;
 pub mod warnings;
 // Enhanced event system with more variety
 pub mod events_enhanced;
+// Machine-evaluable event triggers
+pub mod triggers;
+// Event content loaded from bundled data files
+pub mod event_library;
+// Follow-up events and remembered story branches
+pub mod chains;
+// Multi-week actions that hold focus until they pay off
+pub mod projects;
+// Shareholders, priced rounds and valuation
+pub mod cap_table;
+// Investor pipeline behind every raise
+pub mod investors;
+// Loans, venture debt and revenue-based financing
+pub mod debt;
+// Expense lines, transaction ledger and P&L
+pub mod ledger;
+// Weekly MRR bridge and net revenue retention
+pub mod mrr_bridge;
+// CAC, LTV, payback, burn multiple and other unit economics
+pub mod metrics;
+// Signup cohorts and retention curves
+pub mod cohorts;
+// Visitor to paying customer funnel
+pub mod funnel;
+// Plans, list prices and how price moves conversion and churn
+pub mod pricing;
+// Annual prepaid contracts, deferred revenue and renewals
+pub mod billing;
+// Employee roster behind payroll, velocity and sales capacity
+pub mod team;
+// Weekly candidate pool, interviews, offers and counter-offers
+pub mod hiring;
 // Market condition modifiers
 pub mod market_conditions;
 // Progression and unlock systems
@@ -28,18 +64,48 @@ pub mod synergies;
 pub mod customers;
 // Competitor tracking system
 pub mod competitors;
+// Replay logs and checkpoint verification
+pub mod replay;
+// Weekly turn pipeline
+pub mod engine;
+// Backend-owned game sessions
+pub mod session;
+// Versioned save files and migrations
+pub mod save;
 
 // Re-export main types
+pub use rng::GameRng;
 pub use state::{GameState, DifficultyMode, EscapeVelocityProgress, WeekSnapshot};
+pub use effects::{Stat, Effect, EffectOp, StatEffect, ScheduledEffect, apply_effect, apply_effects, resolve_scheduled_effects};
 pub use actions::{Action, ActionResult};
+pub use projects::{Project, CompletedProject, committed_focus, cancel_project};
+pub use cap_table::{CapTable, Shareholder, ShareClass, PricedRound, Instrument, Convertible, Conversion, Financing, close_financing, pre_money_valuation};
+pub use investors::{Investor, InvestorPipeline, PipelineStage, Thesis, TermSheet, RoundDecision, apply_round_decision};
+pub use debt::{DebtKind, Loan, Covenant, DebtDecision, loan_offer, monthly_debt_service, apply_debt_decision};
+pub use ledger::{LedgerCategory, ExpenseLine, Transaction, Ledger, ProfitAndLoss, CategoryTotal};
+pub use mrr_bridge::{MrrBridge, MrrMovement};
+pub use metrics::{AcquisitionChannel, AcquisitionRecord, ChannelEconomics, UnitEconomics, unit_economics};
+pub use cohorts::{Cohort, CohortRow, cohort_table};
+pub use funnel::{ConversionRates, FunnelWeek, conversion_rates};
+pub use pricing::{Pricing, PricingPlan, PlanTier, PriceMetric, PriceChange};
+pub use billing::{BillingCycle, Contract, RenewalDecision, deferred_revenue};
+pub use team::{Employee, Role, Seniority};
+pub use hiring::{Candidate, CandidateStage, OfferTerms, OfferOutcome};
 pub use events::{GameEvent, EventType, Dilemma};
 pub use victory::{VictoryCondition, DefeatCondition, check_victory, check_defeat};
 pub use insights::{WeeklyInsight, InsightCategory, InsightSeverity, generate_weekly_insights};
-pub use compounding::{CompoundingBonus, CompoundingEffect, StatBonus, check_compounding_effects, apply_compounding_bonuses};
+pub use compounding::{CompoundingBonus, CompoundingEffect, check_compounding_effects, apply_compounding_bonuses};
 pub use warnings::{FailureWarning, WarningSign, WarningSeverity, check_failure_warnings};
 pub use events_enhanced::{GameEvent as EnhancedGameEvent, EnhancedEventType, EventChoice, EventEffect, check_for_events, apply_event_choice};
-pub use synergies::{ActionSynergy, SynergyBonus, SpecializationPath, check_action_synergies, detect_specialization_path};
-pub use market_conditions::{MarketCondition, MarketModifier, MarketEvent, generate_market_condition, apply_market_modifiers, get_action_effectiveness_modifier, get_active_conditions, update_market_conditions};
+pub use triggers::{Condition, Metric, TriggerContext};
+pub use event_library::{EventLibrary, EventTemplate};
+pub use chains::{FollowUp, ScheduledEvent, schedule_follow_up, record_branch};
+pub use synergies::{ActionSynergy, SpecializationPath, check_action_synergies, detect_specialization_path};
+pub use market_conditions::{MarketCondition, MarketModifier, ModifierTarget, MarketEvent, Effectiveness, generate_market_condition, apply_market_modifiers, action_effectiveness, get_action_effectiveness_modifier, get_active_conditions, update_market_conditions};
 pub use progression::{UnlockableAction, UnlockCondition, MilestoneEvent, check_unlocks, get_available_actions, check_milestone_events};
 pub use customers::{Customer, CustomerSegment, CustomerLifecycle, CustomerFeedback, FeedbackSentiment, generate_customer_persona, generate_customer_feedback, get_champions, get_at_risk_customers};
 pub use competitors::{Competitor, FundingStage, PricingStrategy, CompetitorAction, CompetitorActionType, generate_competitors, generate_competitor_action, get_most_threatening_competitor, calculate_market_share};
+pub use replay::{ReplayLog, ReplayWeek, ReplayChoice, ReplayReport, checkpoint_hash};
+pub use engine::{Engine, TurnResult};
+pub use session::{GameSession, SessionStore};
+pub use save::{SaveFile, SaveSummary, CURRENT_SAVE_VERSION, encode_save, decode_save, encode_session, decode_session};
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use super::state::GameState;
+
+/// Weeks of closed bridges kept, matching the weekly history
+const BRIDGE_HISTORY_WEEKS: usize = 52;
+
+/// Why a dollar of MRR appeared or disappeared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MrrMovement {
+    /// Revenue from customers who never paid before
+    NewBusiness,
+    /// Existing customers paying more: upsells and price increases
+    Expansion,
+    /// Existing customers paying less
+    Contraction,
+    /// Customers who left
+    Churned,
+    /// Former customers who came back
+    Reactivated,
+}
+
+/// How MRR got from the start of a week to its end
+///
+/// Every amount is positive; contraction and churn are subtracted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MrrBridge {
+    pub week: u32,
+    pub starting_mrr: f64,
+    pub new_business: f64,
+    pub expansion: f64,
+    pub contraction: f64,
+    pub churned: f64,
+    pub reactivated: f64,
+    pub ending_mrr: f64,
+}
+
+impl MrrBridge {
+    /// An empty bridge for a week starting at `mrr`
+    pub fn opening(week: u32, mrr: f64) -> Self {
+        Self {
+            week,
+            starting_mrr: mrr,
+            new_business: 0.0,
+            expansion: 0.0,
+            contraction: 0.0,
+            churned: 0.0,
+            reactivated: 0.0,
+            ending_mrr: mrr,
+        }
+    }
+
+    pub fn net_new_mrr(&self) -> f64 {
+        self.new_business + self.expansion + self.reactivated - self.contraction - self.churned
+    }
+
+    /// MRR kept from the customers paying at the start of the week, expansion included
+    fn retained(&self) -> f64 {
+        self.expansion - self.contraction - self.churned
+    }
+}
+
+/// Attribute a change in MRR to the current week's bridge
+pub fn record_mrr(state: &mut GameState, movement: MrrMovement, amount: f64) {
+    let bridge = &mut state.mrr_bridge;
+    let bucket = match movement {
+        MrrMovement::NewBusiness => &mut bridge.new_business,
+        MrrMovement::Expansion => &mut bridge.expansion,
+        MrrMovement::Contraction => &mut bridge.contraction,
+        MrrMovement::Churned => &mut bridge.churned,
+        MrrMovement::Reactivated => &mut bridge.reactivated,
+    };
+    *bucket += amount.abs();
+}
+
+/// Attribute a change made by an effect: up is expansion, down is contraction
+pub fn record_mrr_change(state: &mut GameState, delta: f64) {
+    let movement = if delta >= 0.0 { MrrMovement::Expansion } else { MrrMovement::Contraction };
+    record_mrr(state, movement, delta);
+}
+
+/// Close the current week's bridge at today's MRR and open the next one
+pub fn close_mrr_week(state: &mut GameState) -> MrrBridge {
+    let next = MrrBridge::opening(state.week, state.mrr);
+    let mut closed = std::mem::replace(&mut state.mrr_bridge, next);
+    closed.ending_mrr = state.mrr;
+
+    state.mrr_history.push(closed.clone());
+    if state.mrr_history.len() > BRIDGE_HISTORY_WEEKS {
+        state.mrr_history.remove(0);
+    }
+    closed
+}
+
+/// Net revenue retention over the closed weeks on record, up to a year
+///
+/// Starting MRR plus expansion, less contraction and churn, over starting MRR.
+/// None until there was revenue at the start of the window.
+pub fn net_revenue_retention(state: &GameState) -> Option<f64> {
+    let first = state.mrr_history.iter().find(|b| b.starting_mrr > 0.0)?;
+    let retained: f64 = state.mrr_history.iter().filter(|b| b.week >= first.week).map(MrrBridge::retained).sum();
+    Some((first.starting_mrr + retained) / first.starting_mrr * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::effects::{apply_effect, Effect, Stat};
+    use crate::game::state::DifficultyMode;
+
+    #[test]
+    fn test_bridge_explains_the_change() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 2);
+        state.mrr = 1_000.0;
+        state.mrr_bridge = MrrBridge::opening(0, 1_000.0);
+
+        state.mrr += 500.0;
+        record_mrr(&mut state, MrrMovement::NewBusiness, 500.0);
+        apply_effect(&mut state, &Effect::add(Stat::Mrr, 100.0));
+        apply_effect(&mut state, &Effect::add(Stat::Mrr, -40.0));
+        state.mrr -= 60.0;
+        record_mrr(&mut state, MrrMovement::Churned, 60.0);
+        state.week = 1;
+
+        let bridge = close_mrr_week(&mut state);
+
+        assert_eq!(bridge.expansion, 100.0);
+        assert_eq!(bridge.contraction, 40.0);
+        assert_eq!(bridge.starting_mrr + bridge.net_new_mrr(), bridge.ending_mrr);
+        assert_eq!(state.mrr_bridge.week, 1);
+        assert_eq!(state.mrr_bridge.starting_mrr, 1_500.0);
+    }
+
+    #[test]
+    fn test_net_revenue_retention_ignores_new_business() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 2);
+        assert_eq!(net_revenue_retention(&state), None);
+
+        state.mrr_history = vec![
+            MrrBridge { new_business: 5_000.0, expansion: 150.0, churned: 50.0, ..MrrBridge::opening(1, 1_000.0) },
+            MrrBridge { expansion: 20.0, contraction: 20.0, churned: 100.0, ..MrrBridge::opening(2, 6_100.0) },
+        ];
+
+        assert_eq!(net_revenue_retention(&state), Some(100.0));
+    }
+}
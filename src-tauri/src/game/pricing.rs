@@ -0,0 +1,266 @@
+use serde::{Deserialize, Serialize};
+use super::cohorts::reprice_segments;
+use super::customers::CustomerSegment;
+use super::mrr_bridge::record_mrr_change;
+use super::state::GameState;
+
+/// Going monthly rate for a Pro seat in this market
+pub const MARKET_PRO_PRICE: f64 = 49.0;
+/// Going monthly rate for an Enterprise usage unit
+pub const MARKET_ENTERPRISE_PRICE: f64 = 25.0;
+/// Share of the going rate an undercutting competitor charges during a pricing war
+const UNDERCUT_PRICE_SHARE: f64 = 0.7;
+/// How far one price change moves a list price
+const PRICE_STEP: f64 = 0.2;
+/// Lowest and highest list price, as multiples of the going rate
+const PRICE_FLOOR: f64 = 0.25;
+const PRICE_CEILING: f64 = 5.0;
+/// How strongly paid conversion responds to a price below and above the fair price
+const DISCOUNT_ELASTICITY: f64 = 0.5;
+const PREMIUM_ELASTICITY: f64 = 1.5;
+/// How strongly churn rises with a plan's premium over the fair price
+const CHURN_ELASTICITY: f64 = 0.5;
+/// Id prefix of the market condition an undercutting competitor starts
+const PRICING_WAR_PREFIX: &str = "CompetitorPricingWar_";
+
+/// Packaging tier on the pricing page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlanTier {
+    Free,
+    Pro,
+    Enterprise,
+}
+
+impl PlanTier {
+    /// The plan a customer segment buys; cohorts track revenue by segment
+    pub fn for_segment(segment: &CustomerSegment) -> Self {
+        match segment {
+            CustomerSegment::Enterprise => PlanTier::Enterprise,
+            CustomerSegment::SMB | CustomerSegment::SelfServe => PlanTier::Pro,
+        }
+    }
+
+    fn market_price(&self) -> f64 {
+        match self {
+            PlanTier::Free => 0.0,
+            PlanTier::Pro => MARKET_PRO_PRICE,
+            PlanTier::Enterprise => MARKET_ENTERPRISE_PRICE,
+        }
+    }
+}
+
+/// What a plan charges for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceMetric {
+    Seat,
+    Usage,
+}
+
+impl PriceMetric {
+    pub fn unit(&self) -> &'static str {
+        match self {
+            PriceMetric::Seat => "seat",
+            PriceMetric::Usage => "usage unit",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PricingPlan {
+    pub tier: PlanTier,
+    pub metric: PriceMetric,
+    /// Monthly price per seat or usage unit
+    pub list_price: f64,
+    /// Discount for paying a year upfront, 0-1
+    pub annual_discount: f64,
+}
+
+impl PricingPlan {
+    /// Monthly bill for `units` seats or usage units
+    pub fn monthly_price(&self, units: f64) -> f64 {
+        self.list_price * units
+    }
+
+    /// Yearly bill for `units` when paid upfront
+    pub fn annual_price(&self, units: f64) -> f64 {
+        self.monthly_price(units) * 12.0 * (1.0 - self.annual_discount)
+    }
+}
+
+/// The player's pricing page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pricing {
+    /// Activated users who have not converted use the free plan
+    pub free: PricingPlan,
+    pub pro: PricingPlan,
+    pub enterprise: PricingPlan,
+}
+
+impl Default for Pricing {
+    fn default() -> Self {
+        Self {
+            free: PricingPlan { tier: PlanTier::Free, metric: PriceMetric::Seat, list_price: 0.0, annual_discount: 0.0 },
+            pro: PricingPlan { tier: PlanTier::Pro, metric: PriceMetric::Seat, list_price: MARKET_PRO_PRICE, annual_discount: 0.15 },
+            enterprise: PricingPlan { tier: PlanTier::Enterprise, metric: PriceMetric::Usage, list_price: MARKET_ENTERPRISE_PRICE, annual_discount: 0.1 },
+        }
+    }
+}
+
+impl Pricing {
+    pub fn plan(&self, tier: PlanTier) -> &PricingPlan {
+        match tier {
+            PlanTier::Free => &self.free,
+            PlanTier::Pro => &self.pro,
+            PlanTier::Enterprise => &self.enterprise,
+        }
+    }
+
+    fn plan_mut(&mut self, tier: PlanTier) -> &mut PricingPlan {
+        match tier {
+            PlanTier::Free => &mut self.free,
+            PlanTier::Pro => &mut self.pro,
+            PlanTier::Enterprise => &mut self.enterprise,
+        }
+    }
+}
+
+/// Direction of a one-step price change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceChange {
+    Raise,
+    Cut,
+}
+
+/// Whether an undercutting competitor is in a pricing war with the player
+pub fn pricing_war(state: &GameState) -> bool {
+    state.active_market_conditions.iter().any(|c| c.id.starts_with(PRICING_WAR_PREFIX))
+}
+
+/// The price customers consider fair for a tier
+///
+/// It starts from the going rate, or the undercutter's price during a pricing
+/// war, and a strong reputation lets the player charge a premium over it.
+pub fn fair_price(state: &GameState, tier: PlanTier) -> f64 {
+    let going_rate = tier.market_price() * if pricing_war(state) { UNDERCUT_PRICE_SHARE } else { 1.0 };
+    going_rate * (0.5 + state.reputation / 100.0)
+}
+
+/// Multiplier on paid conversion for a tier at its current list price
+///
+/// Buyers punish a premium over the fair price harder than they reward a discount.
+pub fn conversion_factor(state: &GameState, tier: PlanTier) -> f64 {
+    let price = state.pricing.plan(tier).list_price;
+    if price <= 0.0 {
+        return 1.0;
+    }
+    let ratio = fair_price(state, tier) / price;
+    let elasticity = if ratio >= 1.0 { DISCOUNT_ELASTICITY } else { PREMIUM_ELASTICITY };
+    ratio.powf(elasticity).min(2.0)
+}
+
+/// Multiplier on a segment's churn from what its plan costs against the fair price
+pub fn churn_factor(state: &GameState, segment: &CustomerSegment) -> f64 {
+    let tier = PlanTier::for_segment(segment);
+    let fair = fair_price(state, tier);
+    if fair <= 0.0 {
+        return 1.0;
+    }
+    (state.pricing.plan(tier).list_price / fair).powf(CHURN_ELASTICITY).clamp(0.5, 3.0)
+}
+
+/// Set a plan's list price and move the monthly customers already on it to the new price
+///
+/// Returns the change in MRR; a raise shows up as expansion and a cut as contraction.
+pub fn set_list_price(state: &mut GameState, tier: PlanTier, price: f64) -> f64 {
+    if tier == PlanTier::Free {
+        return 0.0;
+    }
+    let going_rate = tier.market_price();
+    let plan = state.pricing.plan_mut(tier);
+    let price = price.clamp(going_rate * PRICE_FLOOR, going_rate * PRICE_CEILING);
+    let factor = price / plan.list_price;
+    plan.list_price = price;
+
+    // Customers on annual contracts keep their price until renewal
+    let on_plan = |segment: &CustomerSegment| PlanTier::for_segment(segment) == tier;
+    let contracted: Vec<String> = state.contracts.iter().map(|c| c.customer_id.clone()).collect();
+    for customer in state.customers.iter_mut().filter(|c| on_plan(&c.segment) && !contracted.contains(&c.id)) {
+        customer.mrr_contribution *= factor;
+    }
+    let delta = reprice_segments(state, on_plan, factor);
+    record_mrr_change(state, delta);
+    delta
+}
+
+/// Move a plan's list price one step up or down
+pub fn change_price(state: &mut GameState, tier: PlanTier, change: PriceChange) -> f64 {
+    let step = match change {
+        PriceChange::Raise => 1.0 + PRICE_STEP,
+        PriceChange::Cut => 1.0 - PRICE_STEP,
+    };
+    let price = state.pricing.plan(tier).list_price * step;
+    set_list_price(state, tier, price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::cohorts::add_signups;
+    use crate::game::market_conditions::generate_pricing_war_condition;
+    use crate::game::state::DifficultyMode;
+
+    fn new_game() -> GameState {
+        GameState::with_seed(DifficultyMode::IndieBootstrap, 9)
+    }
+
+    #[test]
+    fn test_raise_reprices_customers_on_the_plan() {
+        let mut state = new_game();
+        add_signups(&mut state, CustomerSegment::SMB, 1_000.0);
+        add_signups(&mut state, CustomerSegment::Enterprise, 2_000.0);
+
+        let delta = change_price(&mut state, PlanTier::Pro, PriceChange::Raise);
+
+        assert!((delta - 200.0).abs() < 1e-6);
+        assert!((state.mrr - 3_200.0).abs() < 1e-6);
+        assert!((state.mrr_bridge.expansion - 200.0).abs() < 1e-6);
+        assert!((state.pricing.pro.list_price - MARKET_PRO_PRICE * 1.2).abs() < 1e-6);
+        assert_eq!(state.pricing.enterprise.list_price, MARKET_ENTERPRISE_PRICE);
+    }
+
+    #[test]
+    fn test_higher_price_converts_less_and_churns_more() {
+        let mut state = new_game();
+        let base_conversion = conversion_factor(&state, PlanTier::Pro);
+        let base_churn = churn_factor(&state, &CustomerSegment::SelfServe);
+
+        change_price(&mut state, PlanTier::Pro, PriceChange::Raise);
+
+        assert!(conversion_factor(&state, PlanTier::Pro) < base_conversion);
+        assert!(churn_factor(&state, &CustomerSegment::SelfServe) > base_churn);
+        assert_eq!(churn_factor(&state, &CustomerSegment::Enterprise), 1.0);
+    }
+
+    #[test]
+    fn test_pricing_war_lowers_the_fair_price() {
+        let mut state = new_game();
+        let peace = fair_price(&state, PlanTier::Pro);
+        let undercutter = state.competitors[0].clone();
+
+        state.active_market_conditions.push(generate_pricing_war_condition(&undercutter));
+
+        assert!(fair_price(&state, PlanTier::Pro) < peace);
+        assert!(churn_factor(&state, &CustomerSegment::SelfServe) > 1.0);
+    }
+
+    #[test]
+    fn test_free_plan_and_price_limits() {
+        let mut state = new_game();
+        assert_eq!(set_list_price(&mut state, PlanTier::Free, 10.0), 0.0);
+        assert_eq!(state.pricing.free.list_price, 0.0);
+
+        set_list_price(&mut state, PlanTier::Pro, 0.0);
+        assert_eq!(state.pricing.pro.list_price, MARKET_PRO_PRICE * PRICE_FLOOR);
+        assert!((state.pricing.pro.annual_price(1.0) - state.pricing.pro.list_price * 12.0 * 0.85).abs() < 1e-6);
+    }
+}
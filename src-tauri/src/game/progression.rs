@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use super::state::{GameState, DifficultyMode};
 use super::actions::Action;
+use super::cap_table::Instrument;
+use super::debt::DebtKind;
+use super::pricing::{PlanTier, PriceChange};
+use super::hiring::{CandidateStage, OfferTerms};
 
 /// Represents an unlockable action with its condition and description
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,8 +64,10 @@ pub fn action_unlock_key(action: &Action) -> String {
         Action::IncidentResponse => "IncidentResponse".to_string(),
         Action::ShipFeature { .. } => "ShipFeature".to_string(),
         Action::FounderLedSales { .. } => "FounderLedSales".to_string(),
-        Action::Hire => "Hire".to_string(),
+        Action::Interview { .. } | Action::Hire { .. } => "Hire".to_string(),
         Action::Fundraise { .. } => "Fundraise".to_string(),
+        Action::TakeLoan { .. } => "TakeLoan".to_string(),
+        Action::ChangePrice { .. } => "ChangePrice".to_string(),
         Action::TakeBreak => "TakeBreak".to_string(),
         // default fallback for other variants
         other => format!("{:?}", other),
@@ -78,12 +84,14 @@ pub fn action_from_unlock_key(key: &str) -> Option<Action> {
         "DevRel" => Some(Action::DevRel { event_type: super::actions::DevRelEvent::Conference }),
         "PaidAds" => Some(Action::PaidAds { budget: 20_000.0, channel: super::actions::AdChannel::Google }),
         "ProcessImprovement" => Some(Action::ProcessImprovement),
-        "Fire" => Some(Action::Fire { reason: super::actions::FiringReason::Performance }),
+        "Fire" => Some(Action::Fire { employee_id: 0, reason: super::actions::FiringReason::Performance }),
         "IncidentResponse" => Some(Action::IncidentResponse),
         "ShipFeature" => Some(Action::ShipFeature { quality: super::actions::Quality::Quick }),
         "FounderLedSales" => Some(Action::FounderLedSales { call_count: 3 }),
-        "Hire" => Some(Action::Hire),
-        "Fundraise" => Some(Action::Fundraise { target: 250_000.0 }),
+        "Hire" => Some(Action::Hire { candidate_id: 0, offer: OfferTerms::AtAsk }),
+        "Fundraise" => Some(Action::Fundraise { target: 250_000.0, instrument: Instrument::PricedRound }),
+        "TakeLoan" => Some(Action::TakeLoan { kind: DebtKind::RevenueBased }),
+        "ChangePrice" => Some(Action::ChangePrice { tier: PlanTier::Pro, change: PriceChange::Raise }),
         "TakeBreak" => Some(Action::TakeBreak),
         _ => None,
     }
@@ -103,8 +111,10 @@ pub fn check_unlocks(state: &GameState) -> Vec<Action> {
         (Action::DevRel { event_type: super::actions::DevRelEvent::Conference }, UnlockCondition::ReachWeek(13), "Unlocks developer relations events".to_string()),
         (Action::PaidAds { budget: 5000.0, channel: super::actions::AdChannel::Social }, UnlockCondition::ReachWeek(13), "Unlocks paid advertising".to_string()),
         (Action::ProcessImprovement, UnlockCondition::ReachWeek(13), "Unlocks process improvements".to_string()),
-        (Action::Fire { reason: super::actions::FiringReason::Performance }, UnlockCondition::AchieveMetric("team_size".to_string(), 1.0), "Unlocks firing after hiring your first employee".to_string()),
+        (Action::Fire { employee_id: 0, reason: super::actions::FiringReason::Performance }, UnlockCondition::AchieveMetric("team_size".to_string(), 1.0), "Unlocks firing after hiring your first employee".to_string()),
         (Action::IncidentResponse, UnlockCondition::AchieveMetric("incident_count".to_string(), 1.0), "Unlocks incident response after first crisis".to_string()),
+        (Action::TakeLoan { kind: DebtKind::RevenueBased }, UnlockCondition::AchieveMetric("mrr".to_string(), 2_500.0), "Unlocks debt financing once you have revenue to borrow against".to_string()),
+        (Action::ChangePrice { tier: PlanTier::Pro, change: PriceChange::Raise }, UnlockCondition::AchieveMetric("mrr".to_string(), 1.0), "Unlocks repricing once someone is paying".to_string()),
     ];
 
     for (action, condition, _desc) in unlockables {
@@ -116,7 +126,7 @@ pub fn check_unlocks(state: &GameState) -> Vec<Action> {
                     "reputation" => state.reputation >= *value,
                     "wau" => state.wau as f64 >= *value,
                     "mrr" => state.mrr >= *value,
-                    "team_size" => state.team_size as f64 >= *value,
+                    "team_size" => state.team_size() as f64 >= *value,
                     "incident_count" => state.incident_count as f64 >= *value,
                     _ => false,
                 },
@@ -151,12 +161,23 @@ pub fn get_available_actions(state: &GameState) -> Vec<Action> {
         Action::ShipFeature { quality: Quality::Polish },
         Action::FounderLedSales { call_count: 3 },
         Action::FounderLedSales { call_count: 5 },
-        Action::Hire,
-        Action::Fundraise { target: 250_000.0 },
-        Action::Fundraise { target: 500_000.0 },
+        Action::Fundraise { target: 250_000.0, instrument: Instrument::PricedRound },
+        Action::Fundraise { target: 500_000.0, instrument: Instrument::PricedRound },
+        Action::Fundraise { target: 150_000.0, instrument: Instrument::Safe },
+        Action::Fundraise { target: 150_000.0, instrument: Instrument::ConvertibleNote },
         Action::TakeBreak,
     ];
 
+    // Anyone in the candidate pool can be interviewed, and anyone interviewed can get an offer
+    for candidate in &state.candidates {
+        match candidate.stage {
+            CandidateStage::Sourced => available.push(Action::Interview { candidate_id: candidate.id }),
+            CandidateStage::Interviewed => {
+                available.extend(OfferTerms::ALL.map(|offer| Action::Hire { candidate_id: candidate.id, offer }))
+            }
+        }
+    }
+
     let mut push_unique = |action: Action| {
         if !available.iter().any(|existing| existing == &action) {
             available.push(action);
@@ -193,11 +214,25 @@ pub fn get_available_actions(state: &GameState) -> Vec<Action> {
                 push_unique(Action::ProcessImprovement);
             }
             "Fire" => {
-                push_unique(Action::Fire { reason: FiringReason::Performance });
+                // Anyone but the founder can be let go
+                for employee in state.team.iter().filter(|e| !e.is_founder()) {
+                    push_unique(Action::Fire { employee_id: employee.id, reason: FiringReason::Performance });
+                }
             }
             "IncidentResponse" => {
                 push_unique(Action::IncidentResponse);
             }
+            "TakeLoan" => {
+                push_unique(Action::TakeLoan { kind: DebtKind::RevenueBased });
+                push_unique(Action::TakeLoan { kind: DebtKind::BankLoan });
+                push_unique(Action::TakeLoan { kind: DebtKind::VentureDebt });
+            }
+            "ChangePrice" => {
+                for tier in [PlanTier::Pro, PlanTier::Enterprise] {
+                    push_unique(Action::ChangePrice { tier, change: PriceChange::Raise });
+                    push_unique(Action::ChangePrice { tier, change: PriceChange::Cut });
+                }
+            }
             "Fundraise" => {
                 // Already seeded with 250k/500k, skip
             }
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use std::mem::discriminant;
+use super::actions::{resolve_action, Action, ActionResult};
+use super::market_conditions::{action_effectiveness, MarketCondition};
+use super::rng::GameRng;
+use super::state::GameState;
+
+/// A multi-week action that holds its focus every week until it pays off
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    pub id: u32,
+    pub action: Action,
+    pub started_week: u32,
+    pub weeks_total: u32,
+    pub weeks_done: u32,
+}
+
+impl Project {
+    /// Focus the project takes every week it runs
+    pub fn focus_per_week(&self) -> u8 {
+        self.action.focus_cost()
+    }
+
+    /// Share of the work done, 0.0 to 1.0
+    pub fn progress(&self) -> f64 {
+        self.weeks_done as f64 / self.weeks_total.max(1) as f64
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.weeks_done >= self.weeks_total
+    }
+}
+
+/// A project that finished this turn and the payoff it produced
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletedProject {
+    pub project: Project,
+    pub result: ActionResult,
+}
+
+/// Focus already promised to running projects
+pub fn committed_focus(state: &GameState) -> u8 {
+    state.projects.iter().map(Project::focus_per_week).sum()
+}
+
+/// Whether a project of the same kind of action is already running
+pub fn is_in_progress(state: &GameState, action: &Action) -> bool {
+    state.projects.iter().any(|p| discriminant(&p.action) == discriminant(action))
+}
+
+/// Start a project for a multi-week action and return its id
+pub fn start_project(state: &mut GameState, action: &Action) -> u32 {
+    state.next_project_id += 1;
+    let id = state.next_project_id;
+    state.projects.push(Project {
+        id,
+        action: action.clone(),
+        started_week: state.week,
+        weeks_total: action.duration_weeks(),
+        weeks_done: 0,
+    });
+    id
+}
+
+/// Put a week of work into every project and resolve the ones that finish
+///
+/// The payoff uses the market conditions of the week the project completes.
+pub fn advance_projects(state: &mut GameState, market_conditions: &[MarketCondition], rng: &mut GameRng) -> Vec<CompletedProject> {
+    for project in &mut state.projects {
+        project.weeks_done += 1;
+    }
+    let (finished, running): (Vec<_>, Vec<_>) = state.projects.drain(..).partition(Project::is_finished);
+    state.projects = running;
+
+    finished
+        .into_iter()
+        .map(|project| {
+            let effectiveness = action_effectiveness(&project.action, market_conditions);
+            let result = resolve_action(state, &project.action, &effectiveness, rng);
+            CompletedProject { project, result }
+        })
+        .collect()
+}
+
+/// Abandon a project; the work already put in is lost and its focus is free from next turn
+pub fn cancel_project(state: &mut GameState, project_id: u32) -> Result<Project, String> {
+    let position = state
+        .projects
+        .iter()
+        .position(|p| p.id == project_id)
+        .ok_or_else(|| format!("No running project {}", project_id))?;
+    let project = state.projects.remove(position);
+    if matches!(project.action, Action::Fundraise { .. }) {
+        // Walking away from a raise ends every conversation with it
+        state.investor_pipeline = None;
+    }
+    Ok(project)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::RefactorDepth;
+    use crate::game::cap_table::Instrument;
+    use crate::game::state::DifficultyMode;
+
+    #[test]
+    fn test_project_pays_off_when_finished() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 2);
+        state.tech_debt = 60.0;
+        let mut rng = state.next_rng();
+        let action = Action::RefactorCode { depth: RefactorDepth::Deep };
+        start_project(&mut state, &action);
+
+        for _ in 1..action.duration_weeks() {
+            assert!(advance_projects(&mut state, &[], &mut rng).is_empty());
+            assert_eq!(state.tech_debt, 60.0);
+        }
+        let completed = advance_projects(&mut state, &[], &mut rng);
+
+        assert_eq!(completed.len(), 1);
+        assert!(state.projects.is_empty());
+        assert!(state.tech_debt < 60.0);
+    }
+
+    #[test]
+    fn test_cancel_frees_focus_without_payoff() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 2);
+        let bank = state.bank;
+        let id = start_project(&mut state, &Action::Fundraise { target: 250_000.0, instrument: Instrument::PricedRound });
+        assert_eq!(committed_focus(&state), 2);
+
+        let cancelled = cancel_project(&mut state, id).unwrap();
+
+        assert_eq!(cancelled.id, id);
+        assert_eq!(committed_focus(&state), 0);
+        assert_eq!(state.bank, bank);
+        assert!(cancel_project(&mut state, id).is_err());
+    }
+}
@@ -0,0 +1,347 @@
+use serde::{Deserialize, Serialize};
+use super::actions::Action;
+use super::chains::record_branch;
+use super::engine::Engine;
+use super::events_enhanced::{apply_event_choice, EnhancedEventType, GameEvent};
+use super::projects::{cancel_project, Project};
+use super::state::{DifficultyMode, GameState};
+
+/// Everything needed to rebuild a game: seed, difficulty and every player input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayLog {
+    pub seed: u64,
+    pub difficulty: DifficultyMode,
+    pub weeks: Vec<ReplayWeek>,
+    /// Set on games saved before replays were recorded; their log starts mid-game
+    /// and cannot rebuild them
+    #[serde(default)]
+    pub partial: bool,
+}
+
+/// One turn of player input plus the state hash it produced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayWeek {
+    pub week: u32,
+    pub actions: Vec<Action>,
+    pub choices: Vec<ReplayChoice>,
+    /// Projects abandoned between this turn and the next
+    #[serde(default)]
+    pub cancelled_projects: Vec<u32>,
+    pub checkpoint: u64,
+}
+
+/// A dilemma choice made after the turn's events were generated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayChoice {
+    pub event_id: String,
+    pub choice_index: usize,
+}
+
+/// Result of re-simulating a replay log
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayReport {
+    pub final_state: GameState,
+    pub weeks_replayed: usize,
+    pub diverged_at_week: Option<u32>,
+}
+
+impl ReplayLog {
+    pub fn new(seed: u64, difficulty: DifficultyMode) -> Self {
+        Self {
+            seed,
+            difficulty,
+            weeks: Vec::new(),
+            partial: false,
+        }
+    }
+
+    /// Record a finished turn and the checkpoint of the state it produced
+    pub fn record_turn(&mut self, week: u32, actions: Vec<Action>, checkpoint: u64) {
+        self.weeks.push(ReplayWeek {
+            week,
+            actions,
+            choices: Vec::new(),
+            cancelled_projects: Vec::new(),
+            checkpoint,
+        });
+    }
+
+    /// Record a dilemma choice against the latest turn and move its checkpoint forward
+    pub fn record_choice(&mut self, event_id: String, choice_index: usize, checkpoint: u64) {
+        if let Some(last) = self.weeks.last_mut() {
+            last.choices.push(ReplayChoice { event_id, choice_index });
+            last.checkpoint = checkpoint;
+        }
+    }
+
+    /// Record a cancelled project against the latest turn and move its checkpoint forward
+    pub fn record_cancellation(&mut self, project_id: u32, checkpoint: u64) {
+        if let Some(last) = self.weeks.last_mut() {
+            last.cancelled_projects.push(project_id);
+            last.checkpoint = checkpoint;
+        }
+    }
+}
+
+impl Default for ReplayLog {
+    /// Stand-in for a state saved without its log, so it is never verifiable
+    fn default() -> Self {
+        Self { partial: true, ..Self::new(0, DifficultyMode::IndieBootstrap) }
+    }
+}
+
+/// Stable hash of the simulated part of a GameState
+///
+/// Hashes the full contents of every live field, so a replay that drifts in a
+/// single customer, cohort, shareholder, loan or employee is caught. Meta fields
+/// and the append-only logs (replay, ledger, histories) are left out: they are
+/// consequences of the live fields, and skipping them keeps a checkpoint from
+/// growing with the length of the game.
+pub fn checkpoint_hash(state: &GameState) -> u64 {
+    let fields = [
+        serde_json::to_value(state.week),
+        serde_json::to_value(&state.difficulty),
+        serde_json::to_value(state.seed),
+        serde_json::to_value(state.rng_stream),
+        serde_json::to_value(state.bank),
+        serde_json::to_value(state.burn),
+        serde_json::to_value(state.runway_months),
+        serde_json::to_value(state.focus_slots),
+        serde_json::to_value(state.mrr),
+        serde_json::to_value(state.wau),
+        serde_json::to_value(state.wau_growth_rate),
+        serde_json::to_value(state.churn_rate),
+        serde_json::to_value(state.morale),
+        serde_json::to_value(state.reputation),
+        serde_json::to_value(state.nps),
+        serde_json::to_value(state.tech_debt),
+        serde_json::to_value(state.compliance_risk),
+        serde_json::to_value(state.velocity),
+        serde_json::to_value(state.founder_equity),
+        serde_json::to_value(state.option_pool),
+        serde_json::to_value(state.momentum),
+        serde_json::to_value(&state.escape_velocity_progress),
+        serde_json::to_value(&state.unlocked_actions),
+        serde_json::to_value(&state.active_market_conditions),
+        serde_json::to_value(&state.specialization_path),
+        serde_json::to_value(&state.event_cooldowns),
+        serde_json::to_value(&state.seasonal_challenge),
+        serde_json::to_value(state.incident_count),
+        serde_json::to_value(state.last_break_week),
+        serde_json::to_value(state.consecutive_ship_weeks),
+        serde_json::to_value(&state.customers),
+        serde_json::to_value(&state.competitors),
+        serde_json::to_value(state.player_market_share),
+        serde_json::to_value(&state.scheduled_events),
+        serde_json::to_value(&state.story_branches),
+        serde_json::to_value(&state.scheduled_effects),
+        serde_json::to_value(&state.projects),
+        serde_json::to_value(state.next_project_id),
+        serde_json::to_value(&state.cap_table),
+        serde_json::to_value(&state.investor_pipeline),
+        serde_json::to_value(&state.loans),
+        serde_json::to_value(&state.expenses),
+        serde_json::to_value(&state.mrr_bridge),
+        serde_json::to_value(&state.lapsed_customers),
+        serde_json::to_value(&state.cohorts),
+        serde_json::to_value(state.onboarding_quality),
+        serde_json::to_value(&state.pricing),
+        serde_json::to_value(&state.contracts),
+        serde_json::to_value(&state.team),
+        serde_json::to_value(state.next_employee_id),
+        serde_json::to_value(&state.candidates),
+    ];
+
+    // serde_json::Value keeps object keys sorted, so the encoding is canonical
+    let mut hash = FNV_OFFSET_BASIS;
+    for field in fields {
+        hash = fnv1a(hash, field.unwrap_or_default().to_string().as_bytes());
+    }
+    hash
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Apply a dilemma choice and record it in the state's replay log
+pub fn apply_and_record_choice(state: &mut GameState, event: &GameEvent, choice_index: usize) -> Result<(), String> {
+    match &event.event_type {
+        EnhancedEventType::Dilemma { choices } => {
+            let choice = choices.get(choice_index).ok_or("Invalid choice index")?;
+            let mut rng = state.next_rng();
+            apply_event_choice(state, choice, &mut rng);
+            record_branch(state, event, choice);
+
+            let checkpoint = checkpoint_hash(state);
+            state.replay.record_choice(event.id.clone(), choice_index, checkpoint);
+            Ok(())
+        }
+        _ => Err("Event does not require a choice".to_string()),
+    }
+}
+
+/// Cancel a running project and record it in the state's replay log
+pub fn cancel_and_record_project(state: &mut GameState, project_id: u32) -> Result<Project, String> {
+    let project = cancel_project(state, project_id)?;
+    let checkpoint = checkpoint_hash(state);
+    state.replay.record_cancellation(project_id, checkpoint);
+    Ok(project)
+}
+
+/// Rebuild a game from its log, stopping at the first week whose checkpoint differs
+pub fn replay(log: &ReplayLog) -> Result<ReplayReport, String> {
+    if log.partial {
+        return Err("This game was started before replays were recorded and cannot be verified".to_string());
+    }
+    let mut state = GameState::with_seed(log.difficulty.clone(), log.seed);
+
+    for (index, recorded) in log.weeks.iter().enumerate() {
+        let result = Engine::step(&mut state, &recorded.actions)
+            .map_err(|e| format!("Week {}: {}", recorded.week, e))?;
+
+        // A recorded dilemma that no longer occurs is itself a divergence
+        let mut diverged = false;
+        for choice in &recorded.choices {
+            match result.events.iter().find(|e| e.id == choice.event_id) {
+                Some(event) => apply_and_record_choice(&mut state, event, choice.choice_index)?,
+                None => {
+                    diverged = true;
+                    break;
+                }
+            }
+        }
+        for project_id in &recorded.cancelled_projects {
+            if diverged || cancel_and_record_project(&mut state, *project_id).is_err() {
+                diverged = true;
+                break;
+            }
+        }
+
+        if diverged || checkpoint_hash(&state) != recorded.checkpoint {
+            return Ok(ReplayReport {
+                final_state: state,
+                weeks_replayed: index + 1,
+                diverged_at_week: Some(recorded.week),
+            });
+        }
+    }
+
+    Ok(ReplayReport {
+        final_state: state,
+        weeks_replayed: log.weeks.len(),
+        diverged_at_week: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::Quality;
+    use crate::game::cap_table::Instrument;
+
+    fn play(weeks: usize) -> GameState {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 99);
+        for _ in 0..weeks {
+            let actions = vec![
+                Action::ShipFeature { quality: Quality::Balanced },
+                Action::FounderLedSales { call_count: 3 },
+            ];
+            let result = Engine::step(&mut state, &actions).unwrap();
+            if let Some(event) = result.events.iter().find(|e| matches!(e.event_type, EnhancedEventType::Dilemma { .. })) {
+                apply_and_record_choice(&mut state, event, 0).unwrap();
+            }
+        }
+        state
+    }
+
+    #[test]
+    fn test_replay_rebuilds_final_state() {
+        let state = play(15);
+        let report = replay(&state.replay).unwrap();
+
+        assert_eq!(report.diverged_at_week, None);
+        assert_eq!(report.weeks_replayed, 15);
+        assert_eq!(checkpoint_hash(&report.final_state), checkpoint_hash(&state));
+    }
+
+    #[test]
+    fn test_partial_log_is_not_verified() {
+        let mut log = play(3).replay;
+        log.partial = true;
+
+        assert!(replay(&log).is_err());
+    }
+
+    #[test]
+    fn test_replay_reports_first_divergent_week() {
+        let state = play(10);
+        let mut log = state.replay.clone();
+        log.weeks[4].actions = vec![Action::TakeBreak];
+
+        let report = replay(&log).unwrap();
+
+        assert_eq!(report.diverged_at_week, Some(log.weeks[4].week));
+        assert_eq!(report.weeks_replayed, 5);
+    }
+
+    #[test]
+    fn test_replay_applies_cancelled_projects() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 99);
+        Engine::step(&mut state, &[Action::Fundraise { target: 250_000.0, instrument: Instrument::PricedRound }]).unwrap();
+        let project_id = state.projects[0].id;
+        cancel_and_record_project(&mut state, project_id).unwrap();
+        // Only fits in the focus budget because the raise was cancelled
+        let interview = Action::Interview { candidate_id: state.candidates[0].id };
+        Engine::step(&mut state, &[interview, Action::FounderLedSales { call_count: 3 }, Action::TakeBreak]).unwrap();
+
+        let report = replay(&state.replay).unwrap();
+
+        assert_eq!(report.diverged_at_week, None);
+        assert!(report.final_state.projects.is_empty());
+    }
+
+    #[test]
+    fn test_checkpoint_ignores_meta_fields() {
+        let a = GameState::with_seed(DifficultyMode::IndieBootstrap, 5);
+        let mut b = a.clone();
+        b.game_id = "other".to_string();
+        b.started_at += 100;
+
+        assert_eq!(checkpoint_hash(&a), checkpoint_hash(&b));
+    }
+
+    #[test]
+    fn test_checkpoint_ignores_logs_but_not_outcomes() {
+        let mut a = GameState::with_seed(DifficultyMode::IndieBootstrap, 5);
+        Engine::step(&mut a, &[Action::TakeBreak]).unwrap();
+        let mut b = a.clone();
+        b.replay.weeks.clear();
+        b.history.clear();
+        assert_eq!(checkpoint_hash(&a), checkpoint_hash(&b));
+
+        b.bank += 1.0;
+        assert_ne!(checkpoint_hash(&a), checkpoint_hash(&b));
+    }
+
+    #[test]
+    fn test_checkpoint_covers_collection_contents() {
+        let mut a = GameState::with_seed(DifficultyMode::VCTrack, 5);
+        Engine::step(&mut a, &[Action::FounderLedSales { call_count: 3 }]).unwrap();
+        let base = checkpoint_hash(&a);
+
+        let mut b = a.clone();
+        b.cohorts[0].users += 1.0;
+        assert_ne!(checkpoint_hash(&b), base);
+
+        let mut c = a.clone();
+        c.team[0].morale -= 1.0;
+        assert_ne!(checkpoint_hash(&c), base);
+    }
+}
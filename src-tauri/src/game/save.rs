@@ -0,0 +1,297 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use super::cap_table::CapTable;
+use super::cohorts::{legacy_cohort, STARTING_ONBOARDING_QUALITY};
+use super::ledger::{expenses_for_burn, with_payroll};
+use super::mrr_bridge::MrrBridge;
+use super::pricing::Pricing;
+use super::session::GameSession;
+use super::state::{DifficultyMode, GameState};
+use super::team::team_from_payroll;
+
+/// Schema version written by this build
+///
+/// - v0: unversioned `GameState` JSON as the original release stored it
+/// - v1: versioned envelope around the current `GameState`
+pub const CURRENT_SAVE_VERSION: u32 = 1;
+
+/// Migrations indexed by the version they upgrade from
+const MIGRATIONS: [fn(&mut Map<String, Value>); CURRENT_SAVE_VERSION as usize] = [migrate_v0_to_v1];
+
+/// Envelope stored on disk around a serialized game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveFile {
+    pub schema_version: u32,
+    pub saved_at: i64,
+    pub state: Value,
+    /// Dilemmas the player had not answered when the game was saved
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending_dilemmas: Vec<Value>,
+}
+
+/// Lightweight listing entry so the UI can show saves without loading them
+#[derive(Debug, Clone, Serialize)]
+pub struct SaveSummary {
+    pub game_id: String,
+    pub schema_version: u32,
+    pub saved_at: i64,
+    pub week: u32,
+    pub difficulty: String,
+}
+
+/// Wrap a game in a save envelope at the current schema version
+pub fn encode_save(state: &GameState) -> Result<Value, String> {
+    encode_envelope(state, Vec::new())
+}
+
+/// Wrap a backend session, keeping the dilemmas still awaiting a choice
+pub fn encode_session(session: &GameSession) -> Result<Value, String> {
+    let pending = session
+        .pending_dilemmas
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    encode_envelope(&session.state, pending)
+}
+
+fn encode_envelope(state: &GameState, pending_dilemmas: Vec<Value>) -> Result<Value, String> {
+    let save = SaveFile {
+        schema_version: CURRENT_SAVE_VERSION,
+        saved_at: chrono::Utc::now().timestamp(),
+        state: serde_json::to_value(state).map_err(|e| e.to_string())?,
+        pending_dilemmas,
+    };
+    serde_json::to_value(save).map_err(|e| e.to_string())
+}
+
+/// Read a save of any known version, migrating it up to the current schema
+pub fn decode_save(raw: Value) -> Result<GameState, String> {
+    let (version, state) = split_envelope(raw)?;
+    if version > CURRENT_SAVE_VERSION {
+        return Err(format!(
+            "Save was written by a newer version (schema v{}, this build supports v{})",
+            version, CURRENT_SAVE_VERSION
+        ));
+    }
+
+    let mut state = match state {
+        Value::Object(fields) => fields,
+        _ => return Err("Save does not contain a game state".to_string()),
+    };
+    for migrate in &MIGRATIONS[version as usize..] {
+        migrate(&mut state);
+    }
+
+    serde_json::from_value(Value::Object(state)).map_err(|e| format!("Corrupt save: {}", e))
+}
+
+/// Read a save back into a session; dilemmas an older build wrote in another shape are dropped
+pub fn decode_session(raw: Value) -> Result<GameSession, String> {
+    let pending_dilemmas = raw
+        .get("pending_dilemmas")
+        .and_then(Value::as_array)
+        .map(|events| events.iter().filter_map(|e| serde_json::from_value(e.clone()).ok()).collect())
+        .unwrap_or_default();
+    let state = decode_save(raw)?;
+    Ok(GameSession { state, pending_dilemmas })
+}
+
+/// Describe a save without migrating or deserializing the whole state
+pub fn summarize_save(raw: &Value) -> Option<SaveSummary> {
+    let (schema_version, saved_at, state) = match raw.get("schema_version") {
+        Some(version) => (version.as_u64()? as u32, raw.get("saved_at")?.as_i64()?, raw.get("state")?),
+        None => (0, 0, raw),
+    };
+
+    Some(SaveSummary {
+        game_id: state.get("game_id")?.as_str()?.to_string(),
+        schema_version,
+        saved_at,
+        week: state.get("week")?.as_u64()? as u32,
+        difficulty: state.get("difficulty")?.as_str()?.to_string(),
+    })
+}
+
+/// Unversioned saves are a bare GameState object
+fn split_envelope(raw: Value) -> Result<(u32, Value), String> {
+    if raw.get("schema_version").is_none() {
+        return Ok((0, raw));
+    }
+    let save: SaveFile = serde_json::from_value(raw).map_err(|e| format!("Corrupt save: {}", e))?;
+    Ok((save.schema_version, save.state))
+}
+
+fn insert_missing(state: &mut Map<String, Value>, field: &str, value: Value) {
+    state.entry(field.to_string()).or_insert(value);
+}
+
+/// Fill in every system added since the original release from what the old save knew
+///
+/// Old games were not seeded, so they continue on seed 0 with a replay log that
+/// cannot rebuild them. Their payroll becomes a roster of people, their users and
+/// revenue a single founding cohort, and their ownership a cap table with the same
+/// percentages. Old hires named no candidate and old firings no employee, so those
+/// recorded actions point at id 0 and fail if a replay reaches them.
+fn migrate_v0_to_v1(state: &mut Map<String, Value>) {
+    insert_missing(state, "unlocked_actions", json!(["ShipFeature", "FounderLedSales", "Hire", "Fundraise", "TakeBreak"]));
+    insert_missing(state, "active_market_conditions", json!([]));
+    insert_missing(state, "specialization_path", Value::Null);
+    insert_missing(state, "action_history", json!([]));
+    insert_missing(state, "event_cooldowns", json!({}));
+    insert_missing(state, "seasonal_challenge", Value::Null);
+    insert_missing(state, "incident_count", json!(0));
+    insert_missing(state, "last_break_week", json!(0));
+    insert_missing(state, "consecutive_ship_weeks", json!(0));
+    insert_missing(state, "customers", json!([]));
+    insert_missing(state, "competitors", json!([]));
+    insert_missing(state, "player_market_share", json!(50.0));
+
+    let difficulty = state
+        .get("difficulty")
+        .and_then(|d| serde_json::from_value::<DifficultyMode>(d.clone()).ok())
+        .unwrap_or(DifficultyMode::IndieBootstrap);
+    let number = |field: &str, default: f64| state.get(field).and_then(Value::as_f64).unwrap_or(default);
+    let week = number("week", 0.0) as u32;
+    let burn = number("burn", difficulty.starting_burn());
+    let mrr = number("mrr", 0.0);
+    let users = number("wau", 0.0);
+    let cap_table = CapTable::from_ownership(number("founder_equity", 100.0), number("option_pool", 0.0));
+
+    insert_missing(state, "seed", json!(0));
+    insert_missing(state, "rng_stream", json!(1));
+    insert_missing(state, "replay", json!({ "seed": 0, "difficulty": difficulty, "weeks": [], "partial": true }));
+    insert_missing(state, "scheduled_events", json!([]));
+    insert_missing(state, "story_branches", json!({}));
+    insert_missing(state, "scheduled_effects", json!([]));
+    insert_missing(state, "projects", json!([]));
+    insert_missing(state, "next_project_id", json!(0));
+    insert_missing(state, "cap_table", serde_json::to_value(cap_table).unwrap_or_default());
+    insert_missing(state, "investor_pipeline", Value::Null);
+    insert_missing(state, "loans", json!([]));
+    insert_missing(state, "ledger", json!({ "transactions": [] }));
+    insert_missing(state, "mrr_bridge", serde_json::to_value(MrrBridge::opening(week, mrr)).unwrap_or_default());
+    insert_missing(state, "mrr_history", json!([]));
+    insert_missing(state, "lapsed_customers", json!([]));
+    insert_missing(state, "acquisition_log", json!([]));
+    insert_missing(state, "cohorts", serde_json::to_value(vec![legacy_cohort(users, mrr)]).unwrap_or_default());
+    insert_missing(state, "onboarding_quality", json!(STARTING_ONBOARDING_QUALITY));
+    insert_missing(state, "funnel_history", json!([]));
+    insert_missing(state, "pricing", serde_json::to_value(Pricing::default()).unwrap_or_default());
+    insert_missing(state, "contracts", json!([]));
+    insert_missing(state, "candidates", json!([]));
+
+    state.remove("team_size");
+    if !state.contains_key("team") {
+        let expenses = expenses_for_burn(&difficulty, burn);
+        let team = team_from_payroll(&expenses);
+        state.insert("next_employee_id".to_string(), json!(team.len() + 1));
+        state.insert("expenses".to_string(), serde_json::to_value(with_payroll(&expenses, &team)).unwrap_or_default());
+        state.insert("team".to_string(), serde_json::to_value(team).unwrap_or_default());
+    }
+
+    upgrade_actions(state, |action| match action {
+        Value::String(name) if name == "Hire" => *action = json!({ "Hire": { "candidate_id": 0, "offer": "AtAsk" } }),
+        Value::Object(fields) => {
+            if let Some(Value::Object(fire)) = fields.get_mut("Fire") {
+                insert_missing(fire, "employee_id", json!(0));
+            }
+        }
+        _ => {}
+    });
+}
+
+/// Rewrite every recorded action: the recent history, the replay log and running projects
+fn upgrade_actions(state: &mut Map<String, Value>, mut upgrade: impl FnMut(&mut Value)) {
+    if let Some(Value::Array(history)) = state.get_mut("action_history") {
+        for entry in history.iter_mut() {
+            if let Some(Value::Array(actions)) = entry.get_mut(1) {
+                actions.iter_mut().for_each(&mut upgrade);
+            }
+        }
+    }
+    if let Some(Value::Array(weeks)) = state.get_mut("replay").and_then(|r| r.get_mut("weeks")) {
+        for week in weeks.iter_mut() {
+            if let Some(Value::Array(actions)) = week.get_mut("actions") {
+                actions.iter_mut().for_each(&mut upgrade);
+            }
+        }
+    }
+    if let Some(Value::Array(projects)) = state.get_mut("projects") {
+        for project in projects.iter_mut() {
+            if let Some(action) = project.get_mut("action") {
+                upgrade(action);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::{Action, FiringReason};
+    use crate::game::event_library::EventLibrary;
+    use crate::game::hiring::OfferTerms;
+
+    #[test]
+    fn test_round_trip_current_version() {
+        let state = GameState::with_seed(DifficultyMode::VCTrack, 3);
+        let loaded = decode_save(encode_save(&state).unwrap()).unwrap();
+
+        assert_eq!(loaded.game_id, state.game_id);
+        assert_eq!(loaded.seed, 3);
+        assert_eq!(loaded.competitors.len(), state.competitors.len());
+    }
+
+    #[test]
+    fn test_original_release_save_migrates() {
+        let raw: Value = serde_json::from_str(include_str!("testdata/v0_save.json")).unwrap();
+
+        let loaded = decode_save(raw.clone()).unwrap();
+
+        assert_eq!(loaded.week, 3);
+        assert_eq!(loaded.bank, raw["bank"].as_f64().unwrap());
+        assert_eq!(loaded.customers.len(), 2);
+        assert!(loaded.replay.partial);
+        assert!((loaded.cap_table.founder_ownership() - 98.0).abs() < 0.01);
+        assert_eq!(loaded.team.len(), 4);
+        assert_eq!(loaded.next_employee_id, 5);
+        assert_eq!(loaded.cohorts[0].users, 100.0);
+        assert_eq!(loaded.action_history[0].1[0], Action::Hire { candidate_id: 0, offer: OfferTerms::AtAsk });
+        assert_eq!(loaded.action_history[2].1[0], Action::Fire { employee_id: 0, reason: FiringReason::Budget });
+        assert!(matches!(loaded.action_history[1].1[0], Action::Fundraise { target, .. } if target == 500_000.0));
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let state = GameState::with_seed(DifficultyMode::IndieBootstrap, 3);
+        let mut save = encode_save(&state).unwrap();
+        save["schema_version"] = json!(CURRENT_SAVE_VERSION + 1);
+
+        assert!(decode_save(save).is_err());
+    }
+
+    #[test]
+    fn test_session_keeps_pending_dilemmas() {
+        let mut session = GameSession::new(GameState::with_seed(DifficultyMode::VCTrack, 3));
+        let mut rng = session.state.next_rng();
+        let offer = EventLibrary::bundled().get("vc_offer").unwrap().fire(&session.state, &mut rng).unwrap();
+        session.pending_dilemmas.push(offer);
+
+        let loaded = decode_session(encode_session(&session).unwrap()).unwrap();
+
+        assert_eq!(loaded.pending_dilemmas.len(), 1);
+        assert_eq!(loaded.pending_dilemmas[0].id, session.pending_dilemmas[0].id);
+        assert!(decode_session(encode_save(&session.state).unwrap()).unwrap().pending_dilemmas.is_empty());
+    }
+
+    #[test]
+    fn test_summarize_save() {
+        let state = GameState::with_seed(DifficultyMode::InfraDevTool, 3);
+        let summary = summarize_save(&encode_save(&state).unwrap()).unwrap();
+
+        assert_eq!(summary.game_id, state.game_id);
+        assert_eq!(summary.schema_version, CURRENT_SAVE_VERSION);
+        assert_eq!(summary.difficulty, "InfraDevTool");
+    }
+}
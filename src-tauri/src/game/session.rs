@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use super::actions::Action;
+use super::engine::{Engine, TurnResult};
+use super::events_enhanced::{EnhancedEventType, GameEvent};
+use super::projects::Project;
+use super::replay::{apply_and_record_choice, cancel_and_record_project};
+use super::state::{DifficultyMode, GameState};
+
+/// A game owned by the backend, together with the dilemmas still awaiting a choice
+#[derive(Debug, Clone)]
+pub struct GameSession {
+    pub state: GameState,
+    pub pending_dilemmas: Vec<GameEvent>,
+}
+
+impl GameSession {
+    pub fn new(state: GameState) -> Self {
+        Self {
+            state,
+            pending_dilemmas: Vec::new(),
+        }
+    }
+
+    /// Play a week; unanswered dilemmas from the previous week expire
+    pub fn take_turn(&mut self, actions: &[Action]) -> Result<TurnResult, String> {
+        let result = Engine::step(&mut self.state, actions)?;
+        self.pending_dilemmas = result
+            .events
+            .iter()
+            .filter(|e| matches!(e.event_type, EnhancedEventType::Dilemma { .. }))
+            .cloned()
+            .collect();
+        Ok(result)
+    }
+
+    /// Resolve one of this week's pending dilemmas
+    pub fn choose(&mut self, event_id: &str, choice_index: usize) -> Result<(), String> {
+        let position = self
+            .pending_dilemmas
+            .iter()
+            .position(|e| e.id == event_id)
+            .ok_or_else(|| format!("No pending dilemma '{}'", event_id))?;
+
+        apply_and_record_choice(&mut self.state, &self.pending_dilemmas[position], choice_index)?;
+        self.pending_dilemmas.remove(position);
+        Ok(())
+    }
+
+    /// Abandon a running project before the next turn
+    pub fn cancel_project(&mut self, project_id: u32) -> Result<Project, String> {
+        cancel_and_record_project(&mut self.state, project_id)
+    }
+}
+
+/// All live games, keyed by `game_id`
+#[derive(Debug, Default)]
+pub struct SessionStore {
+    sessions: HashMap<String, GameSession>,
+}
+
+impl SessionStore {
+    /// Start a new game and return its initial state
+    pub fn create(&mut self, difficulty: DifficultyMode, seed: Option<u64>) -> GameState {
+        let state = match seed {
+            Some(seed) => GameState::with_seed(difficulty, seed),
+            None => GameState::new(difficulty),
+        };
+        self.insert(GameSession::new(state.clone()));
+        state
+    }
+
+    /// Track an existing session, replacing any session with the same id
+    pub fn insert(&mut self, session: GameSession) {
+        self.sessions.insert(session.state.game_id.clone(), session);
+    }
+
+    pub fn get(&self, game_id: &str) -> Result<&GameSession, String> {
+        self.sessions.get(game_id).ok_or_else(|| format!("Unknown game session '{}'", game_id))
+    }
+
+    pub fn get_mut(&mut self, game_id: &str) -> Result<&mut GameSession, String> {
+        self.sessions.get_mut(game_id).ok_or_else(|| format!("Unknown game session '{}'", game_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::Quality;
+
+    #[test]
+    fn test_create_and_take_turn() {
+        let mut store = SessionStore::default();
+        let state = store.create(DifficultyMode::IndieBootstrap, Some(1));
+
+        let session = store.get_mut(&state.game_id).unwrap();
+        session.take_turn(&[Action::ShipFeature { quality: Quality::Balanced }]).unwrap();
+
+        assert_eq!(store.get(&state.game_id).unwrap().state.week, 1);
+    }
+
+    #[test]
+    fn test_unknown_session_is_rejected() {
+        let store = SessionStore::default();
+        assert!(store.get("missing").is_err());
+    }
+
+    #[test]
+    fn test_choice_must_be_pending() {
+        let mut store = SessionStore::default();
+        let state = store.create(DifficultyMode::IndieBootstrap, Some(1));
+        let session = store.get_mut(&state.game_id).unwrap();
+
+        assert!(session.choose("vc_offer", 0).is_err());
+    }
+
+    #[test]
+    fn test_pending_dilemma_is_resolved_once() {
+        let mut store = SessionStore::default();
+        let state = store.create(DifficultyMode::VCTrack, Some(99));
+        let session = store.get_mut(&state.game_id).unwrap();
+
+        // Play until a dilemma shows up
+        while session.pending_dilemmas.is_empty() {
+            session.take_turn(&[Action::FounderLedSales { call_count: 3 }]).unwrap();
+            assert!(session.state.week < 200, "no dilemma in 200 weeks");
+        }
+        let event_id = session.pending_dilemmas[0].id.clone();
+
+        session.choose(&event_id, 0).unwrap();
+
+        assert!(session.choose(&event_id, 0).is_err());
+        assert_eq!(session.state.replay.weeks.last().unwrap().choices.len(), 1);
+    }
+}
@@ -2,11 +2,32 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 use super::actions::Action;
-use super::market_conditions::MarketCondition;
+use super::market_conditions::{MarketCondition, ModifierTarget};
 use super::synergies::SpecializationPath;
 use super::progression::{SeasonalChallenge, action_unlock_key};
 use super::customers::{Customer, CustomerSegment, update_customer_satisfaction, update_customer_lifecycle};
 use super::competitors::{Competitor, generate_competitors, update_competitor_state, generate_competitor_action, calculate_market_share};
+use super::rng::{GameRng, random_seed, rng_for_stream};
+use super::chains::ScheduledEvent;
+use super::effects::{ScheduledEffect, resolve_scheduled_effects};
+use super::projects::Project;
+use super::cap_table::CapTable;
+use super::investors::InvestorPipeline;
+use super::debt::{monthly_debt_service, service_debt, Loan};
+use super::ledger::{settle_week, starting_expenses, sync_payroll, ExpenseLine, Ledger};
+use super::mrr_bridge::MrrBridge;
+use super::cohorts::{adjust_users, founding_cohort, Cohort, STARTING_ONBOARDING_QUALITY};
+use super::metrics::AcquisitionRecord;
+use super::funnel::FunnelWeek;
+use super::pricing::Pricing;
+use super::billing::{renew_contracts, Contract};
+use super::team::{team_from_payroll, update_team, Employee, Role};
+use super::hiring::{refresh_candidates, Candidate};
+use super::replay::ReplayLog;
+use rand::Rng;
+
+/// Churned customers kept around for win-back sales
+const MAX_LAPSED_CUSTOMERS: usize = 20;
 
 /// Difficulty modes with different starting conditions and modifiers
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -18,6 +39,17 @@ pub enum DifficultyMode {
 }
 
 impl DifficultyMode {
+    /// Parse the short key used by the UI and tools ("indie", "vc", "regulated", "infra")
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "indie" => Some(DifficultyMode::IndieBootstrap),
+            "vc" => Some(DifficultyMode::VCTrack),
+            "regulated" => Some(DifficultyMode::RegulatedFintech),
+            "infra" => Some(DifficultyMode::InfraDevTool),
+            _ => None,
+        }
+    }
+
     pub fn starting_bank(&self) -> f64 {
         match self {
             DifficultyMode::IndieBootstrap => 50_000.0,
@@ -62,6 +94,16 @@ impl DifficultyMode {
             DifficultyMode::InfraDevTool => 0.7,
         }
     }
+
+    /// Multiplier applied to event effect sizes
+    pub fn event_modifier(&self) -> f64 {
+        match self {
+            DifficultyMode::IndieBootstrap => 1.0,
+            DifficultyMode::VCTrack => 1.2,
+            DifficultyMode::RegulatedFintech => 1.5,
+            DifficultyMode::InfraDevTool => 1.3,
+        }
+    }
 }
 
 /// Snapshot of game state at a specific week
@@ -137,6 +179,10 @@ pub struct GameState {
     pub week: u32,
     pub difficulty: DifficultyMode,
     pub started_at: i64,
+    #[serde(default)]
+    pub seed: u64,              // Seed for all simulation randomness
+    #[serde(default)]
+    pub rng_stream: u64,        // Number of RNG streams drawn from the seed so far
 
     // Resources (Primary Constraints)
     pub bank: f64,              // Cash in bank ($)
@@ -178,18 +224,66 @@ pub struct GameState {
     pub action_history: Vec<(u32, Vec<Action>)>,
     pub event_cooldowns: HashMap<String, u32>,
     pub seasonal_challenge: Option<SeasonalChallenge>,
-    pub team_size: u8,
     pub incident_count: u32,
     pub last_break_week: u32,
     pub consecutive_ship_weeks: u8,
     pub customers: Vec<Customer>,
     pub competitors: Vec<Competitor>,
     pub player_market_share: f64,
+
+    // Event chains: queued follow-ups and the choice made at each dilemma
+    pub scheduled_events: Vec<ScheduledEvent>,
+    pub story_branches: HashMap<String, String>,
+    // Delayed and recurring consequences of earlier choices
+    pub scheduled_effects: Vec<ScheduledEffect>,
+    // Multi-week actions in progress
+    pub projects: Vec<Project>,
+    pub next_project_id: u32,
+    // Shareholders and priced rounds; founder_equity and option_pool are read from it
+    pub cap_table: CapTable,
+    // Investors being worked while a raise is in progress
+    pub investor_pipeline: Option<InvestorPipeline>,
+    // Loans and revenue-based financing being repaid
+    pub loans: Vec<Loan>,
+    // Monthly costs that make up the burn, and every bank movement
+    pub expenses: Vec<ExpenseLine>,
+    pub ledger: Ledger,
+    // Where this week's MRR change came from, and the closed weeks before it
+    pub mrr_bridge: MrrBridge,
+    pub mrr_history: Vec<MrrBridge>,
+    // Recently churned customers who can still be won back
+    pub lapsed_customers: Vec<Customer>,
+    // Spend and customers by acquisition channel, for unit economics
+    pub acquisition_log: Vec<AcquisitionRecord>,
+    // Signup cohorts; MRR and WAU are their totals
+    pub cohorts: Vec<Cohort>,
+    pub onboarding_quality: f64, // 0-100, how well new signups are activated
+    // Visitors, signups, activations and paid conversions by week
+    pub funnel_history: Vec<FunnelWeek>,
+    // Plans on the pricing page
+    pub pricing: Pricing,
+    // Annual prepaid contracts, mostly Enterprise
+    pub contracts: Vec<Contract>,
+    // Everyone on payroll; payroll expense lines are kept in sync with it
+    pub team: Vec<Employee>,
+    pub next_employee_id: u32,
+    // People on the job market; candidate ids are reserved employee ids
+    pub candidates: Vec<Candidate>,
+
+    // Full input log for deterministic replays
+    #[serde(default)]
+    pub replay: ReplayLog,
 }
 
 impl GameState {
-    /// Create a new game with specified difficulty
+    /// Create a new game with specified difficulty and a random seed
     pub fn new(difficulty: DifficultyMode) -> Self {
+        Self::with_seed(difficulty, random_seed())
+    }
+
+    /// Create a new game with specified difficulty and seed
+    pub fn with_seed(difficulty: DifficultyMode, seed: u64) -> Self {
+        let mut rng = rng_for_stream(seed, 0);
         let bank = difficulty.starting_bank();
         let burn = difficulty.starting_burn();
         let runway_months = bank / burn;
@@ -199,6 +293,8 @@ impl GameState {
             week: 0,
             difficulty: difficulty.clone(),
             started_at: chrono::Utc::now().timestamp(),
+            seed,
+            rng_stream: 1,
 
             // Resources
             bank,
@@ -246,25 +342,60 @@ impl GameState {
             action_history: Vec::new(),
             event_cooldowns: HashMap::new(),
             seasonal_challenge: None,
-            team_size: 1, // Founder
             incident_count: 0,
             last_break_week: 0,
             consecutive_ship_weeks: 0,
             customers: Vec::new(),
-            competitors: generate_competitors(&difficulty, 0),
+            competitors: generate_competitors(&difficulty, 0, &mut rng),
             player_market_share: 50.0,
+            scheduled_events: Vec::new(),
+            story_branches: HashMap::new(),
+            scheduled_effects: Vec::new(),
+            projects: Vec::new(),
+            next_project_id: 0,
+            cap_table: CapTable::founding(),
+            investor_pipeline: None,
+            loans: Vec::new(),
+            expenses: starting_expenses(&difficulty),
+            ledger: Ledger::default(),
+            mrr_bridge: MrrBridge::opening(0, 0.0),
+            mrr_history: Vec::new(),
+            lapsed_customers: Vec::new(),
+            acquisition_log: Vec::new(),
+            cohorts: vec![founding_cohort(100.0)],
+            onboarding_quality: STARTING_ONBOARDING_QUALITY,
+            funnel_history: Vec::new(),
+            pricing: Pricing::default(),
+            contracts: Vec::new(),
+            team: Vec::new(),
+            next_employee_id: 1,
+            candidates: Vec::new(),
+            replay: ReplayLog::new(seed, difficulty.clone()),
         };
 
+        state.team = team_from_payroll(&state.expenses);
+        state.next_employee_id = state.team.len() as u32 + 1;
+        sync_payroll(&mut state);
+        refresh_candidates(&mut state, &mut rng);
+
         state.update_derived_metrics();
         state.save_snapshot();
         state
     }
 
+    /// Take the next deterministic RNG stream for this game
+    pub fn next_rng(&mut self) -> GameRng {
+        let rng = rng_for_stream(self.seed, self.rng_stream);
+        self.rng_stream += 1;
+        rng
+    }
+
     /// Update calculated/derived metrics
     pub fn update_derived_metrics(&mut self) {
-        // Update runway
-        if self.burn > 0.0 {
-            self.runway_months = self.bank / self.burn;
+        // Update runway; debt payments spend cash like burn does
+        let outflow = self.burn + monthly_debt_service(self);
+        if outflow > 0.0 {
+            self.runway_months = self.bank / outflow;
         } else {
             self.runway_months = f64::INFINITY;
         }
@@ -274,6 +405,10 @@ impl GameState {
             * self.velocity
             * (self.morale / 100.0);
 
+        // Ownership comes from the cap table
+        self.founder_equity = self.cap_table.founder_ownership();
+        self.option_pool = self.cap_table.option_pool_ownership();
+
         // Clamp values to valid ranges
         self.morale = self.morale.clamp(0.0, 100.0);
         self.reputation = self.reputation.clamp(0.0, 100.0);
@@ -305,20 +440,22 @@ impl GameState {
     }
 
     /// Advance to next week
-    pub fn advance_week(&mut self) {
+    pub fn advance_week(&mut self, rng: &mut GameRng) {
         self.week += 1;
 
-        // Apply weekly costs
-        let weekly_burn = self.burn / 4.0; // Convert monthly to weekly
-        self.bank -= weekly_burn;
+        // Pay a week of expenses and bank a week of revenue, line by line
+        settle_week(self);
 
-        // Apply weekly revenue
-        let weekly_mrr = self.mrr / 4.0;
-        self.bank += weekly_mrr;
+        // Annual contracts that reach their renewal date renew or lapse
+        renew_contracts(self, rng);
+
+        // Loan payments come out after the week's revenue is in
+        service_debt(self);
 
         // Apply growth
+        // New users join this week's cohort; shrinking usage leaves every cohort
         let prev_wau = self.wau;
-        self.wau = (self.wau as f64 * (1.0 + self.wau_growth_rate / 100.0)) as u32;
+        adjust_users(self, self.wau as f64 * self.wau_growth_rate / 100.0);
 
         // Calculate actual growth rate
         if prev_wau > 0 {
@@ -333,6 +470,9 @@ impl GameState {
             self.tech_debt += 0.5;
         }
 
+        // Deferred consequences of earlier choices land now
+        resolve_scheduled_effects(self);
+
         // Update market conditions
         super::market_conditions::update_market_conditions(self);
 
@@ -342,12 +482,12 @@ impl GameState {
         }
 
         // Increment incident_count if tech_debt > 80 (probabilistic)
-        if self.tech_debt > 80.0 && rand::random::<f64>() < 0.1 {
+        if self.tech_debt > 80.0 && rng.random::<f64>() < 0.1 {
             self.incident_count += 1;
         }
 
-        // Update team_size based on hires/fires - placeholder, actual logic in actions
-        // For now, assume no change; update in resolve_action
+        // Tenure, ramp-up and each person's morale move the team's velocity
+        update_team(self);
 
         // Track consecutive_ship_weeks - placeholder, update based on actions taken
         // If ShipFeature was taken this week, increment, else reset to 0
@@ -355,21 +495,21 @@ impl GameState {
 
         // Update customer lifecycle
         for customer in &mut self.customers {
-            update_customer_satisfaction(customer, self.nps, self.tech_debt, self.velocity);
+            update_customer_satisfaction(customer, self.nps, self.tech_debt, self.velocity, rng);
             update_customer_lifecycle(customer);
         }
 
-        // Handle customer churn
-        let mut churned_mrr = 0.0;
-        self.customers.retain(|customer| {
-            if std::mem::discriminant(&customer.lifecycle_stage) == std::mem::discriminant(&super::customers::CustomerLifecycle::Churned) {
-                churned_mrr += customer.mrr_contribution;
-                false
-            } else {
-                true
-            }
-        });
-        self.mrr -= churned_mrr;
+        // Handle customer churn; churned customers stay reachable for a win-back.
+        // Their revenue is already leaving through their cohort's retention curve.
+        let (churned, kept): (Vec<Customer>, Vec<Customer>) = std::mem::take(&mut self.customers)
+            .into_iter()
+            .partition(|customer| matches!(customer.lifecycle_stage, super::customers::CustomerLifecycle::Churned));
+        self.customers = kept;
+        self.lapsed_customers.extend(churned);
+        if self.lapsed_customers.len() > MAX_LAPSED_CUSTOMERS {
+            let excess = self.lapsed_customers.len() - MAX_LAPSED_CUSTOMERS;
+            self.lapsed_customers.drain(..excess);
+        }
 
         // Update competitor state (collect state data first to avoid borrow issues)
         let state_velocity = self.velocity;
@@ -383,7 +523,7 @@ impl GameState {
         // Generate competitor actions (collect first to avoid borrow issues)
         let mut competitor_actions = Vec::new();
         for competitor in &self.competitors {
-            if let Some(action) = generate_competitor_action(competitor, self) {
+            if let Some(action) = generate_competitor_action(competitor, self, rng) {
                 competitor_actions.push((competitor.id.clone(), action));
             }
         }
@@ -434,6 +574,9 @@ impl GameState {
         // Handle competitor acquisitions
         self.competitors.retain(|c| !c.is_acquired);
 
+        // The job market moves on: stale candidates leave and new ones apply
+        refresh_candidates(self, rng);
+
         // Update derived metrics
         self.update_derived_metrics();
 
@@ -474,24 +617,28 @@ impl GameState {
     }
 
     /// Get active modifiers from market conditions
-    pub fn get_active_modifiers(&self) -> Vec<(String, f64)> {
+    pub fn get_active_modifiers(&self) -> Vec<(ModifierTarget, f64)> {
         let mut modifiers = Vec::new();
         for condition in &self.active_market_conditions {
             for modifier in &condition.modifiers {
-                modifiers.push((modifier.stat_affected.clone(), modifier.multiplier));
+                modifiers.push((modifier.target, modifier.multiplier));
             }
         }
         modifiers
     }
 
+    /// Headcount, founder included
+    pub fn team_size(&self) -> usize {
+        self.team.len()
+    }
+
     /// Get team composition
     pub fn get_team_composition(&self) -> TeamComposition {
-        // Placeholder: calculate based on team_size and assumptions
-        // In a real implementation, track specific roles
+        let count = |roles: &[Role]| self.team.iter().filter(|e| roles.contains(&e.role)).count() as u8;
         TeamComposition {
-            engineers: (self.team_size / 2).max(1), // Assume half engineers
-            sales: (self.team_size / 4).max(0),
-            other: self.team_size - (self.team_size / 2).max(1) - (self.team_size / 4).max(0),
+            engineers: count(&[Role::Founder, Role::Engineer]),
+            sales: count(&[Role::Sales]),
+            other: count(&[Role::Designer, Role::Marketing, Role::Operations]),
         }
     }
 
@@ -521,11 +668,11 @@ impl GameState {
     }
 
     /// Calculate market-adjusted metric
-    pub fn calculate_market_adjusted_metric(&self, base_value: f64, metric: &str) -> f64 {
+    pub fn calculate_market_adjusted_metric(&self, base_value: f64, metric: ModifierTarget) -> f64 {
         let mut adjusted = base_value;
         for condition in &self.active_market_conditions {
             for modifier in &condition.modifiers {
-                if modifier.stat_affected == metric {
+                if modifier.target == metric {
                     adjusted *= modifier.multiplier;
                 }
             }
@@ -572,7 +719,7 @@ mod tests {
         assert_eq!(state.focus_slots, 3);
         // Test new fields
         assert!(state.unlocked_actions.contains(&"ShipFeature".to_string()));
-        assert_eq!(state.team_size, 1);
+        assert_eq!(state.team_size(), 1);
         assert_eq!(state.incident_count, 0);
     }
 
@@ -598,13 +745,33 @@ mod tests {
         let initial_bank = state.bank;
         let initial_week = state.week;
 
-        state.advance_week();
+        let mut rng = state.next_rng();
+        state.advance_week(&mut rng);
 
         assert_eq!(state.week, initial_week + 1);
         assert!(state.bank < initial_bank); // Should have spent money
         assert_eq!(state.history.len(), 2); // Initial + week 1
     }
 
+    #[test]
+    fn test_same_seed_same_game() {
+        let mut a = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        let mut b = GameState::with_seed(DifficultyMode::VCTrack, 7);
+        for _ in 0..20 {
+            let mut rng_a = a.next_rng();
+            a.advance_week(&mut rng_a);
+            let mut rng_b = b.next_rng();
+            b.advance_week(&mut rng_b);
+        }
+
+        assert_eq!(a.bank, b.bank);
+        assert_eq!(a.incident_count, b.incident_count);
+        assert_eq!(
+            a.competitors.iter().map(|c| (c.name.clone(), c.market_share)).collect::<Vec<_>>(),
+            b.competitors.iter().map(|c| (c.name.clone(), c.market_share)).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn test_morale_clamp() {
         let mut state = GameState::new(DifficultyMode::IndieBootstrap);
@@ -2,12 +2,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use crate::game::actions::Action;
 use crate::game::state::{GameState, WeekSnapshot};
+use crate::game::effects::{apply_effects, Effect, Stat, StatEffect};
 
 /// Represents the type of action for synergy detection (ignoring parameters)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ActionType {
     ShipFeature,
     FounderLedSales,
+    Interview,
     Hire,
     Fundraise,
     TakeBreak,
@@ -21,6 +23,8 @@ pub enum ActionType {
     ComplianceWork,
     IncidentResponse,
     ProcessImprovement,
+    TakeLoan,
+    ChangePrice,
 }
 
 /// Get the action type from an Action
@@ -28,7 +32,8 @@ pub fn get_action_type(action: &Action) -> ActionType {
     match action {
         Action::ShipFeature { .. } => ActionType::ShipFeature,
         Action::FounderLedSales { .. } => ActionType::FounderLedSales,
-        Action::Hire => ActionType::Hire,
+        Action::Interview { .. } => ActionType::Interview,
+        Action::Hire { .. } => ActionType::Hire,
         Action::Fundraise { .. } => ActionType::Fundraise,
         Action::TakeBreak => ActionType::TakeBreak,
         Action::RefactorCode { .. } => ActionType::RefactorCode,
@@ -41,17 +46,11 @@ pub fn get_action_type(action: &Action) -> ActionType {
         Action::ComplianceWork { .. } => ActionType::ComplianceWork,
         Action::IncidentResponse => ActionType::IncidentResponse,
         Action::ProcessImprovement => ActionType::ProcessImprovement,
+        Action::TakeLoan { .. } => ActionType::TakeLoan,
+        Action::ChangePrice { .. } => ActionType::ChangePrice,
     }
 }
 
-/// Bonus effect from a synergy
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SynergyBonus {
-    pub stat_name: String,
-    pub bonus_amount: f64,
-    pub is_multiplier: bool,
-}
-
 /// Action synergy definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionSynergy {
@@ -59,7 +58,7 @@ pub struct ActionSynergy {
     pub name: String,
     pub description: String,
     pub required_actions: Vec<ActionType>,
-    pub bonus_effects: Vec<SynergyBonus>,
+    pub bonus_effects: Vec<Effect>,
 }
 
 /// Specialization paths for consistent strategies
@@ -79,11 +78,7 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             name: "Launch Momentum".to_string(),
             description: "Shipping features with content creates launch momentum".to_string(),
             required_actions: vec![ActionType::ShipFeature, ActionType::ContentLaunch],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "WAU".to_string(),
-                bonus_amount: 0.15,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Wau, 0.15)],
         },
         ActionSynergy {
             id: "product_credibility".to_string(),
@@ -91,16 +86,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "DevRel amplifies product launches".to_string(),
             required_actions: vec![ActionType::ShipFeature, ActionType::DevRel],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "Reputation".to_string(),
-                    bonus_amount: 10.0,
-                    is_multiplier: false,
-                },
-                SynergyBonus {
-                    stat_name: "WAU".to_string(),
-                    bonus_amount: 0.05,
-                    is_multiplier: true,
-                },
+                Effect::add(Stat::Reputation, 10.0),
+                Effect::boost(Stat::Wau, 0.05),
             ],
         },
         ActionSynergy {
@@ -109,16 +96,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "Paid ads boost feature adoption".to_string(),
             required_actions: vec![ActionType::ShipFeature, ActionType::PaidAds],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "WAU".to_string(),
-                    bonus_amount: 0.20,
-                    is_multiplier: true,
-                },
-                SynergyBonus {
-                    stat_name: "Burn".to_string(),
-                    bonus_amount: -0.10,
-                    is_multiplier: true,
-                },
+                Effect::boost(Stat::Wau, 0.20),
+                Effect::boost(Stat::Burn, -0.10),
             ],
         },
         ActionSynergy {
@@ -126,11 +105,7 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             name: "Engineering Excellence".to_string(),
             description: "Refactoring with coaching builds strong engineering".to_string(),
             required_actions: vec![ActionType::RefactorCode, ActionType::Coach],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "Velocity".to_string(),
-                bonus_amount: 0.2,
-                is_multiplier: false,
-            }],
+            bonus_effects: vec![Effect::add(Stat::Velocity, 0.2)],
         },
         ActionSynergy {
             id: "technical_foundation".to_string(),
@@ -138,16 +113,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "Refactoring and process improvement create solid foundations".to_string(),
             required_actions: vec![ActionType::RefactorCode, ActionType::ProcessImprovement],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "Velocity".to_string(),
-                    bonus_amount: 0.15,
-                    is_multiplier: false,
-                },
-                SynergyBonus {
-                    stat_name: "TechDebt".to_string(),
-                    bonus_amount: -10.0,
-                    is_multiplier: false,
-                },
+                Effect::add(Stat::Velocity, 0.15),
+                Effect::add(Stat::TechDebt, -10.0),
             ],
         },
         ActionSynergy {
@@ -155,33 +122,21 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             name: "Data-Driven Content".to_string(),
             description: "Experiments inform better content".to_string(),
             required_actions: vec![ActionType::RunExperiment, ActionType::ContentLaunch],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "Reputation".to_string(),
-                bonus_amount: 0.10,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Reputation, 0.10)],
         },
         ActionSynergy {
             id: "credibility_boost".to_string(),
             name: "Credibility Boost".to_string(),
             description: "Sales calls backed by DevRel build trust".to_string(),
             required_actions: vec![ActionType::FounderLedSales, ActionType::DevRel],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "Reputation".to_string(),
-                bonus_amount: 10.0,
-                is_multiplier: false,
-            }],
+            bonus_effects: vec![Effect::add(Stat::Reputation, 10.0)],
         },
         ActionSynergy {
             id: "sales_team".to_string(),
             name: "Sales Team".to_string(),
             description: "Coaching improves sales effectiveness".to_string(),
             required_actions: vec![ActionType::FounderLedSales, ActionType::Coach],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "MRR".to_string(),
-                bonus_amount: 0.10,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Mrr, 0.10)],
         },
         ActionSynergy {
             id: "community_building".to_string(),
@@ -189,16 +144,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "Content and DevRel grow engaged communities".to_string(),
             required_actions: vec![ActionType::ContentLaunch, ActionType::DevRel],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "WAU".to_string(),
-                    bonus_amount: 0.20,
-                    is_multiplier: true,
-                },
-                SynergyBonus {
-                    stat_name: "Reputation".to_string(),
-                    bonus_amount: 15.0,
-                    is_multiplier: false,
-                },
+                Effect::boost(Stat::Wau, 0.20),
+                Effect::add(Stat::Reputation, 15.0),
             ],
         },
         ActionSynergy {
@@ -206,22 +153,14 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             name: "Integrated Marketing".to_string(),
             description: "Content amplifies paid ads".to_string(),
             required_actions: vec![ActionType::ContentLaunch, ActionType::PaidAds],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "WAU".to_string(),
-                bonus_amount: 0.50,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Wau, 0.50)],
         },
         ActionSynergy {
             id: "full_funnel".to_string(),
             name: "Full Funnel".to_string(),
             description: "DevRel and ads cover the entire funnel".to_string(),
             required_actions: vec![ActionType::DevRel, ActionType::PaidAds],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "WAU".to_string(),
-                bonus_amount: 0.25,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Wau, 0.25)],
         },
         ActionSynergy {
             id: "team_development".to_string(),
@@ -229,16 +168,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "Hiring followed by coaching builds strong teams".to_string(),
             required_actions: vec![ActionType::Hire, ActionType::Coach],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "Velocity".to_string(),
-                    bonus_amount: 0.15,
-                    is_multiplier: false,
-                },
-                SynergyBonus {
-                    stat_name: "Morale".to_string(),
-                    bonus_amount: 10.0,
-                    is_multiplier: false,
-                },
+                Effect::add(Stat::Velocity, 0.15),
+                Effect::add(Stat::Morale, 10.0),
             ],
         },
         ActionSynergy {
@@ -247,16 +178,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "Hiring with process improvement enables scaling".to_string(),
             required_actions: vec![ActionType::Hire, ActionType::ProcessImprovement],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "Burn".to_string(),
-                    bonus_amount: -0.15,
-                    is_multiplier: true,
-                },
-                SynergyBonus {
-                    stat_name: "Velocity".to_string(),
-                    bonus_amount: 0.1,
-                    is_multiplier: false,
-                },
+                Effect::boost(Stat::Burn, -0.15),
+                Effect::add(Stat::Velocity, 0.1),
             ],
         },
         ActionSynergy {
@@ -264,44 +187,28 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             name: "Regulatory Excellence".to_string(),
             description: "Compliance work and processes ensure regulatory compliance".to_string(),
             required_actions: vec![ActionType::ComplianceWork, ActionType::ProcessImprovement],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "ComplianceRisk".to_string(),
-                bonus_amount: -20.0,
-                is_multiplier: false,
-            }],
+            bonus_effects: vec![Effect::add(Stat::ComplianceRisk, -20.0)],
         },
         ActionSynergy {
             id: "incident_prevention".to_string(),
             name: "Incident Prevention".to_string(),
             description: "Responding to incidents with process improvement prevents future issues".to_string(),
             required_actions: vec![ActionType::IncidentResponse, ActionType::ProcessImprovement],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "TechDebt".to_string(),
-                bonus_amount: -5.0,
-                is_multiplier: false,
-            }],
+            bonus_effects: vec![Effect::add(Stat::TechDebt, -5.0)],
         },
         ActionSynergy {
             id: "growth_capital".to_string(),
             name: "Growth Capital".to_string(),
             description: "Fundraising enables better hiring".to_string(),
             required_actions: vec![ActionType::Fundraise, ActionType::Hire],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "Velocity".to_string(),
-                bonus_amount: 0.20,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Velocity, 0.20)],
         },
         ActionSynergy {
             id: "marketing_budget".to_string(),
             name: "Marketing Budget".to_string(),
             description: "Fundraising boosts marketing effectiveness".to_string(),
             required_actions: vec![ActionType::Fundraise, ActionType::PaidAds],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "WAU".to_string(),
-                bonus_amount: 0.30,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Wau, 0.30)],
         },
         ActionSynergy {
             id: "founder_wellness".to_string(),
@@ -309,16 +216,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "Breaks with coaching maintain founder health".to_string(),
             required_actions: vec![ActionType::TakeBreak, ActionType::Coach],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "Morale".to_string(),
-                    bonus_amount: 20.0,
-                    is_multiplier: false,
-                },
-                SynergyBonus {
-                    stat_name: "Reputation".to_string(),
-                    bonus_amount: 5.0,
-                    is_multiplier: false,
-                },
+                Effect::add(Stat::Morale, 20.0),
+                Effect::add(Stat::Reputation, 5.0),
             ],
         },
         ActionSynergy {
@@ -327,16 +226,8 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             description: "Firing and hiring refreshes the team".to_string(),
             required_actions: vec![ActionType::Fire, ActionType::Hire],
             bonus_effects: vec![
-                SynergyBonus {
-                    stat_name: "Velocity".to_string(),
-                    bonus_amount: 0.1,
-                    is_multiplier: false,
-                },
-                SynergyBonus {
-                    stat_name: "Morale".to_string(),
-                    bonus_amount: -5.0,
-                    is_multiplier: false,
-                },
+                Effect::add(Stat::Velocity, 0.1),
+                Effect::add(Stat::Morale, -5.0),
             ],
         },
         ActionSynergy {
@@ -344,11 +235,7 @@ pub fn get_all_synergies() -> Vec<ActionSynergy> {
             name: "Optimized Ads".to_string(),
             description: "Experiments optimize ad spend".to_string(),
             required_actions: vec![ActionType::RunExperiment, ActionType::PaidAds],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "WAU".to_string(),
-                bonus_amount: 0.15,
-                is_multiplier: true,
-            }],
+            bonus_effects: vec![Effect::boost(Stat::Wau, 0.15)],
         },
     ]
 }
@@ -386,7 +273,7 @@ pub fn detect_specialization_path(action_history: &[(u32, Vec<Action>)], recent_
             ActionType::ShipFeature | ActionType::RefactorCode | ActionType::RunExperiment => "product",
             ActionType::FounderLedSales | ActionType::ContentLaunch | ActionType::DevRel | ActionType::PaidAds => "growth",
             ActionType::ComplianceWork | ActionType::IncidentResponse | ActionType::ProcessImprovement => "ops",
-            ActionType::Hire | ActionType::Coach | ActionType::Fire => "team",
+            ActionType::Interview | ActionType::Hire | ActionType::Coach | ActionType::Fire => "team",
             _ => "other",
         };
         *category_counts.entry(category).or_insert(0.0) += 1.0;
@@ -414,56 +301,8 @@ pub fn detect_specialization_path(action_history: &[(u32, Vec<Action>)], recent_
 }
 
 /// Apply synergy bonuses to the game state
-pub fn apply_synergy_bonuses(state: &mut GameState, synergies: &[ActionSynergy]) {
-    for synergy in synergies {
-        for bonus in &synergy.bonus_effects {
-            match bonus.stat_name.as_str() {
-                "WAU" => {
-                    let mut wau = state.wau as f64;
-                    apply_bonus(&mut wau, bonus);
-                    state.wau = wau.max(0.0).round() as u32;
-                }
-                "MRR" => {
-                    apply_bonus(&mut state.mrr, bonus);
-                    state.mrr = state.mrr.max(0.0);
-                }
-                "Burn" => {
-                    apply_bonus(&mut state.burn, bonus);
-                    state.burn = state.burn.max(0.0);
-                }
-                "Velocity" => {
-                    apply_bonus(&mut state.velocity, bonus);
-                    state.velocity = state.velocity.clamp(0.0, 5.0);
-                }
-                "Morale" => {
-                    apply_bonus(&mut state.morale, bonus);
-                    state.morale = state.morale.clamp(0.0, 100.0);
-                }
-                "Reputation" => {
-                    apply_bonus(&mut state.reputation, bonus);
-                    state.reputation = state.reputation.clamp(0.0, 100.0);
-                }
-                "TechDebt" => {
-                    apply_bonus(&mut state.tech_debt, bonus);
-                    state.tech_debt = state.tech_debt.clamp(0.0, 100.0);
-                }
-                "ComplianceRisk" => {
-                    apply_bonus(&mut state.compliance_risk, bonus);
-                    state.compliance_risk = state.compliance_risk.clamp(0.0, 100.0);
-                }
-                _ => continue,
-            }
-        }
-    }
-    state.update_derived_metrics();
-}
-
-fn apply_bonus(value: &mut f64, bonus: &SynergyBonus) {
-    if bonus.is_multiplier {
-        *value *= 1.0 + bonus.bonus_amount;
-    } else {
-        *value += bonus.bonus_amount;
-    }
+pub fn apply_synergy_bonuses(state: &mut GameState, synergies: &[ActionSynergy]) -> Vec<StatEffect> {
+    apply_effects(state, synergies.iter().flat_map(|s| &s.bonus_effects))
 }
 
 /// Calculate a score for how well actions work together
@@ -511,11 +350,7 @@ mod tests {
             name: "Test".to_string(),
             description: "Test synergy".to_string(),
             required_actions: vec![],
-            bonus_effects: vec![SynergyBonus {
-                stat_name: "WAU".to_string(),
-                bonus_amount: 10.0,
-                is_multiplier: false,
-            }],
+            bonus_effects: vec![Effect::add(Stat::Wau, 10.0)],
         }];
         apply_synergy_bonuses(&mut state, &synergies);
         assert_eq!(state.wau, initial_wau + 10);
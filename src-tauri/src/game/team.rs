@@ -0,0 +1,322 @@
+use serde::{Deserialize, Serialize};
+use rand::seq::IndexedRandom;
+use super::ledger::{sync_payroll, ExpenseLine, LedgerCategory, FOUNDER_SALARY};
+use super::rng::GameRng;
+use super::state::GameState;
+
+/// Velocity a fully ramped engineer with perfect skill adds
+const VELOCITY_PER_BUILDER: f64 = 0.15;
+/// Velocity before the product team's output and tech debt are counted
+const BASE_VELOCITY: f64 = 1.0;
+/// Tech debt at which nothing ships at all
+const DEBT_STALL_POINT: f64 = 200.0;
+/// Founder-led sales conversion a fully ramped seller with perfect skill adds
+const CONVERSION_PER_SELLER: f64 = 0.25;
+/// Share of the gap to company morale each person closes every week
+const MORALE_DRIFT: f64 = 0.2;
+/// Morale a new hire starts with
+const STARTING_MORALE: f64 = 70.0;
+
+const FIRST_NAMES: [&str; 20] = [
+    "Alex", "Priya", "Sam", "Jordan", "Mei", "Tomás", "Aisha", "Lukas", "Noor", "Diego",
+    "Hana", "Kofi", "Elena", "Ravi", "Zoe", "Marcus", "Ingrid", "Yusuf", "Chloe", "Kenji",
+];
+const LAST_NAMES: [&str; 20] = [
+    "Nguyen", "Patel", "Okafor", "Schmidt", "Costa", "Kowalski", "Haddad", "Tanaka", "Moreau", "Silva",
+    "Andersen", "Mensah", "Rossi", "Iyer", "Novak", "Reyes", "Lindqvist", "Demir", "Walsh", "Sato",
+];
+
+/// What someone does at the company
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    Founder,
+    Engineer,
+    Designer,
+    Sales,
+    Marketing,
+    Operations,
+}
+
+impl Role {
+    /// Roles the company can hire for; there is only ever one founder
+    pub const HIRABLE: [Role; 5] = [Role::Engineer, Role::Designer, Role::Sales, Role::Marketing, Role::Operations];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Role::Founder => "Founder",
+            Role::Engineer => "Engineer",
+            Role::Designer => "Designer",
+            Role::Sales => "Account executive",
+            Role::Marketing => "Marketer",
+            Role::Operations => "Operations",
+        }
+    }
+
+    /// Monthly salary for a mid-level hire
+    pub fn base_salary(&self) -> f64 {
+        match self {
+            Role::Founder => 8_000.0,
+            Role::Engineer => 10_000.0,
+            Role::Designer => 9_000.0,
+            Role::Sales => 8_000.0,
+            Role::Marketing => 7_500.0,
+            Role::Operations => 6_500.0,
+        }
+    }
+
+    /// Whether the role ships product and adds velocity
+    pub fn builds_product(&self) -> bool {
+        matches!(self, Role::Founder | Role::Engineer | Role::Designer)
+    }
+
+    /// Best guess at the role behind a payroll line, for games saved before the roster
+    fn from_payroll_label(label: &str) -> Self {
+        let label = label.to_lowercase();
+        if label == FOUNDER_SALARY.to_lowercase() {
+            Role::Founder
+        } else if label.contains("design") {
+            Role::Designer
+        } else {
+            Role::Engineer
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Seniority {
+    Junior,
+    Mid,
+    Senior,
+}
+
+impl Seniority {
+    pub fn salary_factor(&self) -> f64 {
+        match self {
+            Seniority::Junior => 0.7,
+            Seniority::Mid => 1.0,
+            Seniority::Senior => 1.4,
+        }
+    }
+
+    /// Range skill is drawn from, out of 100
+    pub fn skill_range(&self) -> (f64, f64) {
+        match self {
+            Seniority::Junior => (30.0, 50.0),
+            Seniority::Mid => (50.0, 70.0),
+            Seniority::Senior => (70.0, 90.0),
+        }
+    }
+
+    /// Weeks until a new hire works at full output
+    pub fn ramp_up_weeks(&self) -> u32 {
+        match self {
+            Seniority::Junior => 12,
+            Seniority::Mid => 8,
+            Seniority::Senior => 4,
+        }
+    }
+}
+
+/// One person on the payroll
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Employee {
+    pub id: u32,
+    pub name: String,
+    pub role: Role,
+    pub seniority: Seniority,
+    /// Monthly salary
+    pub salary: f64,
+    /// 0-100
+    pub skill: f64,
+    /// 0-100; drifts toward company morale
+    pub morale: f64,
+    /// Weeks left before they work at full output
+    pub ramp_up_weeks: u32,
+    pub tenure_weeks: u32,
+}
+
+impl Employee {
+    /// Someone joining today, starting to ramp up from nothing
+    pub fn new_hire(id: u32, name: String, role: Role, seniority: Seniority, salary: f64, skill: f64) -> Self {
+        Self {
+            id,
+            name,
+            role,
+            seniority,
+            salary,
+            skill,
+            morale: STARTING_MORALE,
+            ramp_up_weeks: seniority.ramp_up_weeks(),
+            tenure_weeks: 0,
+        }
+    }
+
+    /// Share of a perfect hire's output: nothing on day one, rising as they ramp, lower when unhappy
+    pub fn output(&self) -> f64 {
+        let ramp = 1.0 - self.ramp_up_weeks as f64 / self.seniority.ramp_up_weeks() as f64;
+        self.skill / 100.0 * ramp.clamp(0.0, 1.0) * (0.5 + self.morale / 200.0)
+    }
+
+    pub fn is_founder(&self) -> bool {
+        self.role == Role::Founder
+    }
+}
+
+/// Velocity the product team adds
+pub fn team_velocity(team: &[Employee]) -> f64 {
+    team.iter().filter(|e| e.role.builds_product()).map(Employee::output).sum::<f64>() * VELOCITY_PER_BUILDER
+}
+
+/// Multiplier on founder-led sales conversion from the sales team
+pub fn sales_capacity(state: &GameState) -> f64 {
+    1.0 + state.team.iter().filter(|e| e.role == Role::Sales).map(Employee::output).sum::<f64>() * CONVERSION_PER_SELLER
+}
+
+pub fn random_name(rng: &mut GameRng) -> String {
+    format!("{} {}", FIRST_NAMES.choose(rng).unwrap(), LAST_NAMES.choose(rng).unwrap())
+}
+
+/// The people behind the payroll lines a game starts with, already ramped up
+pub fn team_from_payroll(expenses: &[ExpenseLine]) -> Vec<Employee> {
+    expenses
+        .iter()
+        .filter(|line| line.category == LedgerCategory::Payroll)
+        .enumerate()
+        .map(|(index, line)| {
+            let role = Role::from_payroll_label(&line.label);
+            let seniority = if role == Role::Founder || line.label.contains("Founding") { Seniority::Senior } else { Seniority::Mid };
+            Employee {
+                id: index as u32 + 1,
+                name: match role {
+                    Role::Founder => "You".to_string(),
+                    _ => format!("{} {}", FIRST_NAMES[index % FIRST_NAMES.len()], LAST_NAMES[index * 7 % LAST_NAMES.len()]),
+                },
+                role,
+                seniority,
+                salary: line.monthly,
+                skill: seniority.skill_range().1 - 10.0,
+                morale: STARTING_MORALE,
+                ramp_up_weeks: 0,
+                tenure_weeks: 0,
+            }
+        })
+        .collect()
+}
+
+/// Put a new hire on payroll
+pub fn hire(state: &mut GameState, employee: Employee) {
+    state.next_employee_id = state.next_employee_id.max(employee.id + 1);
+    state.team.push(employee);
+    sync_payroll(state);
+}
+
+/// Let someone go and take them off payroll; the founder stays
+pub fn fire(state: &mut GameState, employee_id: u32) -> Result<Employee, String> {
+    let position = state
+        .team
+        .iter()
+        .position(|e| e.id == employee_id)
+        .ok_or_else(|| format!("No employee with id {}", employee_id))?;
+    if state.team[position].is_founder() {
+        return Err("You can't fire yourself".to_string());
+    }
+    let employee = state.team.remove(position);
+    sync_payroll(state);
+    Ok(employee)
+}
+
+/// Move velocity by the change in what the product team delivers
+pub fn apply_velocity_change(state: &mut GameState, before: f64) -> f64 {
+    let delta = team_velocity(&state.team) - before;
+    state.velocity += delta;
+    delta
+}
+
+/// Velocity the roster can sustain at the current tech debt
+pub fn roster_velocity(state: &GameState) -> f64 {
+    let debt_penalty = (1.0 - state.tech_debt / DEBT_STALL_POINT).clamp(0.0, 1.0);
+    (BASE_VELOCITY + team_velocity(&state.team)) * debt_penalty
+}
+
+/// Move velocity back to what the roster sustains and return the change
+///
+/// Drops one-off boosts and penalties, including ones earlier actions made this week.
+pub fn settle_velocity(state: &mut GameState) -> f64 {
+    let before = state.velocity;
+    state.velocity = roster_velocity(state);
+    state.velocity - before
+}
+
+/// A week passes for everyone: tenure grows, new hires ramp and morale drifts
+pub fn update_team(state: &mut GameState) {
+    let before = team_velocity(&state.team);
+    let company_morale = state.morale;
+    for employee in &mut state.team {
+        employee.tenure_weeks += 1;
+        employee.ramp_up_weeks = employee.ramp_up_weeks.saturating_sub(1);
+        employee.morale += (company_morale - employee.morale) * MORALE_DRIFT;
+    }
+    apply_velocity_change(state, before);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::DifficultyMode;
+
+    #[test]
+    fn test_starting_team_matches_payroll() {
+        let state = GameState::with_seed(DifficultyMode::VCTrack, 2);
+        let payroll: f64 = state.expenses.iter().filter(|l| l.category == LedgerCategory::Payroll).map(|l| l.monthly).sum();
+
+        assert_eq!(state.team.len(), 4);
+        assert_eq!(state.team.iter().map(|e| e.salary).sum::<f64>(), payroll);
+        assert_eq!(state.team.iter().filter(|e| e.is_founder()).count(), 1);
+        assert_eq!(state.team.iter().filter(|e| e.role == Role::Designer).count(), 1);
+    }
+
+    #[test]
+    fn test_new_hire_ramps_into_velocity() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 2);
+        let burn = state.burn;
+        let velocity = state.velocity;
+
+        let hire_id = state.next_employee_id;
+        let new_hire = Employee::new_hire(hire_id, "Mei Tanaka".to_string(), Role::Engineer, Seniority::Mid, 10_000.0, 60.0);
+        hire(&mut state, new_hire.clone());
+
+        assert_eq!(state.burn, burn + new_hire.salary);
+        assert_eq!(state.velocity, velocity);
+        assert_eq!(state.next_employee_id, hire_id + 1);
+        for _ in 0..new_hire.seniority.ramp_up_weeks() {
+            update_team(&mut state);
+        }
+        assert!(state.velocity > velocity);
+        assert_eq!(state.team.last().unwrap().tenure_weeks, new_hire.seniority.ramp_up_weeks());
+    }
+
+    #[test]
+    fn test_fire_targets_one_person_and_keeps_the_founder() {
+        let mut state = GameState::with_seed(DifficultyMode::VCTrack, 2);
+        let designer = state.team.iter().find(|e| e.role == Role::Designer).unwrap().clone();
+        let founder = state.team.iter().find(|e| e.is_founder()).unwrap().id;
+        let burn = state.burn;
+
+        assert_eq!(fire(&mut state, designer.id).unwrap(), designer);
+        assert_eq!(state.burn, burn - designer.salary);
+        assert!(fire(&mut state, founder).is_err());
+        assert!(fire(&mut state, designer.id).is_err());
+    }
+
+    #[test]
+    fn test_sellers_raise_sales_capacity() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 2);
+        assert_eq!(sales_capacity(&state), 1.0);
+
+        let mut seller = Employee::new_hire(state.next_employee_id, "Kofi Mensah".to_string(), Role::Sales, Seniority::Senior, 11_200.0, 80.0);
+        seller.ramp_up_weeks = 0;
+        hire(&mut state, seller);
+
+        assert!(sales_capacity(&state) > 1.0);
+    }
+}
@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+use super::competitors::{Competitor, CompetitorActionType, FundingStage, PricingStrategy};
+use super::customers::{get_at_risk_customers, Customer, CustomerLifecycle};
+use super::billing::renewals_due;
+use super::debt::breached_loans;
+use super::investors::live_offers;
+use super::state::{DifficultyMode, GameState, WeekSnapshot};
+
+/// A number a trigger can compare against
+///
+/// Subject metrics read the customer or competitor an event is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    Week,
+    Bank,
+    Burn,
+    RunwayMonths,
+    Mrr,
+    Wau,
+    WauGrowthRate,
+    ChurnRate,
+    Morale,
+    Reputation,
+    Nps,
+    TechDebt,
+    ComplianceRisk,
+    Velocity,
+    FounderEquity,
+    Momentum,
+    TeamSize,
+    Customers,
+    AtRiskCustomers,
+    CustomerMrr,
+    CompetitorFeatureParity,
+    /// Live term sheets in the current raise
+    TermSheets,
+    /// Loans breaching a covenant
+    CovenantBreaches,
+    /// Annual contracts nearing renewal with no terms agreed
+    RenewalsDue,
+}
+
+impl Metric {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Metric::Week => "Week",
+            Metric::Bank => "Bank",
+            Metric::Burn => "Burn",
+            Metric::RunwayMonths => "Runway (months)",
+            Metric::Mrr => "MRR",
+            Metric::Wau => "WAU",
+            Metric::WauGrowthRate => "WAU growth %",
+            Metric::ChurnRate => "Churn rate",
+            Metric::Morale => "Morale",
+            Metric::Reputation => "Reputation",
+            Metric::Nps => "NPS",
+            Metric::TechDebt => "Tech debt",
+            Metric::ComplianceRisk => "Compliance risk",
+            Metric::Velocity => "Velocity",
+            Metric::FounderEquity => "Founder equity",
+            Metric::Momentum => "Growth",
+            Metric::TeamSize => "Team size",
+            Metric::Customers => "Customers",
+            Metric::AtRiskCustomers => "At-risk customers",
+            Metric::CustomerMrr => "Customer MRR",
+            Metric::CompetitorFeatureParity => "Competitor feature parity",
+            Metric::TermSheets => "Term sheets",
+            Metric::CovenantBreaches => "Covenant breaches",
+            Metric::RenewalsDue => "Renewals due",
+        }
+    }
+
+    /// Current value, or None when the metric needs a subject or snapshot field that is missing
+    fn value(&self, ctx: &TriggerContext) -> Option<f64> {
+        if let Some(snapshot) = ctx.snapshot {
+            return self.snapshot_value(snapshot);
+        }
+        let state = ctx.state;
+        Some(match self {
+            Metric::Week => state.week as f64,
+            Metric::Bank => state.bank,
+            Metric::Burn => state.burn,
+            Metric::RunwayMonths => state.runway_months,
+            Metric::Mrr => state.mrr,
+            Metric::Wau => state.wau as f64,
+            Metric::WauGrowthRate => state.wau_growth_rate,
+            Metric::ChurnRate => state.churn_rate,
+            Metric::Morale => state.morale,
+            Metric::Reputation => state.reputation,
+            Metric::Nps => state.nps,
+            Metric::TechDebt => state.tech_debt,
+            Metric::ComplianceRisk => state.compliance_risk,
+            Metric::Velocity => state.velocity,
+            Metric::FounderEquity => state.founder_equity,
+            Metric::Momentum => state.momentum,
+            Metric::TeamSize => state.team_size() as f64,
+            Metric::Customers => state.customers.len() as f64,
+            Metric::AtRiskCustomers => get_at_risk_customers(&state.customers).len() as f64,
+            Metric::CustomerMrr => ctx.customer?.mrr_contribution,
+            Metric::CompetitorFeatureParity => ctx.competitor?.feature_parity,
+            Metric::TermSheets => live_offers(state) as f64,
+            Metric::CovenantBreaches => breached_loans(state) as f64,
+            Metric::RenewalsDue => renewals_due(state) as f64,
+        })
+    }
+
+    /// Value recorded in a weekly snapshot; only the snapshot's own fields are available
+    fn snapshot_value(&self, snapshot: &WeekSnapshot) -> Option<f64> {
+        match self {
+            Metric::Week => Some(snapshot.week as f64),
+            Metric::Bank => Some(snapshot.bank),
+            Metric::Burn => Some(snapshot.burn),
+            Metric::Mrr => Some(snapshot.mrr),
+            Metric::Wau => Some(snapshot.wau as f64),
+            Metric::Morale => Some(snapshot.morale),
+            Metric::Reputation => Some(snapshot.reputation),
+            Metric::Momentum => Some(snapshot.momentum),
+            _ => None,
+        }
+    }
+}
+
+/// Machine-evaluable trigger for an event
+///
+/// Written in content files as externally tagged JSON, e.g.
+/// `{"all": [{"above": ["tech_debt", 70]}, {"difficulty": "RegulatedFintech"}]}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    All(Vec<Condition>),
+    Any(Vec<Condition>),
+    Not(Box<Condition>),
+    Above(Metric, f64),
+    Below(Metric, f64),
+    Difficulty(DifficultyMode),
+    /// Held in each of the last N weekly snapshots
+    ForWeeks(usize, Box<Condition>),
+    CustomerLifecycle(CustomerLifecycle),
+    CustomerHasFeedback,
+    CompetitorPricing(PricingStrategy),
+    CompetitorRaisedFunding,
+    CompetitorFundingStage(Vec<FundingStage>),
+    /// The player picked `choice` (a choice label) at the dilemma `chain`
+    Branch { chain: String, choice: String },
+}
+
+impl Default for Condition {
+    /// An empty `all` always holds
+    fn default() -> Self {
+        Condition::All(Vec::new())
+    }
+}
+
+/// What a condition is evaluated against
+#[derive(Debug, Clone, Copy)]
+pub struct TriggerContext<'a> {
+    pub state: &'a GameState,
+    pub customer: Option<&'a Customer>,
+    pub competitor: Option<&'a Competitor>,
+    /// Set while evaluating the inner condition of `ForWeeks`
+    snapshot: Option<&'a WeekSnapshot>,
+}
+
+impl<'a> TriggerContext<'a> {
+    pub fn new(state: &'a GameState, customer: Option<&'a Customer>, competitor: Option<&'a Competitor>) -> Self {
+        Self { state, customer, competitor, snapshot: None }
+    }
+}
+
+impl Condition {
+    pub fn evaluate(&self, ctx: &TriggerContext) -> bool {
+        match self {
+            Condition::All(conditions) => conditions.iter().all(|c| c.evaluate(ctx)),
+            Condition::Any(conditions) => conditions.iter().any(|c| c.evaluate(ctx)),
+            Condition::Not(condition) => !condition.evaluate(ctx),
+            Condition::Above(metric, threshold) => metric.value(ctx).is_some_and(|v| v > *threshold),
+            Condition::Below(metric, threshold) => metric.value(ctx).is_some_and(|v| v < *threshold),
+            Condition::Difficulty(difficulty) => ctx.state.difficulty == *difficulty,
+            Condition::ForWeeks(weeks, condition) => {
+                let history = &ctx.state.history;
+                history.len() >= *weeks
+                    && history.iter().rev().take(*weeks).all(|snapshot| {
+                        condition.evaluate(&TriggerContext { snapshot: Some(snapshot), ..*ctx })
+                    })
+            }
+            Condition::CustomerLifecycle(stage) => ctx.customer.is_some_and(|c| c.lifecycle_stage == *stage),
+            Condition::CustomerHasFeedback => ctx.customer.is_some_and(|c| !c.feedback_history.is_empty()),
+            Condition::CompetitorPricing(strategy) => ctx.competitor.is_some_and(|c| c.pricing_strategy == *strategy),
+            Condition::CompetitorRaisedFunding => ctx.competitor.is_some_and(|c| {
+                c.action_history.iter().any(|a| matches!(a.action_type, CompetitorActionType::FundingRound))
+            }),
+            Condition::CompetitorFundingStage(stages) => ctx.competitor.is_some_and(|c| stages.contains(&c.funding_stage)),
+            Condition::Branch { chain, choice } => ctx.state.story_branches.get(chain) == Some(choice),
+        }
+    }
+
+    /// Human-readable prerequisites shown with the event, one per top-level clause
+    pub fn describe(&self) -> Vec<String> {
+        match self {
+            Condition::All(conditions) => conditions.iter().map(Condition::summary).collect(),
+            _ => vec![self.summary()],
+        }
+    }
+
+    fn summary(&self) -> String {
+        match self {
+            Condition::All(conditions) => conditions.iter().map(Condition::summary).collect::<Vec<_>>().join(" and "),
+            Condition::Any(conditions) => conditions.iter().map(Condition::summary).collect::<Vec<_>>().join(" or "),
+            Condition::Not(condition) => format!("Not ({})", condition.summary()),
+            Condition::Above(metric, threshold) => format!("{} > {}", metric.label(), threshold),
+            Condition::Below(metric, threshold) => format!("{} < {}", metric.label(), threshold),
+            Condition::Difficulty(difficulty) => format!("Difficulty: {:?}", difficulty),
+            Condition::ForWeeks(weeks, condition) => format!("{} for {} weeks", condition.summary(), weeks),
+            Condition::CustomerLifecycle(stage) => format!("{:?} customer", stage),
+            Condition::CustomerHasFeedback => "Customer has left feedback".to_string(),
+            Condition::CompetitorPricing(strategy) => format!("Competitor uses {:?} pricing", strategy),
+            Condition::CompetitorRaisedFunding => "Competitor recently raised funding".to_string(),
+            Condition::CompetitorFundingStage(stages) => format!("Competitor at {:?}", stages),
+            Condition::Branch { choice, .. } => format!("You chose \"{}\"", choice),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Condition {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_threshold_conditions() {
+        let mut state = GameState::with_seed(DifficultyMode::RegulatedFintech, 1);
+        state.tech_debt = 75.0;
+        state.compliance_risk = 40.0;
+        let ctx = TriggerContext::new(&state, None, None);
+
+        assert!(parse(r#"{"above": ["tech_debt", 70]}"#).evaluate(&ctx));
+        assert!(!parse(r#"{"all": [{"difficulty": "RegulatedFintech"}, {"above": ["compliance_risk", 60]}]}"#).evaluate(&ctx));
+        assert!(parse(r#"{"any": [{"below": ["tech_debt", 10]}, {"not": {"above": ["compliance_risk", 60]}}]}"#).evaluate(&ctx));
+    }
+
+    #[test]
+    fn test_for_weeks_needs_full_history() {
+        let mut state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        let condition = parse(r#"{"for_weeks": [4, {"below": ["morale", 30]}]}"#);
+        state.morale = 20.0;
+
+        for _ in 0..3 {
+            state.save_snapshot();
+        }
+        assert!(!condition.evaluate(&TriggerContext::new(&state, None, None)));
+
+        state.save_snapshot();
+        assert!(condition.evaluate(&TriggerContext::new(&state, None, None)));
+    }
+
+    #[test]
+    fn test_subject_conditions_fail_without_subject() {
+        let state = GameState::with_seed(DifficultyMode::IndieBootstrap, 1);
+        let condition = parse(r#"{"below": ["competitor_feature_parity", 40]}"#);
+
+        assert!(!condition.evaluate(&TriggerContext::new(&state, None, None)));
+
+        let competitor = &state.competitors[0];
+        let ctx = TriggerContext::new(&state, None, Some(competitor));
+        assert_eq!(condition.evaluate(&ctx), competitor.feature_parity < 40.0);
+    }
+}
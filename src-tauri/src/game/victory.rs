@@ -15,6 +15,7 @@ pub enum DefeatCondition {
     OutOfMoney,
     FounderBurnout,
     ReputationDestroyed,
+    DebtDefault,
 }
 
 /// Check if player has achieved victory
@@ -44,6 +45,11 @@ pub fn check_defeat(state: &GameState) -> Option<DefeatCondition> {
         return Some(DefeatCondition::ReputationDestroyed);
     }
 
+    // Defaulted on a loan
+    if state.loans.iter().any(|loan| loan.is_in_default()) {
+        return Some(DefeatCondition::DebtDefault);
+    }
+
     None
 }
 
@@ -68,6 +74,7 @@ pub fn update_escape_velocity_progress(state: &mut GameState) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::debt::{take_loan, DebtKind};
     use crate::game::state::DifficultyMode;
 
     #[test]
@@ -97,6 +104,17 @@ mod tests {
         assert!(matches!(defeat, Some(DefeatCondition::FounderBurnout)));
     }
 
+    #[test]
+    fn test_defeat_debt_default() {
+        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        state.mrr = 20_000.0;
+        take_loan(&mut state, DebtKind::BankLoan).unwrap();
+        state.loans[0].missed_payments = 3;
+
+        let defeat = check_defeat(&state);
+        assert!(matches!(defeat, Some(DefeatCondition::DebtDefault)));
+    }
+
     #[test]
     fn test_escape_velocity_progress() {
         let mut state = GameState::new(DifficultyMode::VCTrack);
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use super::state::GameState;
+use super::metrics::{unit_economics, METRICS_WINDOW_WEEKS};
 
 /// Warning about impending failure if patterns continue
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -277,6 +278,40 @@ pub fn check_failure_warnings(state: &GameState) -> Vec<FailureWarning> {
         });
     }
 
+    // 7. Inefficient Burn - Spending far more than the growth it buys
+    let metrics = unit_economics(state);
+    if let Some(burn_multiple) = metrics.burn_multiple.filter(|m| *m > 3.0 && state.week >= METRICS_WINDOW_WEEKS) {
+        let severity = if burn_multiple > 5.0 {
+            WarningSeverity::Danger
+        } else {
+            WarningSeverity::Caution
+        };
+
+        let mut warning_signs = vec![WarningSign {
+            week: state.week,
+            observation: format!("Burning ${:.2} for every $1 of net new ARR", burn_multiple),
+            indicator_level: (burn_multiple * 15.0).min(100.0),
+        }];
+        if let Some(payback) = metrics.cac_payback_months {
+            warning_signs.push(WarningSign {
+                week: state.week,
+                observation: format!("Customers take {:.0} months to pay back what they cost to acquire", payback),
+                indicator_level: (payback * 4.0).min(100.0),
+            });
+        }
+
+        warnings.push(FailureWarning {
+            risk_id: "inefficient_burn".to_string(),
+            title: "Inefficient Burn".to_string(),
+            current_status: format!("Burn multiple of {:.1}x over the last quarter", burn_multiple),
+            warning_signs,
+            projected_outcome: "If this continues: The money runs out long before growth pays for itself. Investors read a burn multiple above 3x as a business that can't scale efficiently, so the next round gets harder just when you need it.".to_string(),
+            lesson: "Growth isn't free, but its price matters. A burn multiple under 2x means each dollar burned buys real revenue. Above 3x, cut spend that isn't producing customers and fix retention before pouring more into acquisition.".to_string(),
+            weeks_until_critical: Some((state.runway_months * 4.0).clamp(0.0, u8::MAX as f64) as u8),
+            severity,
+        });
+    }
+
     // Sort by severity
     warnings.sort_by_key(|w| w.severity.clone());
     warnings.reverse(); // Most severe first
@@ -418,6 +453,8 @@ fn estimate_weeks_until_reputation_failure(current: f64) -> u8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::ledger::LedgerCategory;
+    use crate::game::mrr_bridge::MrrBridge;
     use crate::game::state::DifficultyMode;
 
     #[test]
@@ -461,6 +498,18 @@ mod tests {
         assert!(warnings.iter().any(|w| w.risk_id == "cash_crunch"));
     }
 
+    #[test]
+    fn test_inefficient_burn_warning() {
+        let mut state = GameState::new(DifficultyMode::IndieBootstrap);
+        state.week = 12;
+        state.mrr_history = vec![MrrBridge { new_business: 1_000.0, ..MrrBridge::opening(12, 0.0) }];
+        state.ledger.record(12, LedgerCategory::Payroll, -60_000.0, "Payroll");
+
+        let warnings = check_failure_warnings(&state);
+
+        assert!(warnings.iter().any(|w| w.risk_id == "inefficient_burn"));
+    }
+
     #[test]
     fn test_warning_severity_ordering() {
         let mut state = GameState::new(DifficultyMode::IndieBootstrap);
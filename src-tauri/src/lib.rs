@@ -5,7 +5,9 @@
 )]
 
 use serde::Serialize;
-use std::sync::Mutex;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
 use tauri::{
   // state is used in Linux
   self,
@@ -17,22 +19,19 @@ use tauri_plugin_window_state;
 
 mod tray_icon;
 mod utils;
-mod game;
+pub mod game;
 
 use tray_icon::{TrayState, create_tray_icon, tray_update_lang};
 use utils::long_running_thread;
 use game::{
-    GameState, DifficultyMode, Action,
-    actions::resolve_action,
-    victory::{check_victory, check_defeat, update_escape_velocity_progress},
-    economy::{apply_churn, update_nps},
-    insights::generate_weekly_insights,
-    compounding::{check_compounding_effects, apply_compounding_bonuses},
-    warnings::check_failure_warnings,
-    events_enhanced::check_for_events,
-    synergies::{check_action_synergies, detect_specialization_path, apply_synergy_bonuses, ActionSynergy, SpecializationPath},
-    market_conditions::{get_active_conditions, update_market_conditions, generate_market_condition, MarketCondition, get_action_effectiveness_modifier},
-    progression::{action_unlock_key, get_available_actions as progression_get_available_actions, check_milestone_events, check_unlocks, MilestoneEvent},
+  GameState, DifficultyMode, Action, TurnResult, ProfitAndLoss, UnitEconomics, unit_economics, CohortRow, cohort_table, ConversionRates, conversion_rates,
+  victory::{check_victory, check_defeat},
+  market_conditions::get_active_conditions,
+  progression::get_available_actions as progression_get_available_actions,
+  replay::{replay, ReplayLog, ReplayReport},
+  session::{GameSession, SessionStore},
+  event_library::EventLibrary,
+  save::{encode_session, decode_session, summarize_save, SaveSummary},
 };
 
 #[derive(Clone, Serialize)]
@@ -60,167 +59,221 @@ fn process_file(filepath: String) -> String {
 // GAME COMMANDS
 // ============================================================================
 
+type Sessions<'a> = tauri::State<'a, Mutex<SessionStore>>;
+
+/// Directory in the app data dir holding one `<game_id>.json` per game
+const SAVES_DIR: &str = "saves";
+
+fn lock_sessions<'a>(sessions: &'a Sessions) -> Result<MutexGuard<'a, SessionStore>, String> {
+  sessions.lock().map_err(|e| format!("Session store unavailable: {}", e))
+}
+
 #[tauri::command]
-fn new_game(difficulty: String) -> Result<GameState, String> {
-  let diff = match difficulty.as_str() {
-    "indie" => DifficultyMode::IndieBootstrap,
-    "vc" => DifficultyMode::VCTrack,
-    "regulated" => DifficultyMode::RegulatedFintech,
-    "infra" => DifficultyMode::InfraDevTool,
-    _ => return Err("Invalid difficulty mode".to_string()),
-  };
-
-  Ok(GameState::new(diff))
+fn new_game(sessions: Sessions, difficulty: String, seed: Option<u64>) -> Result<GameState, String> {
+  let diff = DifficultyMode::from_key(&difficulty).ok_or("Invalid difficulty mode")?;
+  Ok(lock_sessions(&sessions)?.create(diff, seed))
 }
 
+/// Turn response for the webview: the new state plus everything that happened
 #[derive(Clone, Serialize)]
-struct TurnResult {
+struct TurnResponse {
   state: GameState,
-  insights: Vec<game::insights::WeeklyInsight>,
-  warnings: Vec<game::warnings::FailureWarning>,
-  compounding_bonuses: Vec<game::compounding::CompoundingBonus>,
-  events: Vec<game::events_enhanced::GameEvent>,
-  synergies: Vec<game::synergies::ActionSynergy>,
-  market_conditions: Vec<game::market_conditions::MarketCondition>,
-  unlocked_actions: Vec<String>,
-  milestone_event: Option<game::progression::MilestoneEvent>,
-  specialization_bonus: Option<game::synergies::SpecializationPath>,
+  #[serde(flatten)]
+  result: TurnResult,
 }
 
 #[tauri::command]
-fn take_turn(mut state: GameState, actions: Vec<Action>) -> Result<TurnResult, String> {
-  // Before action resolution: Check action unlocks and validate
-  let available_actions = progression_get_available_actions(&state);
-  for action in &actions {
-    if !available_actions.iter().any(|available| available == action) {
-      return Err(format!("Action {:?} is not unlocked yet", action));
-    }
-  }
-  let market_modifiers = get_active_conditions(&state);
+fn take_turn(app: tauri::AppHandle, sessions: Sessions, game_id: String, actions: Vec<Action>) -> Result<TurnResponse, String> {
+  let mut store = lock_sessions(&sessions)?;
+  let session = store.get_mut(&game_id)?;
+  let result = session.take_turn(&actions)?;
+  autosave(&app, session);
+  Ok(TurnResponse {
+    state: session.state.clone(),
+    result,
+  })
+}
+
+#[tauri::command]
+fn apply_event_choice(
+  app: tauri::AppHandle,
+  sessions: Sessions,
+  game_id: String,
+  event_id: String,
+  choice_index: usize,
+) -> Result<GameState, String> {
+  let mut store = lock_sessions(&sessions)?;
+  let session = store.get_mut(&game_id)?;
+  session.choose(&event_id, choice_index)?;
+  autosave(&app, session);
+  Ok(session.state.clone())
+}
 
-  // Validate focus cost
-  let total_focus: u8 = actions.iter().map(|a| a.focus_cost()).sum();
-  if total_focus > state.focus_slots {
-    return Err(format!("Not enough focus slots! Required: {}, Available: {}", total_focus, state.focus_slots));
+#[tauri::command]
+fn cancel_project(app: tauri::AppHandle, sessions: Sessions, game_id: String, project_id: u32) -> Result<GameState, String> {
+  let mut store = lock_sessions(&sessions)?;
+  let session = store.get_mut(&game_id)?;
+  session.cancel_project(project_id)?;
+  autosave(&app, session);
+  Ok(session.state.clone())
+}
+
+fn saves_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+  let dir = app.path().app_data_dir().map_err(|e| e.to_string())?.join(SAVES_DIR);
+  std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+  Ok(dir)
+}
+
+fn save_path(app: &tauri::AppHandle, game_id: &str) -> Result<PathBuf, String> {
+  // Ids come from the webview, so never let one reach outside the saves dir
+  if game_id.is_empty() || !game_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+    return Err(format!("Invalid game id '{}'", game_id));
   }
+  Ok(saves_dir(app)?.join(format!("{}.json", game_id)))
+}
+
+fn read_save_file(path: &Path) -> Result<Value, String> {
+  let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+  serde_json::from_slice(&bytes).map_err(|e| format!("Corrupt save: {}", e))
+}
+
+/// Write through a temp file so a crash mid-save never leaves a truncated save
+fn write_save_file(path: &Path, save: &Value) -> Result<(), String> {
+  let bytes = serde_json::to_vec(save).map_err(|e| e.to_string())?;
+  let tmp = path.with_extension("json.tmp");
+  std::fs::write(&tmp, bytes).map_err(|e| e.to_string())?;
+  std::fs::rename(&tmp, path).map_err(|e| e.to_string())
+}
 
-  // Save state before changes for insights comparison
-  let prev_state = state.clone();
+fn write_save(app: &tauri::AppHandle, session: &GameSession) -> Result<(), String> {
+  write_save_file(&save_path(app, &session.state.game_id)?, &encode_session(session)?)
+}
 
-  // During action resolution: Apply market effectiveness modifiers and track actions
-  for action in &actions {
-    let _modifier = get_action_effectiveness_modifier(action, &market_modifiers);
-    // Apply modifier to action resolution (assuming resolve_action can take a modifier; adjust if needed)
-    let _result = resolve_action(&mut state, action); // TODO: Integrate modifier into resolve_action
+/// Save after every change so a closed window never loses progress
+fn autosave(app: &tauri::AppHandle, session: &GameSession) {
+  if let Err(e) = write_save(app, session) {
+    log::warn!("Autosave of game {} failed: {}", session.state.game_id, e);
   }
-  // Track actions in state.action_history (assuming state has this field; add if not present)
-  state.action_history.push((state.week, actions.clone()));
-
-  // After action resolution: Check synergies and apply bonuses
-  let synergies = check_action_synergies(&actions);
-  apply_synergy_bonuses(&mut state, &synergies);
-  let specialization_bonus = detect_specialization_path(&state.action_history, &actions);
-
-  // Check and apply compounding effects (rewards for sustained good practices)
-  let compounding_bonuses = check_compounding_effects(&state, 12);
-  apply_compounding_bonuses(&mut state, &compounding_bonuses);
-
-  // Apply weekly mechanics
-  apply_churn(&mut state);
-  update_nps(&mut state);
-  update_escape_velocity_progress(&mut state);
-
-  // During week advancement: Update market conditions, check for new ones, milestones, and unlocks
-  update_market_conditions(&mut state);
-  let new_market_condition = generate_market_condition(&state, state.week);
-  if let Some(condition) = new_market_condition {
-    // Add to state.active_market_conditions (assuming field exists)
-    state.active_market_conditions.push(condition);
+}
+
+/// Event files ship as resources so designers can edit them without a rebuild
+fn load_event_content(app: &tauri::AppHandle) {
+  let loaded = app
+    .path()
+    .resource_dir()
+    .map_err(|e| e.to_string())
+    .and_then(|dir| EventLibrary::load_dir(&dir.join("content").join("events")));
+  if let Err(e) = loaded.and_then(EventLibrary::install) {
+    log::warn!("Using bundled event content: {}", e);
   }
-  let milestone_event = check_milestone_events(&state);
-  let new_unlock_actions = check_unlocks(&state);
-  let mut new_unlocks = Vec::new();
-  for action in new_unlock_actions {
-    let key = action_unlock_key(&action);
-    if !state.unlocked_actions.contains(&key) {
-      state.unlocked_actions.push(key.clone());
-      new_unlocks.push(key);
-    }
+}
+
+#[tauri::command]
+fn save_game(app: tauri::AppHandle, sessions: Sessions, game_id: String) -> Result<(), String> {
+  let store = lock_sessions(&sessions)?;
+  write_save(&app, store.get(&game_id)?)
+}
+
+#[tauri::command]
+fn load_game(app: tauri::AppHandle, sessions: Sessions, game_id: String) -> Result<GameState, String> {
+  let path = save_path(&app, &game_id)?;
+  if !path.exists() {
+    return Err(format!("No save for game '{}'", game_id));
   }
+  let session = decode_session(read_save_file(&path)?)?;
+  let state = session.state.clone();
+  lock_sessions(&sessions)?.insert(session);
+  Ok(state)
+}
 
-  // Advance to next week
-  state.advance_week();
+/// Bring in a save written outside the saves dir, such as the bare game state older builds kept
+#[tauri::command]
+fn import_save(app: tauri::AppHandle, save: Value) -> Result<(), String> {
+  let session = decode_session(save)?;
+  // Never clobber a game that has already been imported and played on
+  if save_path(&app, &session.state.game_id)?.exists() {
+    return Ok(());
+  }
+  write_save(&app, &session)
+}
 
-  // Update derived metrics
-  state.update_derived_metrics();
+#[tauri::command]
+fn list_saves(app: tauri::AppHandle) -> Result<Vec<SaveSummary>, String> {
+  let entries = std::fs::read_dir(saves_dir(&app)?).map_err(|e| e.to_string())?;
+  let mut summaries: Vec<SaveSummary> = entries
+    .flatten()
+    .map(|entry| entry.path())
+    .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+    .filter_map(|path| read_save_file(&path).ok())
+    .filter_map(|save| summarize_save(&save))
+    .collect();
+  summaries.sort_by(|a, b| b.saved_at.cmp(&a.saved_at));
+  Ok(summaries)
+}
 
-  // Generate educational insights by comparing before/after
-  let insights = generate_weekly_insights(&prev_state, &state);
+#[tauri::command]
+fn verify_replay(log: ReplayLog) -> Result<ReplayReport, String> {
+  replay(&log)
+}
 
-  // Check for failure warnings
-  let warnings = check_failure_warnings(&state);
+#[tauri::command]
+fn get_available_actions(sessions: Sessions, game_id: String) -> Result<Vec<String>, String> {
+  let store = lock_sessions(&sessions)?;
+  let actions = progression_get_available_actions(&store.get(&game_id)?.state);
+  Ok(actions.iter().map(|a| format!("{:?}", a)).collect())
+}
 
-  // Check for random events
-  let events = check_for_events(&mut state);
+#[tauri::command]
+fn get_market_status(sessions: Sessions, game_id: String) -> Result<Vec<game::market_conditions::MarketCondition>, String> {
+  let store = lock_sessions(&sessions)?;
+  Ok(get_active_conditions(&store.get(&game_id)?.state))
+}
 
-  Ok(TurnResult {
-    state,
-    insights,
-    warnings,
-    compounding_bonuses,
-    events,
-    synergies,
-    market_conditions: market_modifiers,
-    unlocked_actions: new_unlocks,
-    milestone_event,
-    specialization_bonus,
-  })
+/// Profit and loss over the last `weeks` weeks, up to a year
+#[tauri::command]
+fn get_profit_and_loss(sessions: Sessions, game_id: String, weeks: u32) -> Result<ProfitAndLoss, String> {
+  let store = lock_sessions(&sessions)?;
+  let state = &store.get(&game_id)?.state;
+  let from_week = state.week.saturating_sub(weeks.max(1) - 1);
+  Ok(state.ledger.profit_and_loss(from_week, state.week))
 }
 
+/// CAC, LTV, payback, burn multiple and the rest over the trailing quarter
 #[tauri::command]
-fn apply_event_choice(
-  mut state: GameState,
-  event_id: String,
-  choice_index: usize,
-  event: game::events_enhanced::GameEvent,
-) -> Result<GameState, String> {
-  match event.event_type {
-    game::events_enhanced::EnhancedEventType::Dilemma { choices } => {
-      if choice_index >= choices.len() {
-        return Err("Invalid choice index".to_string());
-      }
-
-      let choice = &choices[choice_index];
-      game::events_enhanced::apply_event_choice(&mut state, choice);
-
-      Ok(state)
-    }
-    _ => Err("Event does not require a choice".to_string()),
-  }
+fn get_unit_economics(sessions: Sessions, game_id: String) -> Result<UnitEconomics, String> {
+  let store = lock_sessions(&sessions)?;
+  Ok(unit_economics(&store.get(&game_id)?.state))
 }
 
+/// User and revenue retention for every signup cohort
 #[tauri::command]
-fn get_available_actions(state: GameState) -> Result<Vec<String>, String> {
-  let actions = progression_get_available_actions(&state);
-  Ok(actions.iter().map(|a| format!("{:?}", a)).collect())
+fn get_cohort_table(sessions: Sessions, game_id: String) -> Result<Vec<CohortRow>, String> {
+  let store = lock_sessions(&sessions)?;
+  Ok(cohort_table(&store.get(&game_id)?.state))
 }
 
+/// Current conversion rates at each stage of the acquisition funnel
 #[tauri::command]
-fn get_market_status(state: GameState) -> Result<Vec<game::market_conditions::MarketCondition>, String> {
-  Ok(get_active_conditions(&state))
+fn get_conversion_rates(sessions: Sessions, game_id: String) -> Result<ConversionRates, String> {
+  let store = lock_sessions(&sessions)?;
+  Ok(conversion_rates(&store.get(&game_id)?.state))
 }
 
 #[tauri::command]
-fn check_game_status(state: GameState) -> Result<String, String> {
-  if let Some(_victory) = check_victory(&state) {
+fn check_game_status(sessions: Sessions, game_id: String) -> Result<String, String> {
+  let store = lock_sessions(&sessions)?;
+  let state = &store.get(&game_id)?.state;
+
+  if let Some(_victory) = check_victory(state) {
     return Ok("victory".to_string());
   }
 
-  if let Some(defeat) = check_defeat(&state) {
+  if let Some(defeat) = check_defeat(state) {
     let reason = match defeat {
       game::victory::DefeatCondition::OutOfMoney => "out_of_money",
       game::victory::DefeatCondition::FounderBurnout => "burnout",
       game::victory::DefeatCondition::ReputationDestroyed => "reputation",
+      game::victory::DefeatCondition::DebtDefault => "debt_default",
     };
     return Ok(format!("defeat:{}", reason));
   }
@@ -273,9 +326,19 @@ pub fn run() {
       new_game,
       take_turn,
       apply_event_choice,
+      cancel_project,
       check_game_status,
       get_available_actions,
       get_market_status,
+      get_profit_and_loss,
+      get_unit_economics,
+      get_cohort_table,
+      get_conversion_rates,
+      verify_replay,
+      save_game,
+      load_game,
+      import_save,
+      list_saves,
     ])
     // allow only one instance and propagate args and cwd to existing instance
     .plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
@@ -292,6 +355,8 @@ pub fn run() {
     .setup(|app| {
       let _ = create_tray_icon(app.handle());
       app.manage(Mutex::new(TrayState::NotPlaying));
+      app.manage(Mutex::new(SessionStore::default()));
+      load_event_content(app.handle());
 
       let app_handle = app.handle().clone();
       tauri::async_runtime::spawn(async move { long_running_thread(&app_handle).await });
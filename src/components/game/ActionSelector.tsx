@@ -10,9 +10,16 @@ import type {
   AdChannel,
   CoachingFocus,
   FiringReason,
+  Instrument,
+  DebtKind,
+  PlanTier,
+  PriceChange,
   MarketCondition,
   ActionSynergy,
+  Employee,
+  Candidate,
 } from '../../types/game-systems';
+import { OFFER_TERMS, formatOfferTerms, formatRole } from '../../types/game-systems';
 
 interface ActionSelectorProps {
   selectedActions: Action[];
@@ -23,6 +30,8 @@ interface ActionSelectorProps {
   marketConditions: MarketCondition[];
   synergies: ActionSynergy[];
   actionHistory: Array<{ week: number; actions: string[] }>;
+  team: Employee[];
+  candidates: Candidate[];
 }
 
 interface ActionCardData {
@@ -46,6 +55,8 @@ export default function ActionSelector({
   marketConditions,
   synergies,
   actionHistory,
+  team,
+  candidates,
 }: ActionSelectorProps) {
   const [modalOpen, setModalOpen] = useState(false);
   const [selectedActionForModal, setSelectedActionForModal] = useState<ActionCardData | null>(null);
@@ -59,12 +70,14 @@ export default function ActionSelector({
     if (card.name.startsWith('RefactorCode')) return 'RefactorCode';
     if (card.name.startsWith('ContentLaunch')) return 'ContentLaunch';
     if (card.name.startsWith('Raise')) return 'Fundraise';
+    if (card.name.startsWith('Borrow')) return 'TakeLoan';
+    if (card.name.startsWith('Price')) return 'ChangePrice';
     if (card.name === 'RunExperiment') return 'RunExperiment';
     if (card.name === 'DevRel') return 'DevRel';
     if (card.name === 'PaidAds') return 'PaidAds';
-    if (card.name === 'Hire') return 'Hire';
+    if (card.name.startsWith('Hire') || card.name.startsWith('Interview')) return 'Hire';
     if (card.name === 'Coach') return 'Coach';
-    if (card.name === 'Fire') return 'Fire';
+    if (card.name.startsWith('Fire')) return 'Fire';
     if (card.name === 'Take Break') return 'TakeBreak';
     if (card.name === 'ComplianceWork') return 'ComplianceWork';
     if (card.name === 'IncidentResponse') return 'IncidentResponse';
@@ -78,7 +91,7 @@ export default function ActionSelector({
       icon: '⚡',
       focusCost: 1,
       description: 'Move fast, break things',
-      effects: ['+3-5% WAU', '+6-8 tech debt', '-1-2 morale'],
+      effects: ['+3-5% WAU', '+6-8 tech debt', '-1-2 morale', 'Velocity resets to team level'],
       category: 'product',
       risk: 'medium',
       action: { ShipFeature: { quality: 'Quick' as Quality } },
@@ -88,7 +101,7 @@ export default function ActionSelector({
       icon: '⚖️',
       focusCost: 1,
       description: 'Balanced quality & speed',
-      effects: ['+4-6% WAU', '+2-3 tech debt', '+1 morale'],
+      effects: ['+4-6% WAU', '+2-3 tech debt', '+1 morale', 'Velocity resets to team level'],
       category: 'product',
       risk: 'low',
       action: { ShipFeature: { quality: 'Balanced' as Quality } },
@@ -98,7 +111,7 @@ export default function ActionSelector({
       icon: '✨',
       focusCost: 1,
       description: 'High quality, slower',
-      effects: ['+2-3% WAU', '-3-4 tech debt', '+3-4 morale', '+rep'],
+      effects: ['+2-3% WAU', '-3-4 tech debt', '+3-4 morale', '+rep', 'Velocity resets to team level'],
       category: 'product',
       risk: 'low',
       action: { ShipFeature: { quality: 'Polish' as Quality } },
@@ -156,6 +169,50 @@ export default function ActionSelector({
       risk: 'high',
       action: { FounderLedSales: { call_count: 5 } },
     },
+    {
+      name: 'Price: Raise Pro',
+      icon: '🏷️',
+      focusCost: 1,
+      description: 'Pro seats cost 20% more, for existing customers too',
+      effects: ['+20% Pro MRR', 'Lower paid conversion', 'Higher churn'],
+      category: 'sales',
+      risk: 'medium',
+      action: { ChangePrice: { tier: 'Pro' as PlanTier, change: 'Raise' as PriceChange } },
+      unlockCondition: 'First paying customer',
+    },
+    {
+      name: 'Price: Cut Pro',
+      icon: '✂️',
+      focusCost: 1,
+      description: 'Pro seats cost 20% less; holds the line in a pricing war',
+      effects: ['-20% Pro MRR', 'Higher paid conversion', 'Lower churn'],
+      category: 'sales',
+      risk: 'medium',
+      action: { ChangePrice: { tier: 'Pro' as PlanTier, change: 'Cut' as PriceChange } },
+      unlockCondition: 'First paying customer',
+    },
+    {
+      name: 'Price: Raise Enterprise',
+      icon: '🏢',
+      focusCost: 1,
+      description: 'Enterprise usage costs 20% more',
+      effects: ['+20% Enterprise MRR', 'Fewer enterprise deals close'],
+      category: 'sales',
+      risk: 'medium',
+      action: { ChangePrice: { tier: 'Enterprise' as PlanTier, change: 'Raise' as PriceChange } },
+      unlockCondition: 'First paying customer',
+    },
+    {
+      name: 'Price: Cut Enterprise',
+      icon: '🤝',
+      focusCost: 1,
+      description: 'Enterprise usage costs 20% less',
+      effects: ['-20% Enterprise MRR', 'More enterprise deals close'],
+      category: 'sales',
+      risk: 'low',
+      action: { ChangePrice: { tier: 'Enterprise' as PlanTier, change: 'Cut' as PriceChange } },
+      unlockCondition: 'First paying customer',
+    },
     {
       name: 'ContentLaunch (Blog)',
       icon: '📝',
@@ -200,16 +257,26 @@ export default function ActionSelector({
       action: { PaidAds: { budget: 20000, channel: 'Google' as AdChannel } },
       unlockCondition: 'Week 13+',
     },
-    {
-      name: 'Hire',
-      icon: '👥',
+    ...candidates.filter(c => c.stage === 'Sourced').map((candidate): ActionCardData => ({
+      name: `Interview ${candidate.name}`,
+      icon: '🗣️',
+      focusCost: 1,
+      description: `${candidate.seniority} ${formatRole(candidate.role).toLowerCase()} on the market until week ${candidate.expires_week}`,
+      effects: ['Reveals their skill', `Asks $${Math.round(candidate.salary_ask / 1000)}k/mo + ${candidate.equity_ask.toFixed(2)}%`],
+      category: 'team',
+      risk: 'low',
+      action: { Interview: { candidate_id: candidate.id } },
+    })),
+    ...candidates.filter(c => c.stage === 'Interviewed').flatMap(candidate => OFFER_TERMS.map((offer): ActionCardData => ({
+      name: `Hire ${candidate.name} (${offer === 'AtAsk' ? 'at ask' : offer === 'Lowball' ? 'lowball' : 'above ask'})`,
+      icon: '🤝',
       focusCost: 2,
-      description: 'Expand the team',
-      effects: ['+$10k burn', '+0.1 velocity', '+5 morale'],
+      description: `Offer the ${formatRole(candidate.role).toLowerCase()} (skill ${Math.round(candidate.skill)}): ${formatOfferTerms(offer).toLowerCase()}`,
+      effects: ['+salary on burn', `${candidate.equity_ask.toFixed(2)}% from the option pool`, 'Competitors may counter', '+5 morale'],
       category: 'team',
-      risk: 'medium',
-      action: { Hire: null },
-    },
+      risk: offer === 'Lowball' ? 'high' : 'medium',
+      action: { Hire: { candidate_id: candidate.id, offer } },
+    }))),
     {
       name: 'Coach',
       icon: '🎓',
@@ -221,23 +288,23 @@ export default function ActionSelector({
       action: { Coach: { focus: 'Skills' as CoachingFocus } },
       unlockCondition: 'Week 5+',
     },
-    {
-      name: 'Fire',
+    ...team.filter(e => e.role !== 'Founder').map((employee): ActionCardData => ({
+      name: `Fire ${employee.name}`,
       icon: '👋',
       focusCost: 1,
-      description: 'Remove underperforming team member',
-      effects: ['-$10k burn', '-15 morale', '-0.1 velocity', 'Depends on reason'],
+      description: `Let go of your ${formatRole(employee.role).toLowerCase()}`,
+      effects: [`-$${Math.round(employee.salary / 1000)}k burn`, '-15 morale', 'Loses their output', 'Depends on reason'],
       category: 'team',
       risk: 'high',
-      action: { Fire: { reason: 'Performance' as FiringReason } },
+      action: { Fire: { employee_id: employee.id, reason: 'Performance' as FiringReason } },
       unlockCondition: 'Week 21+',
-    },
+    })),
     {
       name: 'Raise $250k',
       icon: '💰',
       focusCost: 2,
-      description: 'Fundraise round',
-      effects: ['+$250k if win', '~5% dilution', '-10 morale if fail'],
+      description: 'Pitch investors for a priced round over 6 weeks',
+      effects: ['+$250k with a term sheet', 'Dilution + option pool top-up', '-10 morale if fail'],
       category: 'capital',
       risk: 'high',
       action: { Fundraise: { target: 250000 } },
@@ -247,11 +314,64 @@ export default function ActionSelector({
       icon: '💎',
       focusCost: 2,
       description: 'Larger round (harder)',
-      effects: ['+$500k if win', '~10% dilution', '-10 morale if fail'],
+      effects: ['+$500k if win', 'Dilution + option pool top-up', '-10 morale if fail'],
       category: 'capital',
       risk: 'high',
       action: { Fundraise: { target: 500000 } },
     },
+    {
+      name: 'Raise $150k SAFE',
+      icon: '📝',
+      focusCost: 2,
+      description: 'SAFE pitched over 3 weeks, converts at your next priced round',
+      effects: ['+$150k if win', 'Capped at current valuation, 20% discount', 'Dilution deferred, not avoided'],
+      category: 'capital',
+      risk: 'medium',
+      action: { Fundraise: { target: 150000, instrument: 'Safe' as Instrument } },
+    },
+    {
+      name: 'Raise $150k Note',
+      icon: '🧾',
+      focusCost: 2,
+      description: 'Convertible note pitched over 3 weeks, accrues 8% interest until it converts',
+      effects: ['+$150k if win', 'Capped at current valuation, 20% discount', 'Interest converts into more shares'],
+      category: 'capital',
+      risk: 'medium',
+      action: { Fundraise: { target: 150000, instrument: 'ConvertibleNote' as Instrument } },
+    },
+    {
+      name: 'Borrow: Revenue-Based',
+      icon: '💳',
+      focusCost: 1,
+      description: 'Advance of 4 months of MRR, repaid at 8% of revenue',
+      effects: ['+4× MRR in cash', 'Repays 1.4× the advance', 'No dilution'],
+      category: 'capital',
+      risk: 'medium',
+      action: { TakeLoan: { kind: 'RevenueBased' as DebtKind } },
+      unlockCondition: 'MRR $2.5k+',
+    },
+    {
+      name: 'Borrow: Bank Loan',
+      icon: '🏦',
+      focusCost: 1,
+      description: '6 months of MRR over 2 years at 8%',
+      effects: ['+6× MRR in cash', 'Weekly principal + interest', 'Covenant: MRR stays ≥ 75% of today'],
+      category: 'capital',
+      risk: 'medium',
+      action: { TakeLoan: { kind: 'BankLoan' as DebtKind } },
+      unlockCondition: 'MRR $2.5k+',
+    },
+    {
+      name: 'Borrow: Venture Debt',
+      icon: '🏗️',
+      focusCost: 1,
+      description: '30% of your last priced round over 3 years at 12%',
+      effects: ['Needs a priced round', 'Interest-only for 6 months', 'Covenant: cash stays ≥ half the loan'],
+      category: 'capital',
+      risk: 'high',
+      action: { TakeLoan: { kind: 'VentureDebt' as DebtKind } },
+      unlockCondition: 'MRR $2.5k+',
+    },
     {
       name: 'Take Break',
       icon: '🌴',
@@ -46,12 +46,12 @@ export default function CompoundingBonuses({ bonuses }: CompoundingBonusesProps)
                   {bonus.bonuses.map((stat, statIndex) => (
                     <Group key={statIndex} gap="xs">
                       <Badge size="sm" color="green" variant="light">
-                        {stat.stat_name}
+                        {stat.stat}
                       </Badge>
                       <Text size="xs" c="green" fw={600}>
-                        {stat.is_multiplier
-                          ? `${(stat.bonus_amount * 100).toFixed(0)}% boost`
-                          : `${stat.bonus_amount > 0 ? '+' : ''}${stat.bonus_amount.toFixed(1)}`}
+                        {stat.op === 'Boost'
+                          ? `${(stat.amount * 100).toFixed(0)}% boost`
+                          : `${stat.amount > 0 ? '+' : ''}${stat.amount.toFixed(1)}`}
                       </Text>
                     </Group>
                   ))}
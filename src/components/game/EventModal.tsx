@@ -1,5 +1,5 @@
 import { Modal, Stack, Text, Card, Button, Group, Badge, Alert } from '@mantine/core';
-import { GameEvent, EventChoice } from '../../types/game-systems';
+import { GameEvent, EventChoice, formatEffectTiming, formatFinancing, formatRoundDecision, formatDebtDecision, formatRenewalDecision } from '../../types/game-systems';
 import { useState } from 'react';
 
 interface EventModalProps {
@@ -67,16 +67,21 @@ export default function EventModal({ opened, event, onClose, onChoiceSelected }:
               {event.event_type.Automatic.effects.map((effect, index) => (
                 <Group key={index} gap="xs">
                   <Badge size="sm" variant="light">
-                    {effect.stat_name}
+                    {effect.stat}
                   </Badge>
                   <Text
                     size="sm"
-                    c={effect.change > 0 ? 'green' : 'red'}
+                    c={effect.amount > 0 ? 'green' : 'red'}
                     fw={600}
                   >
-                    {effect.change > 0 ? '+' : ''}
-                    {effect.change}
+                    {effect.amount > 0 ? '+' : ''}
+                    {effect.amount}
                   </Text>
+                  {formatEffectTiming(effect) && (
+                    <Text size="sm" c="dimmed" fs="italic">
+                      {formatEffectTiming(effect)}
+                    </Text>
+                  )}
                   <Text size="sm" c="dimmed">
                     {effect.description}
                   </Text>
@@ -164,19 +169,44 @@ export default function EventModal({ opened, event, onClose, onChoiceSelected }:
                       <Text size="xs" fw={700} tt="uppercase" c="dimmed">
                         Effects:
                       </Text>
+                      {choice.financing && (
+                        <Text size="xs" fw={600} c="green">
+                          💰 {formatFinancing(choice.financing)}
+                        </Text>
+                      )}
+                      {choice.round_decision && (
+                        <Text size="xs" fw={600}>
+                          📝 {formatRoundDecision(choice.round_decision)}
+                        </Text>
+                      )}
+                      {choice.debt_decision && (
+                        <Text size="xs" fw={600}>
+                          🏦 {formatDebtDecision(choice.debt_decision)}
+                        </Text>
+                      )}
+                      {choice.renewal_decision && (
+                        <Text size="xs" fw={600}>
+                          📄 {formatRenewalDecision(choice.renewal_decision)}
+                        </Text>
+                      )}
                       {choice.effects.map((effect, effectIndex) => (
                         <Group key={effectIndex} gap="xs">
                           <Badge size="xs" variant="outline">
-                            {effect.stat_name}
+                            {effect.stat}
                           </Badge>
                           <Text
                             size="xs"
-                            c={effect.delta > 0 ? 'green' : 'red'}
+                            c={effect.amount > 0 ? 'green' : 'red'}
                             fw={600}
                           >
-                            {effect.delta > 0 ? '+' : ''}
-                            {effect.delta}
+                            {effect.amount > 0 ? '+' : ''}
+                            {effect.amount}
                           </Text>
+                          {formatEffectTiming(effect) && (
+                            <Text size="xs" c="dimmed" fs="italic">
+                              {formatEffectTiming(effect)}
+                            </Text>
+                          )}
                           <Text size="xs" c="dimmed">
                             {effect.description}
                           </Text>
@@ -35,6 +35,7 @@ import type {
   DifficultyMode,
   Action,
 } from '../../types/game-systems';
+import { formatRole } from '../../types/game-systems';
 
 function mapEngineToUIDifficulty(engineDifficulty: DifficultyMode): string {
   switch (engineDifficulty) {
@@ -141,7 +142,7 @@ export default function GameDashboard({ gameState, onStateUpdate, onResetGame }:
 
       // Call enhanced take_turn that returns TurnResult
       const result = await gameInvoke('take_turn', {
-        state: gameState,
+        gameId: gameState.game_id,
         actions: selectedActions,
       });
 
@@ -196,11 +197,10 @@ export default function GameDashboard({ gameState, onStateUpdate, onResetGame }:
     if (!currentEvent || !('Dilemma' in currentEvent.event_type)) return;
 
     try {
-      const choiceId = currentEvent.event_type.Dilemma.choices[choiceIndex].id;
       const newState = await gameInvoke('apply_event_choice', {
-        state: gameState,
-        event: currentEvent,
-        choiceId,
+        gameId: gameState.game_id,
+        eventId: currentEvent.id,
+        choiceIndex,
       });
 
       onStateUpdate(newState);
@@ -215,6 +215,19 @@ export default function GameDashboard({ gameState, onStateUpdate, onResetGame }:
     }
   };
 
+  const handleCancelProject = async (projectId: number) => {
+    try {
+      const newState = await gameInvoke('cancel_project', {
+        gameId: gameState.game_id,
+        projectId,
+      });
+      onStateUpdate(newState);
+    } catch (err) {
+      console.error('Failed to cancel project', err);
+      setError(extractErrorMessage(err));
+    }
+  };
+
   const handleEventClose = () => {
     setEventModalOpened(false);
     setCurrentEvent(null);
@@ -222,10 +235,11 @@ export default function GameDashboard({ gameState, onStateUpdate, onResetGame }:
     setWeekSummaryOpened(true);
   };
 
-  const focusUsed = selectedActions.reduce((total, action) => {
+  const focusCost = (actions: Action[]) => actions.reduce((total, action) => {
     // Calculate focus cost (simplified - should match Rust logic)
     if ('ShipFeature' in action) return total + 1;
     if ('FounderLedSales' in action) return total + 1;
+    if ('Interview' in action) return total + 1;
     if ('Hire' in action) return total + 2;
     if ('Fundraise' in action) return total + 2;
     if ('TakeBreak' in action) return total + 1;
@@ -242,7 +256,43 @@ export default function GameDashboard({ gameState, onStateUpdate, onResetGame }:
     return total;
   }, 0);
 
-  const focusRemaining = gameState.focus_slots - focusUsed;
+  const projects = gameState.projects ?? [];
+  const focusUsed = focusCost(selectedActions);
+  // Running projects hold their focus every week until they finish
+  const focusHeld = focusCost(projects.map((project) => project.action));
+  const focusRemaining = gameState.focus_slots - focusHeld - focusUsed;
+
+  const mrrHistory = gameState.mrr_history ?? [];
+  const lastBridge = mrrHistory.length > 0 ? mrrHistory[mrrHistory.length - 1] : null;
+  const bridgeRows: [string, number][] = lastBridge
+    ? [
+        ['New business', lastBridge.new_business],
+        ['Expansion', lastBridge.expansion],
+        ['Reactivated', lastBridge.reactivated],
+        ['Contraction', -lastBridge.contraction],
+        ['Churned', -lastBridge.churned],
+      ]
+    : [];
+
+  // Most recent signup cohorts, newest first
+  const recentCohorts = (gameState.cohorts ?? []).slice(-6).reverse();
+  const retained = (value: number, initial: number) => (initial > 0 ? Math.round((value / initial) * 100) : 0);
+
+  // Acquisition funnel for the most recent week that had traffic
+  const lastFunnel = (gameState.funnel_history ?? []).slice(-1)[0];
+
+  // Paid plans on the pricing page, and whether an undercutter is forcing a pricing war
+  const paidPlans = gameState.pricing ? [gameState.pricing.pro, gameState.pricing.enterprise] : [];
+  const inPricingWar = (gameState.active_market_conditions ?? []).some((c) => c.id.startsWith('CompetitorPricingWar_'));
+
+  // Annual prepaid contracts: cash already collected but not yet earned, and the next renewals
+  const contracts = gameState.contracts ?? [];
+  const deferredRevenue = contracts.reduce((sum, c) => sum + c.deferred_revenue, 0);
+  const upcomingRenewals = [...contracts].sort((a, b) => a.renewal_week - b.renewal_week).slice(0, 3);
+
+  // The job market this week, and whether a talent war is making it harder
+  const candidates = gameState.candidates ?? [];
+  const inTalentWar = (gameState.active_market_conditions ?? []).some((c) => c.id === 'TalentWar');
 
   useEffect(() => {
     const container = officeContainerRef.current;
@@ -504,6 +554,257 @@ export default function GameDashboard({ gameState, onStateUpdate, onResetGame }:
                 {/* Placeholder for recommendations */}
               </Card>
 
+              {/* Projects In Progress */}
+              {projects.length > 0 && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Text size="sm" fw={700}>
+                      🚧 Projects in progress ({focusHeld} focus held)
+                    </Text>
+                    {projects.map((project) => (
+                      <Group key={project.id} justify="space-between" wrap="nowrap">
+                        <Stack gap={4} style={{ flex: 1 }}>
+                          <Text size="sm">
+                            {Object.keys(project.action)[0].replace(/([A-Z])/g, ' $1').trim()}
+                            {' '}· week {project.weeks_done} of {project.weeks_total}
+                          </Text>
+                          <Progress value={(project.weeks_done / project.weeks_total) * 100} size="sm" />
+                        </Stack>
+                        <Button size="xs" variant="subtle" color="red" onClick={() => handleCancelProject(project.id)}>
+                          Cancel
+                        </Button>
+                      </Group>
+                    ))}
+                    {gameState.investor_pipeline && (
+                      <Stack gap={4}>
+                        <Text size="xs" fw={700} tt="uppercase" c="dimmed">
+                          Investor pipeline
+                        </Text>
+                        {gameState.investor_pipeline.investors.map((investor) => (
+                          <Group key={investor.name} justify="space-between" wrap="nowrap">
+                            <Text size="xs">
+                              {investor.name} · {Math.round(investor.interest)}% interest
+                            </Text>
+                            <Badge size="xs" variant="light" color={investor.offer ? 'green' : investor.stage === 'Passed' ? 'gray' : 'blue'}>
+                              {investor.offer
+                                ? `$${(investor.offer.valuation / 1_000_000).toFixed(1)}M until week ${investor.offer.expires_week}`
+                                : investor.stage.replace(/([A-Z])/g, ' $1').trim()}
+                            </Badge>
+                          </Group>
+                        ))}
+                      </Stack>
+                    )}
+                  </Stack>
+                </Card>
+              )}
+
+              {/* Outstanding Debt */}
+              {(gameState.loans ?? []).length > 0 && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Text size="sm" fw={700}>
+                      🏦 Debt
+                    </Text>
+                    {gameState.loans.map((loan, index) => (
+                      <Group key={index} justify="space-between" wrap="nowrap">
+                        <Text size="sm">
+                          {loan.kind.replace(/([A-Z])/g, ' $1').trim()} · ${Math.round(loan.balance).toLocaleString()} owed
+                        </Text>
+                        {loan.missed_payments > 0 ? (
+                          <Badge size="xs" color="red">{loan.missed_payments} missed</Badge>
+                        ) : loan.weeks_in_breach > 0 ? (
+                          <Badge size="xs" color="orange">Covenant breached</Badge>
+                        ) : (
+                          <Badge size="xs" variant="light" color="green">Current</Badge>
+                        )}
+                      </Group>
+                    ))}
+                  </Stack>
+                </Card>
+              )}
+
+              {/* MRR Bridge */}
+              {lastBridge && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Text size="sm" fw={700}>
+                      📈 MRR bridge · week {lastBridge.week}
+                    </Text>
+                    <Group justify="space-between">
+                      <Text size="sm" c="dimmed">Starting MRR</Text>
+                      <Text size="sm">${Math.round(lastBridge.starting_mrr).toLocaleString()}</Text>
+                    </Group>
+                    {bridgeRows.map(([label, amount]) => (
+                      <Group key={label} justify="space-between">
+                        <Text size="sm">{label}</Text>
+                        <Text size="sm" c={amount > 0 ? 'green' : amount < 0 ? 'red' : 'dimmed'}>
+                          {amount >= 0 ? '+' : '-'}${Math.abs(Math.round(amount)).toLocaleString()}
+                        </Text>
+                      </Group>
+                    ))}
+                    <Group justify="space-between">
+                      <Text size="sm" fw={700}>Ending MRR</Text>
+                      <Text size="sm" fw={700}>${Math.round(lastBridge.ending_mrr).toLocaleString()}</Text>
+                    </Group>
+                  </Stack>
+                </Card>
+              )}
+
+              {/* Cohort Retention */}
+              {recentCohorts.length > 0 && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Text size="sm" fw={700}>
+                      👥 Cohorts · onboarding {Math.round(gameState.onboarding_quality)}
+                    </Text>
+                    {recentCohorts.map((cohort) => {
+                      const initialMrr = cohort.revenue.reduce((sum, r) => sum + r.initial_mrr, 0);
+                      const mrr = cohort.revenue.reduce((sum, r) => sum + r.mrr, 0);
+                      return (
+                        <Group key={cohort.join_week} justify="space-between" wrap="nowrap">
+                          <Text size="sm">Week {cohort.join_week}</Text>
+                          <Text size="sm" c="dimmed">
+                            {retained(cohort.users, cohort.initial_users)}% users
+                            {initialMrr > 0 && ` · ${retained(mrr, initialMrr)}% revenue`}
+                          </Text>
+                        </Group>
+                      );
+                    })}
+                  </Stack>
+                </Card>
+              )}
+
+              {/* Acquisition Funnel */}
+              {lastFunnel && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Text size="sm" fw={700}>
+                      🔻 Funnel · week {lastFunnel.week}
+                    </Text>
+                    {[
+                      ['Visitors', lastFunnel.visitors],
+                      ['Signups', lastFunnel.signups],
+                      ['Activated', lastFunnel.activated],
+                      ['Paid', lastFunnel.paid],
+                    ].map(([label, value]) => (
+                      <Group key={label} justify="space-between" wrap="nowrap">
+                        <Text size="sm">{label}</Text>
+                        <Text size="sm" c="dimmed">
+                          {Math.round(value as number).toLocaleString()}
+                        </Text>
+                      </Group>
+                    ))}
+                    {lastFunnel.new_mrr > 0 && (
+                      <Text size="xs" c="dimmed">
+                        +${Math.round(lastFunnel.new_mrr).toLocaleString()} new MRR
+                      </Text>
+                    )}
+                  </Stack>
+                </Card>
+              )}
+
+              {/* Pricing */}
+              {paidPlans.length > 0 && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Group justify="space-between" wrap="nowrap">
+                      <Text size="sm" fw={700}>
+                        🏷️ Pricing
+                      </Text>
+                      {inPricingWar && <Badge size="xs" color="red">Pricing war</Badge>}
+                    </Group>
+                    {paidPlans.map((plan) => (
+                      <Group key={plan.tier} justify="space-between" wrap="nowrap">
+                        <Text size="sm">{plan.tier}</Text>
+                        <Text size="sm" c="dimmed">
+                          ${plan.list_price.toFixed(0)}/{plan.metric === 'Seat' ? 'seat' : 'unit'} · {Math.round(plan.annual_discount * 100)}% off annual
+                        </Text>
+                      </Group>
+                    ))}
+                  </Stack>
+                </Card>
+              )}
+
+              {/* Annual Contracts */}
+              {contracts.length > 0 && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Group justify="space-between" wrap="nowrap">
+                      <Text size="sm" fw={700}>
+                        📄 Annual contracts
+                      </Text>
+                      <Badge size="xs" variant="light">{contracts.length} prepaid</Badge>
+                    </Group>
+                    <Group justify="space-between" wrap="nowrap">
+                      <Text size="sm">Deferred revenue</Text>
+                      <Text size="sm" fw={600}>${Math.round(deferredRevenue).toLocaleString()}</Text>
+                    </Group>
+                    <Text size="xs" c="dimmed">
+                      Cash collected upfront, earned week by week as the service is delivered
+                    </Text>
+                    {upcomingRenewals.map((contract) => (
+                      <Group key={contract.customer_id} justify="space-between" wrap="nowrap">
+                        <Text size="sm">{contract.company}</Text>
+                        <Text size="sm" c="dimmed">
+                          ${Math.round(contract.annual_value).toLocaleString()}/yr · renews week {contract.renewal_week}
+                        </Text>
+                      </Group>
+                    ))}
+                  </Stack>
+                </Card>
+              )}
+
+              {/* Candidate Pool */}
+              {candidates.length > 0 && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Group justify="space-between" wrap="nowrap">
+                      <Text size="sm" fw={700}>
+                        🧑‍💼 Candidates
+                      </Text>
+                      {inTalentWar && <Badge size="xs" color="red" variant="light">Talent war</Badge>}
+                    </Group>
+                    {candidates.map((candidate) => (
+                      <Group key={candidate.id} justify="space-between" wrap="nowrap">
+                        <Text size="sm">
+                          {candidate.name}
+                          <Text span size="xs" c="dimmed"> · {candidate.seniority} {formatRole(candidate.role).toLowerCase()}</Text>
+                        </Text>
+                        <Text size="sm" c="dimmed">
+                          {candidate.stage === 'Interviewed' ? `skill ${Math.round(candidate.skill)} · ` : ''}
+                          ${Math.round(candidate.salary_ask / 1000)}k/mo + {candidate.equity_ask.toFixed(2)}%
+                        </Text>
+                      </Group>
+                    ))}
+                    <Text size="xs" c="dimmed">
+                      Option pool: {gameState.option_pool.toFixed(1)}% left for grants
+                    </Text>
+                  </Stack>
+                </Card>
+              )}
+
+              {/* Burn Breakdown */}
+              {(gameState.expenses ?? []).length > 0 && (
+                <Card withBorder padding="md">
+                  <Stack gap="sm">
+                    <Text size="sm" fw={700}>
+                      💸 Burn breakdown
+                    </Text>
+                    {gameState.expenses.map((line, index) => (
+                      <Group key={index} justify="space-between" wrap="nowrap">
+                        <Text size="sm">
+                          {line.label}
+                          <Text span size="xs" c="dimmed"> · {line.category}</Text>
+                        </Text>
+                        <Text size="sm" fw={600}>
+                          ${Math.round(line.monthly).toLocaleString()}/mo
+                        </Text>
+                      </Group>
+                    ))}
+                  </Stack>
+                </Card>
+              )}
+
               {/* Action Selector */}
               <Card withBorder padding="lg">
                 <Stack gap="md">
@@ -526,6 +827,8 @@ export default function GameDashboard({ gameState, onStateUpdate, onResetGame }:
                     marketConditions={marketConditions}
                     synergies={synergies}
                     actionHistory={[]} // Placeholder
+                    team={gameState.team ?? []}
+                    candidates={candidates}
                   />
                 </Stack>
               </Card>
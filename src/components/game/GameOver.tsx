@@ -28,6 +28,11 @@ export default function GameOver({ status, gameState, onRestart }: GameOverProps
           title: 'Reputation Destroyed',
           message: 'Trust is hard to build and easy to lose. Your brand never recovered.',
         };
+      case 'debt_default':
+        return {
+          title: 'Loan Default',
+          message: 'You missed one payment too many and the lender called the loan. Debt is cheap until it comes due.',
+        };
       default:
         return { title: 'Game Over', message: 'The startup journey ends here.' };
     }
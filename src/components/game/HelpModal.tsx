@@ -66,6 +66,10 @@ export default function HelpModal({ opened, onClose }: HelpModalProps) {
                     <Badge color="red">3</Badge>
                     <Text size="sm">Reputation ≤ 10 (brand destroyed)</Text>
                   </Group>
+                  <Group gap="xs">
+                    <Badge color="red">4</Badge>
+                    <Text size="sm">Three missed loan payments, or a called loan you can't repay (default)</Text>
+                  </Group>
                 </Stack>
               </Stack>
             </Card>
@@ -1,6 +1,7 @@
 import { Card, Stack, Title, Text, Group, Badge, Tooltip, Button, Modal, Grid } from '@mantine/core';
 import { useId, useState, type KeyboardEventHandler } from 'react';
 import type { GameState } from '../../types/game-systems';
+import { formatRole } from '../../types/game-systems';
 import { estimateTeamSizeFromBurn } from '../../lib/office/stateMapper';
 
 interface OfficeCamProps {
@@ -56,21 +57,29 @@ export default function OfficeCam({ gameState }: OfficeCamProps) {
   const modalTitleId = `${widgetId}-office-cam-title`;
   const modalDescriptionId = `${widgetId}-office-cam-description`;
 
-  const teamSize = estimateTeamSizeFromBurn(gameState.burn);
   const moraleEmoji = getMoraleEmoji(gameState.morale);
   const clutterState = getClutterLevel(gameState.tech_debt);
   const officeVibe = getOfficeVibe(gameState.momentum, gameState.morale);
   const activityIcon = getActivityIndicator(gameState.week);
 
-  // Generate team members with morale-based expressions
-  const teamMembers = Array(teamSize)
-    .fill(0)
-    .map((_, i) => {
-      // Vary expressions slightly
-      const variance = (i % 3) * 10 - 10;
-      const memberMorale = sanitizePercent(gameState.morale + variance);
-      return getMoraleEmoji(memberMorale);
-    });
+  // Show the roster; saves from before it only have burn to go on
+  const roster = gameState.team ?? [];
+  const teamMembers = roster.length > 0
+    ? roster.map((employee) => ({
+        emoji: getMoraleEmoji(sanitizePercent(employee.morale)),
+        name: employee.name,
+        role: formatRole(employee.role),
+        morale: sanitizePercent(employee.morale),
+      }))
+    : Array(estimateTeamSizeFromBurn(gameState.burn))
+        .fill(0)
+        .map((_, i) => {
+          // Vary expressions slightly
+          const variance = (i % 3) * 10 - 10;
+          const memberMorale = sanitizePercent(gameState.morale + variance);
+          return { emoji: getMoraleEmoji(memberMorale), name: `Person ${i + 1}`, role: `Desk ${i + 1}`, morale: memberMorale };
+        });
+  const teamSize = teamMembers.length;
 
   const handleOpen = () => setExpanded(true);
   const handleKeyDown: KeyboardEventHandler<HTMLDivElement> = (event) => {
@@ -123,9 +132,9 @@ export default function OfficeCam({ gameState }: OfficeCamProps) {
             <div style={{ marginBottom: '12px' }}>
               <Text size="xs" c="dimmed" mb={4}>Team ({teamSize})</Text>
               <Group gap="xs">
-                {teamMembers.map((emoji, i) => (
-                  <Tooltip key={i} label={`Team member ${i + 1}`}>
-                    <Text size="xl" style={{ fontSize: '24px' }}>{emoji}</Text>
+                {teamMembers.map((member, i) => (
+                  <Tooltip key={i} label={`${member.name} (${member.role})`}>
+                    <Text size="xl" style={{ fontSize: '24px' }}>{member.emoji}</Text>
                   </Tooltip>
                 ))}
               </Group>
@@ -192,7 +201,9 @@ export default function OfficeCam({ gameState }: OfficeCamProps) {
             <Stack gap="sm">
               <Title order={4}>👥 Team ({teamSize} people)</Title>
               <Text size="sm" c="dimmed">
-                Estimated from burn rate: ${(gameState.burn / 1000).toFixed(0)}k/mo
+                {roster.length > 0
+                  ? `Payroll and tools: $${(gameState.burn / 1000).toFixed(0)}k/mo`
+                  : `Estimated from burn rate: $${(gameState.burn / 1000).toFixed(0)}k/mo`}
               </Text>
 
               <div style={{
@@ -201,10 +212,10 @@ export default function OfficeCam({ gameState }: OfficeCamProps) {
                 gap: '12px',
                 marginTop: '8px'
               }}>
-                {teamMembers.map((emoji, i) => (
+                {teamMembers.map((member, i) => (
                   <Tooltip
                     key={i}
-                    label={`Person ${i + 1}: ${getMoraleDescription(gameState.morale)}`}
+                    label={`${member.name}: ${getMoraleDescription(member.morale)}`}
                   >
                     <div style={{
                       textAlign: 'center',
@@ -213,8 +224,8 @@ export default function OfficeCam({ gameState }: OfficeCamProps) {
                       borderRadius: '8px',
                       cursor: 'pointer'
                     }}>
-                      <Text size="xl" style={{ fontSize: '32px' }}>{emoji}</Text>
-                      <Text size="xs" c="dimmed">Desk {i + 1}</Text>
+                      <Text size="xl" style={{ fontSize: '32px' }}>{member.emoji}</Text>
+                      <Text size="xs" c="dimmed">{member.role}</Text>
                     </div>
                   </Tooltip>
                 ))}
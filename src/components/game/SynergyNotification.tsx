@@ -23,7 +23,7 @@ export function SynergyNotification({ synergies, onClose }: SynergyNotificationP
       synergy.bonus_effects.forEach((effect, effectIndex) => {
         const key = `${synergyIndex}-${effectIndex}`;
         setTimeout(() => {
-          setAnimatedBonuses(prev => ({ ...prev, [key]: effect.bonus_amount }));
+          setAnimatedBonuses(prev => ({ ...prev, [key]: effect.amount }));
         }, 500 + (effectIndex * 200));
       });
     });
@@ -96,7 +96,7 @@ export function SynergyNotification({ synergies, onClose }: SynergyNotificationP
                           size="lg"
                           leftSection="+"
                         >
-                          {formatBonus(effect.stat_name, animatedValue)}
+                          {formatBonus(effect.stat, animatedValue)}
                         </Badge>
                       );
                     })}
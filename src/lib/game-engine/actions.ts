@@ -92,6 +92,19 @@ export function takeTurn(state: GameState, actions: Action[]): any {
     unlocked_actions: progressionStatus.available_rewards.filter(r => r.type === 'Action').map(r => r.value),
     milestone_event: milestoneEvent,
     specialization_bonus: specializationBonus,
+    completed_projects: [],
+    // The legacy engine doesn't attribute MRR, so the bridge only shows the net change
+    mrr_bridge: {
+      week: prevState.week,
+      starting_mrr: prevState.mrr,
+      new_business: Math.max(0, state.mrr - prevState.mrr),
+      expansion: 0,
+      contraction: 0,
+      churned: Math.max(0, prevState.mrr - state.mrr),
+      reactivated: 0,
+      ending_mrr: state.mrr,
+    },
+    net_revenue_retention: null,
   };
 }
 
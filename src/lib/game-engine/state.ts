@@ -48,11 +48,50 @@ export function newGame(difficulty: DifficultyMode): GameState {
     ],
     active_market_conditions: [],
     specialization_path: null,
-    team_size: 1,
+    team: [],
+    next_employee_id: 1,
+    candidates: [],
     incident_count: 0,
     last_break_week: 0,
     competitors: generateCompetitors(difficulty, 0),
     player_market_share: 50.0,
+    scheduled_events: [],
+    story_branches: {},
+    scheduled_effects: [],
+    projects: [],
+    next_project_id: 0,
+    cap_table: {
+      shareholders: [{ name: 'Founder', class: 'Common', shares: 10_000_000 }],
+      rounds: [],
+      convertibles: [],
+    },
+    investor_pipeline: null,
+    loans: [],
+    expenses: [],
+    ledger: { transactions: [] },
+    mrr_bridge: {
+      week: 0,
+      starting_mrr: 0,
+      new_business: 0,
+      expansion: 0,
+      contraction: 0,
+      churned: 0,
+      reactivated: 0,
+      ending_mrr: 0,
+    },
+    mrr_history: [],
+    acquisition_log: [],
+    cohorts: [
+      { join_week: 0, onboarding_quality: 50, initial_users: 100, users: 100, revenue: [], history: [] },
+    ],
+    onboarding_quality: 50,
+    funnel_history: [],
+    pricing: {
+      free: { tier: 'Free', metric: 'Seat', list_price: 0, annual_discount: 0 },
+      pro: { tier: 'Pro', metric: 'Seat', list_price: 49, annual_discount: 0.15 },
+      enterprise: { tier: 'Enterprise', metric: 'Usage', list_price: 25, annual_discount: 0.1 },
+    },
+    contracts: [],
   };
 
   updateDerivedMetrics(state);
@@ -1,4 +1,5 @@
-import { GameState, DifficultyMode, Action, GameEvent } from '../types/game-systems';
+import { localforage } from '../common/utils';
+import { GameState, DifficultyMode, Action, GameEvent, SaveSummary } from '../types/game-systems';
 import * as gameEngine from './game-engine';
 
 // Type definitions for Tauri invoke
@@ -25,25 +26,96 @@ async function invoke<T = any>(options: InvokeOptions): Promise<T> {
   }
 }
 
+// Without a backend the web build keeps its games here, keyed by game_id like the Tauri SessionStore
+interface WebSession {
+  state: GameState;
+  pendingDilemmas: GameEvent[];
+}
+
+// Same shape as the backend's per-game save file
+interface WebSave {
+  saved_at: number;
+  state: GameState;
+  pending_dilemmas: GameEvent[];
+}
+
+const sessions = new Map<string, WebSession>();
+const SAVE_KEY_PREFIX = 'save:';
+
+function getSession(gameId: string): WebSession {
+  const session = sessions.get(gameId);
+  if (!session) throw new Error(`Unknown game session '${gameId}'`);
+  return session;
+}
+
+async function autosave(session: WebSession): Promise<void> {
+  const save: WebSave = {
+    saved_at: Math.floor(Date.now() / 1000),
+    state: session.state,
+    pending_dilemmas: session.pendingDilemmas,
+  };
+  await localforage.setItem(SAVE_KEY_PREFIX + session.state.game_id, save);
+}
+
 // Route commands to TypeScript implementations
 async function invokeTypeScript<T>(options: InvokeOptions): Promise<T> {
   const { cmd, ...args } = options;
 
   switch (cmd) {
-    case 'new_game':
-      return gameEngine.newGame(args.difficulty as DifficultyMode) as T;
-
-    case 'take_turn':
-      return gameEngine.takeTurn(args.state as GameState, args.actions as Action[]) as T;
+    case 'new_game': {
+      const session: WebSession = { state: gameEngine.newGame(args.difficulty as DifficultyMode), pendingDilemmas: [] };
+      sessions.set(session.state.game_id, session);
+      await autosave(session);
+      return session.state as T;
+    }
+
+    case 'take_turn': {
+      const session = getSession(args.gameId);
+      const result = gameEngine.takeTurn(session.state, args.actions as Action[]);
+      session.state = result.state;
+      session.pendingDilemmas = result.events.filter((e: GameEvent) => e.event_type.Dilemma);
+      await autosave(session);
+      return result as T;
+    }
+
+    case 'load_game': {
+      const save = await localforage.getItem<WebSave>(SAVE_KEY_PREFIX + args.gameId);
+      if (!save) throw new Error(`No save for game '${args.gameId}'`);
+      sessions.set(save.state.game_id, { state: save.state, pendingDilemmas: save.pending_dilemmas });
+      return save.state as T;
+    }
+
+    case 'import_save': {
+      const state = args.save as GameState;
+      // Never clobber a game that has already been imported and played on
+      if (await localforage.getItem(SAVE_KEY_PREFIX + state.game_id)) return undefined as T;
+      await autosave({ state, pendingDilemmas: [] });
+      return undefined as T;
+    }
+
+    case 'list_saves': {
+      const keys = (await localforage.keys()).filter(key => key.startsWith(SAVE_KEY_PREFIX));
+      const saves = await Promise.all(keys.map(key => localforage.getItem<WebSave>(key)));
+      const summaries: SaveSummary[] = saves
+        .filter((save): save is WebSave => save !== null)
+        .map(save => ({
+          game_id: save.state.game_id,
+          schema_version: 0,
+          saved_at: save.saved_at,
+          week: save.state.week,
+          difficulty: save.state.difficulty,
+        }));
+      return summaries.sort((a, b) => b.saved_at - a.saved_at) as T;
+    }
 
     case 'check_game_status':
-      return gameEngine.checkGameStatus(args.state as GameState) as T;
+      return gameEngine.checkGameStatus(getSession(args.gameId).state) as T;
 
     case 'get_available_actions':
-      return gameEngine.getAvailableActions(args.state as GameState) as T;
+      return gameEngine.getAvailableActions(getSession(args.gameId).state) as T;
 
     case 'get_market_status':
-      return gameEngine.getMarketStatus(args.state as GameState, []) as T;
+      return gameEngine.getMarketStatus(getSession(args.gameId).state, []) as T;
 
     case 'generate_insights':
       return gameEngine.generateInsights(args.prevState as GameState, args.currentState as GameState) as T;
@@ -57,8 +129,18 @@ async function invokeTypeScript<T>(options: InvokeOptions): Promise<T> {
     case 'check_for_events':
       return gameEngine.checkForEvents(args.state as GameState, args.activeEvents) as T;
 
-    case 'apply_event_choice':
-      return gameEngine.applyEventChoice(args.state as GameState, args.event as GameEvent, args.choiceId) as T;
+    case 'apply_event_choice': {
+      const session = getSession(args.gameId);
+      const position = session.pendingDilemmas.findIndex(e => e.id === args.eventId);
+      if (position < 0) throw new Error(`No pending dilemma '${args.eventId}'`);
+      const event = session.pendingDilemmas[position];
+      const choice = event.event_type.Dilemma?.choices[args.choiceIndex];
+      if (!choice) throw new Error(`Invalid choice index ${args.choiceIndex}`);
+      session.state = gameEngine.applyEventChoice(session.state, event, choice.id);
+      session.pendingDilemmas.splice(position, 1);
+      await autosave(session);
+      return session.state as T;
+    }
 
     case 'check_action_synergies':
       return gameEngine.checkActionSynergies(args.state as GameState, args.actions, args.recentActions, args.synergies) as T;
@@ -90,20 +172,28 @@ export async function newGame(difficulty: DifficultyMode): Promise<GameState> {
   return gameInvoke('new_game', { difficulty });
 }
 
-export async function takeTurn(state: GameState, actions: Action[]): Promise<any> {
-  return gameInvoke('take_turn', { state, actions });
+export async function takeTurn(gameId: string, actions: Action[]): Promise<any> {
+  return gameInvoke('take_turn', { gameId, actions });
+}
+
+export async function loadGame(gameId: string): Promise<GameState> {
+  return gameInvoke('load_game', { gameId });
+}
+
+export async function listSaves(): Promise<SaveSummary[]> {
+  return gameInvoke('list_saves');
 }
 
-export async function checkGameStatus(state: GameState): Promise<any> {
-  return gameInvoke('check_game_status', { state });
+export async function checkGameStatus(gameId: string): Promise<any> {
+  return gameInvoke('check_game_status', { gameId });
 }
 
-export async function getAvailableActions(state: GameState): Promise<string[]> {
-  return gameInvoke('get_available_actions', { state });
+export async function getAvailableActions(gameId: string): Promise<string[]> {
+  return gameInvoke('get_available_actions', { gameId });
 }
 
-export async function getMarketStatus(state: GameState): Promise<any> {
-  return gameInvoke('get_market_status', { state });
+export async function getMarketStatus(gameId: string): Promise<any> {
+  return gameInvoke('get_market_status', { gameId });
 }
 
 export async function generateInsights(prevState: GameState, currentState: GameState): Promise<any> {
@@ -122,8 +212,8 @@ export async function checkForEvents(state: GameState, activeEvents: any[]): Pro
   return gameInvoke('check_for_events', { state, activeEvents });
 }
 
-export async function applyEventChoice(state: GameState, event: any, choiceId: string): Promise<any> {
-  return gameInvoke('apply_event_choice', { state, event, choiceId });
+export async function applyEventChoice(gameId: string, eventId: string, choiceIndex: number): Promise<GameState> {
+  return gameInvoke('apply_event_choice', { gameId, eventId, choiceIndex });
 }
 
 export async function checkActionSynergies(state: GameState, actions: Action[], recentActions: Action[], synergies: any[]): Promise<any> {
@@ -39,6 +39,14 @@ export function estimateTeamSizeFromBurn(burn: number): number {
   return Math.max(MIN_TEAM_SIZE, Math.min(MAX_TEAM_SIZE, estimated));
 }
 
+/** Headcount from the roster, falling back to the burn estimate for older saves */
+export function teamSizeFor(gameState: GameState): number {
+  if (gameState.team && gameState.team.length > 0) {
+    return Math.max(MIN_TEAM_SIZE, Math.min(MAX_TEAM_SIZE, gameState.team.length));
+  }
+  return estimateTeamSizeFromBurn(gameState.burn);
+}
+
 // ============================================================================
 // STATE MAPPER CLASS
 // ============================================================================
@@ -99,7 +107,7 @@ export class OfficeStateMapper {
     const clutterRng = this.rngFactory(`${baseSeed}-clutter`);
 
     // Determine office layout based on team size
-    const teamSize = estimateTeamSizeFromBurn(gameState.burn);
+    const teamSize = teamSizeFor(gameState);
     const layout = this.getLayoutForTeamSize(teamSize);
 
     // Generate tiles
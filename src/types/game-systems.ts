@@ -58,21 +58,69 @@ export type CoachingFocus = 'Skills' | 'Morale' | 'Alignment' | 'Performance';
 
 export type FiringReason = 'Performance' | 'Culture' | 'Budget';
 
+export type Instrument = 'PricedRound' | 'Safe' | 'ConvertibleNote';
+
+export type DebtKind = 'BankLoan' | 'VentureDebt' | 'RevenueBased';
+
+export type PlanTier = 'Free' | 'Pro' | 'Enterprise';
+
+export type PriceMetric = 'Seat' | 'Usage';
+
+export type PriceChange = 'Raise' | 'Cut';
+
+export type Role = 'Founder' | 'Engineer' | 'Designer' | 'Sales' | 'Marketing' | 'Operations';
+
+export type Seniority = 'Junior' | 'Mid' | 'Senior';
+
+export interface Employee {
+  id: number;
+  name: string;
+  role: Role;
+  seniority: Seniority;
+  salary: number;  // monthly
+  skill: number;
+  morale: number;
+  ramp_up_weeks: number;
+  tenure_weeks: number;
+}
+
+export type CandidateStage = 'Sourced' | 'Interviewed';
+
+export interface Candidate {
+  id: number;  // becomes their employee id if they join
+  name: string;
+  role: Role;
+  seniority: Seniority;
+  skill: number;  // only known after the interview
+  salary_ask: number;  // monthly
+  equity_ask: number;  // percent of the company
+  interest: number;
+  stage: CandidateStage;
+  expires_week: number;
+}
+
+export type OfferTerms = 'Lowball' | 'AtAsk' | 'AboveAsk';
+
+export const OFFER_TERMS: OfferTerms[] = ['Lowball', 'AtAsk', 'AboveAsk'];
+
 export type Action =
   | { ShipFeature: { quality: Quality } }
   | { RefactorCode: { depth: RefactorDepth } }
   | { RunExperiment: { category: ExperimentType } }
   | { FounderLedSales: { call_count: number } }
+  | { ChangePrice: { tier: PlanTier; change: PriceChange } }
   | { ContentLaunch: { content_type: ContentType } }
   | { DevRel: { event_type: DevRelEvent } }
   | { PaidAds: { budget: number; channel: AdChannel } }
-  | { Hire: null }
+  | { Interview: { candidate_id: number } }
+  | { Hire: { candidate_id: number; offer: OfferTerms } }
   | { Coach: { focus: CoachingFocus } }
-  | { Fire: { reason: FiringReason } }
+  | { Fire: { employee_id: number; reason: FiringReason } }
   | { ComplianceWork: { hours: number } }
   | { IncidentResponse: null }
   | { ProcessImprovement: null }
-  | { Fundraise: { target: number } }
+  | { Fundraise: { target: number; instrument?: Instrument } }
+  | { TakeLoan: { kind: DebtKind } }
   | { TakeBreak: null };
 
 export interface GameState {
@@ -102,11 +150,308 @@ export interface GameState {
   unlocked_actions: string[];  // NEW
   active_market_conditions: MarketCondition[];  // NEW
   specialization_path: SpecializationPath | null;  // NEW
-  team_size: number;  // NEW
+  team: Employee[];
+  next_employee_id: number;
+  candidates: Candidate[];
   incident_count: number;  // NEW
   last_break_week: number;  // NEW
   competitors: Competitor[];  // NEW
   player_market_share: number;  // NEW
+  scheduled_events: ScheduledEvent[];
+  story_branches: Record<string, string>;
+  scheduled_effects: ScheduledEffect[];
+  projects: Project[];
+  next_project_id: number;
+  cap_table: CapTable;
+  investor_pipeline: InvestorPipeline | null;
+  loans: Loan[];
+  expenses: ExpenseLine[];
+  ledger: Ledger;
+  mrr_bridge: MrrBridge;
+  mrr_history: MrrBridge[];
+  acquisition_log: AcquisitionRecord[];
+  cohorts: Cohort[];
+  onboarding_quality: number;
+  funnel_history: FunnelWeek[];
+  pricing: Pricing;
+  contracts: Contract[];
+}
+
+export type ShareClass = 'Common' | 'Preferred' | 'Options';
+
+export interface Shareholder {
+  name: string;
+  class: ShareClass;
+  shares: number;
+}
+
+export interface PricedRound {
+  name: string;
+  week: number;
+  pre_money: number;
+  amount_raised: number;
+  post_money: number;
+  price_per_share: number;
+  shares_issued: number;
+  pool_top_up: number;
+  conversions: Conversion[];
+  founder_ownership: number;
+}
+
+export interface Convertible {
+  instrument: Instrument;
+  principal: number;
+  valuation_cap: number;
+  discount: number;
+  interest_rate: number;
+  issued_week: number;
+}
+
+export interface Conversion {
+  instrument: Instrument;
+  amount: number;
+  price_per_share: number;
+  shares: number;
+}
+
+export interface Financing {
+  instrument: Instrument;
+  amount: number;
+  valuation: number;
+}
+
+export interface CapTable {
+  shareholders: Shareholder[];
+  rounds: PricedRound[];
+  convertibles: Convertible[];
+}
+
+export type Thesis = 'Generalist' | 'Saas' | 'Fintech' | 'DevTools';
+
+export type PipelineStage = 'Intro' | 'Pitch' | 'PartnerMeeting' | 'Diligence' | 'TermSheet' | 'Passed';
+
+export interface TermSheet {
+  valuation: number;
+  expires_week: number;
+}
+
+export interface Investor {
+  name: string;
+  thesis: Thesis;
+  check_size: number;
+  interest: number;
+  stage: PipelineStage;
+  offer: TermSheet | null;
+}
+
+export interface InvestorPipeline {
+  target: number;
+  instrument: Instrument;
+  opened_week: number;
+  investors: Investor[];
+}
+
+export type RoundDecision = 'SignBestOffer' | 'ShopAround';
+
+export type Covenant = { MinimumCash: number } | { MinimumMrr: number };
+
+export interface Loan {
+  kind: DebtKind;
+  principal: number;
+  balance: number;
+  annual_rate: number;
+  term_weeks: number;
+  issued_week: number;
+  weeks_elapsed: number;
+  covenant: Covenant | null;
+  weeks_in_breach: number;
+  missed_payments: number;
+}
+
+export type DebtDecision = 'NegotiateWaiver' | 'RepayInFull';
+
+export type LedgerCategory =
+  | 'Revenue'
+  | 'Payroll'
+  | 'Infrastructure'
+  | 'Tools'
+  | 'Marketing'
+  | 'Office'
+  | 'Compliance'
+  | 'Interest'
+  | 'Other'
+  | 'Equity'
+  | 'Debt'
+  | 'DeferredRevenue';
+
+export interface ExpenseLine {
+  category: LedgerCategory;
+  label: string;
+  monthly: number;
+}
+
+export interface Transaction {
+  week: number;
+  category: LedgerCategory;
+  amount: number;
+  reason: string;
+}
+
+export interface Ledger {
+  transactions: Transaction[];
+}
+
+export interface CategoryTotal {
+  category: LedgerCategory;
+  amount: number;
+}
+
+export interface MrrBridge {
+  week: number;
+  starting_mrr: number;
+  new_business: number;
+  expansion: number;
+  contraction: number;
+  churned: number;
+  reactivated: number;
+  ending_mrr: number;
+}
+
+export type AcquisitionChannel = { Ads: AdChannel } | 'FounderLedSales' | 'Content';
+
+export interface AcquisitionRecord {
+  week: number;
+  channel: AcquisitionChannel;
+  spend: number;
+  customers: number;
+  new_mrr: number;
+}
+
+export interface ChannelEconomics {
+  channel: AcquisitionChannel;
+  spend: number;
+  customers: number;
+  cac: number | null;
+}
+
+export interface UnitEconomics {
+  channels: ChannelEconomics[];
+  blended_cac: number | null;
+  arpu: number | null;
+  ltv: number | null;
+  ltv_to_cac: number | null;
+  cac_payback_months: number | null;
+  burn_multiple: number | null;
+  magic_number: number | null;
+  rule_of_40: number | null;
+}
+
+export type CustomerSegment = 'Enterprise' | 'SMB' | 'SelfServe';
+
+export interface SegmentRevenue {
+  segment: CustomerSegment;
+  initial_mrr: number;
+  mrr: number;
+}
+
+export interface CohortWeek {
+  users: number;
+  mrr: number;
+}
+
+export interface Cohort {
+  join_week: number;
+  onboarding_quality: number;
+  initial_users: number;
+  users: number;
+  revenue: SegmentRevenue[];
+  history: CohortWeek[];
+}
+
+// Retention by week since signup, in percent
+export interface CohortRow {
+  join_week: number;
+  initial_users: number;
+  initial_mrr: number;
+  user_retention: number[];
+  revenue_retention: number[];
+}
+
+// List prices are monthly, per seat or usage unit
+export interface PricingPlan {
+  tier: PlanTier;
+  metric: PriceMetric;
+  list_price: number;
+  annual_discount: number;
+}
+
+export interface Pricing {
+  free: PricingPlan;
+  pro: PricingPlan;
+  enterprise: PricingPlan;
+}
+
+export type BillingCycle = 'Monthly' | 'Annual';
+
+export type RenewalDecision = 'ListPrice' | 'OfferDiscount' | 'PushUplift';
+
+export interface Contract {
+  customer_id: string;
+  company: string;
+  segment: CustomerSegment;
+  tier: PlanTier;
+  units: number;
+  join_week: number;
+  start_week: number;
+  renewal_week: number;
+  annual_value: number;
+  deferred_revenue: number;
+  renewal_terms: RenewalDecision | null;
+}
+
+export interface ConversionRates {
+  visitor_to_signup: number;
+  signup_to_activation: number;
+  activation_to_paid: number;
+}
+
+export interface FunnelWeek {
+  week: number;
+  visitors: number;
+  signups: number;
+  activated: number;
+  paid: number;
+  new_mrr: number;
+}
+
+export interface ProfitAndLoss {
+  from_week: number;
+  to_week: number;
+  revenue: number;
+  operating_expenses: CategoryTotal[];
+  operating_income: number;
+  interest: number;
+  net_income: number;
+  financing: number;
+  deferred_revenue: number;
+  net_cash_flow: number;
+}
+
+export interface Project {
+  id: number;
+  action: Action;
+  started_week: number;
+  weeks_total: number;
+  weeks_done: number;
+}
+
+export interface CompletedProject {
+  project: Project;
+  result: {
+    success: boolean;
+    message: string;
+    effects: { stat: Stat; old_value: number; new_value: number; delta: number }[];
+  };
 }
 
 export type WarningSeverity = 'Watch' | 'Caution' | 'Danger' | 'Critical';
@@ -128,23 +473,50 @@ export interface FailureWarning {
   severity: WarningSeverity;
 }
 
-export interface StatBonus {
-  stat_name: string;
-  bonus_amount: number;
-  is_multiplier: boolean;
+export type Stat =
+  | 'Bank'
+  | 'Burn'
+  | 'MRR'
+  | 'WAU'
+  | 'WAU Growth'
+  | 'Churn Rate'
+  | 'Morale'
+  | 'Reputation'
+  | 'NPS'
+  | 'Tech Debt'
+  | 'Compliance Risk'
+  | 'Velocity'
+  | 'Founder Equity'
+  | 'Focus'
+  | 'Onboarding Quality';
+
+export type EffectOp = 'Add' | 'Boost' | 'Multiply' | 'Set';
+
+export interface Effect {
+  stat: Stat;
+  op: EffectOp;
+  amount: number;
 }
 
 export interface CompoundingBonus {
   effect_id: string;
   name: string;
   message: string;
-  bonuses: StatBonus[];
+  bonuses: Effect[];
 }
 
-export interface EventEffect {
-  stat_name: string;
-  delta: number;
+export interface EventEffect extends Effect {
+  chance: number | null;
   description: string;
+  delay_weeks: number;
+  duration_weeks: number;
+}
+
+export interface ScheduledEffect {
+  effect: Effect;
+  description: string;
+  start_week: number;
+  remaining_weeks: number;
 }
 
 export interface EventChoice {
@@ -155,6 +527,22 @@ export interface EventChoice {
   long_term: string;
   wisdom: string;
   effects: EventEffect[];
+  follow_up: FollowUp | null;
+  financing: Financing | null;
+  round_decision: RoundDecision | null;
+  debt_decision: DebtDecision | null;
+  renewal_decision: RenewalDecision | null;
+}
+
+export interface FollowUp {
+  event_id: string;
+  delay_weeks: number;
+}
+
+export interface ScheduledEvent {
+  event_id: string;
+  due_week: number;
+  source_event_id: string;
 }
 
 export interface GameEvent {
@@ -172,13 +560,7 @@ export interface ActionSynergy {
   id: string;
   name: string;
   description: string;
-  bonus_effects: SynergyEffect[];
-}
-
-export interface SynergyEffect {
-  stat_name: string;
-  bonus_amount: number;
-  description: string;
+  bonus_effects: Effect[];
 }
 
 export interface MarketCondition {
@@ -226,6 +608,18 @@ export interface TurnResult {
   unlocked_actions: string[];  // NEW
   milestone_event: MilestoneEvent | null;  // NEW
   specialization_bonus: SpecializationPath | null;  // NEW
+  completed_projects: CompletedProject[];
+  mrr_bridge: MrrBridge;
+  net_revenue_retention: number | null;
+}
+
+// One entry per saved game, listed without loading the whole state
+export interface SaveSummary {
+  game_id: string;
+  schema_version: number;
+  saved_at: number;  // Unix seconds
+  week: number;
+  difficulty: DifficultyMode;
 }
 
 // Competitor tracking system types
@@ -374,10 +768,68 @@ export function getMarketConditionColor(condition: MarketCondition): string {
 }
 
 export function formatSynergyBonus(synergy: ActionSynergy): string {
-  const bonuses = synergy.bonus_effects.map(effect => `${effect.description} (+${effect.bonus_amount})`).join(', ');
+  const bonuses = synergy.bonus_effects.map(effect => `${effect.stat} (+${effect.amount})`).join(', ');
   return `${synergy.name}: ${bonuses}`;
 }
 
+export function formatEffectTiming(effect: EventEffect): string {
+  const start = effect.delay_weeks > 0 ? `in ${effect.delay_weeks} weeks` : '';
+  if (effect.duration_weeks > 0) {
+    const recurring = `per week for ${effect.duration_weeks} weeks`;
+    return start ? `${recurring}, starting ${start}` : recurring;
+  }
+  return start;
+}
+
+export function formatFinancing(financing: Financing): string {
+  const amount = `$${(financing.amount / 1_000_000).toFixed(2)}M`;
+  const valuation = `$${(financing.valuation / 1_000_000).toFixed(1)}M`;
+  if (financing.instrument === 'PricedRound') {
+    return `Raise ${amount} in a priced round at ${valuation} pre-money`;
+  }
+  const label = financing.instrument === 'Safe' ? 'SAFE' : 'convertible note';
+  return `Raise ${amount} on a ${label} capped at ${valuation}, converting at your next priced round`;
+}
+
+export function formatDebtDecision(decision: DebtDecision): string {
+  return decision === 'NegotiateWaiver'
+    ? 'Pays a fee and a higher rate for a looser covenant'
+    : 'Repays the breached loan in full if you have the cash';
+}
+
+export function formatRenewalDecision(decision: RenewalDecision): string {
+  switch (decision) {
+    case 'ListPrice':
+      return 'Renews annual contracts at today\'s list price';
+    case 'OfferDiscount':
+      return 'Renews at 10% off; more customers stay';
+    case 'PushUplift':
+      return 'Asks 15% over list; some customers may leave';
+  }
+}
+
+export function formatRole(role: Role): string {
+  switch (role) {
+    case 'Sales': return 'Account executive';
+    case 'Marketing': return 'Marketer';
+    default: return role;
+  }
+}
+
+export function formatOfferTerms(terms: OfferTerms): string {
+  switch (terms) {
+    case 'Lowball': return '15% under the ask';
+    case 'AtAsk': return 'Meets the ask';
+    case 'AboveAsk': return '15% over the ask; holds up against counter-offers';
+  }
+}
+
+export function formatRoundDecision(decision: RoundDecision): string {
+  return decision === 'SignBestOffer'
+    ? 'Signs the best term sheet and ends the raise'
+    : 'Keeps the raise open: offers may improve or be pulled';
+}
+
 // Competitor helper functions
 
 export function getCompetitorsByFunding(competitors: Competitor[], stage: FundingStage): Competitor[] {
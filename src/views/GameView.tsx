@@ -1,13 +1,13 @@
 import { useEffect, useState } from 'react';
 import { Container, Title, Text, Button, Stack, Group, Card, Badge, Divider } from '@mantine/core';
 import { gameInvoke } from '../lib/invoke-wrapper';
+import { localforage } from '../common/utils';
 import GameDashboard from '../components/game/GameDashboard';
 import GameOver from '../components/game/GameOver';
 import StartupAnimation from '../components/game/StartupAnimation';
 import { useGameConfig } from '../common/GameConfigContext';
 import { THEME_PRESETS } from '../common/themePresets';
-import { useLocalForage } from '../common/utils';
-import type { GameState, DifficultyMode } from '../types/game-systems';
+import type { GameState, DifficultyMode, SaveSummary } from '../types/game-systems';
 import type { GameStatus } from '../lib/game-engine/victory';
 
 function mapUIToEngineDifficulty(uiDifficulty: string): DifficultyMode {
@@ -30,6 +30,17 @@ function mapEngineToUIDifficulty(engineDifficulty: DifficultyMode): string {
   }
 }
 
+// Older builds kept a single game here as a bare GameState
+const LEGACY_SAVE_KEY = 'saved-game-state';
+
+// Hand the old game to the backend as a v0 save, then forget it so this only runs once
+async function importLegacySave(): Promise<void> {
+  const legacy = await localforage.getItem<GameState>(LEGACY_SAVE_KEY);
+  if (!legacy) return;
+  await gameInvoke('import_save', { save: legacy });
+  await localforage.removeItem(LEGACY_SAVE_KEY);
+}
+
 export default function GameView() {
   const { config } = useGameConfig();
   const accentColor = THEME_PRESETS[config.themeAccent]?.primaryColor ?? 'teal';
@@ -38,7 +49,7 @@ export default function GameView() {
   const [selectedDifficulty, setSelectedDifficulty] = useState<string>(config.defaultDifficulty);
   const [loading, setLoading] = useState(false);
   const [showStartupAnimation, setShowStartupAnimation] = useState(true);
-  const [savedGame, setSavedGame, savedLoading] = useLocalForage<GameState | null>('saved-game-state', null);
+  const [saves, setSaves] = useState<SaveSummary[]>([]);
 
   const startNewGame = async (difficulty: string) => {
     setLoading(true);
@@ -47,7 +58,6 @@ export default function GameView() {
       const state = await gameInvoke('new_game', { difficulty: engineDifficulty });
       setGameState(state);
       setGameStatus(null); // Will be checked in useEffect
-      setSavedGame(state);
     } catch (error) {
       console.error('Failed to start game:', error);
     } finally {
@@ -59,7 +69,7 @@ export default function GameView() {
     if (!gameState) return;
 
     try {
-      const status = await gameInvoke('check_game_status', { state: gameState });
+      const status = await gameInvoke('check_game_status', { gameId: gameState.game_id });
       setGameStatus(status);
     } catch (error) {
       console.error('Failed to check game status:', error);
@@ -78,14 +88,31 @@ export default function GameView() {
     }
   }, [config.defaultDifficulty, gameStatus]);
 
-  const resumeSavedGame = () => {
-    if (!savedGame) return;
-    setGameState(savedGame);
-    setGameStatus(null); // Will be checked in useEffect
+  // The backend owns saved games; refresh the list whenever the menu shows
+  useEffect(() => {
+    if (gameStatus !== null) return;
+    importLegacySave()
+      .catch(error => console.error('Failed to import saved game:', error))
+      .then(() => gameInvoke('list_saves'))
+      .then(setSaves)
+      .catch(error => console.error('Failed to list saves:', error));
+  }, [gameStatus]);
+
+  const resumeSavedGame = async (gameId: string) => {
+    setLoading(true);
+    try {
+      // Loading re-registers the session so later commands find it
+      const state = await gameInvoke('load_game', { gameId });
+      setGameState(state);
+      setGameStatus(null); // Will be checked in useEffect
+    } catch (error) {
+      console.error('Failed to resume game:', error);
+    } finally {
+      setLoading(false);
+    }
   };
 
   const resetProgress = () => {
-    setSavedGame(null);
     setGameState(null);
     setGameStatus(null);
     setSelectedDifficulty(config.defaultDifficulty);
@@ -93,7 +120,6 @@ export default function GameView() {
 
   const handleStateUpdate = (state: GameState) => {
     setGameState(state);
-    setSavedGame(state);
   };
 
   // Show startup animation on first load
@@ -113,26 +139,25 @@ export default function GameView() {
           </Stack>
 
           <Stack gap="lg" w="100%" maw={800}>
-            {!savedLoading && savedGame && (
+            {saves.length > 0 && (
               <Card withBorder padding="lg" radius="md">
                 <Stack gap="sm">
-                  <Group justify="space-between" align="center">
-                    <Group gap="sm">
-                      <Title order={3} size="h4">Resume Campaign</Title>
-                      <Badge color={accentColor as any}>
-                        {mapEngineToUIDifficulty(savedGame.difficulty)}
-                      </Badge>
+                  <Title order={3} size="h4">Resume Campaign</Title>
+                  {saves.map(save => (
+                    <Group key={save.game_id} justify="space-between" align="center">
+                      <Group gap="sm">
+                        <Badge color={accentColor as any}>
+                          {mapEngineToUIDifficulty(save.difficulty)}
+                        </Badge>
+                        <Text size="sm" c="dimmed">
+                          Week {save.week} • Saved {new Date(save.saved_at * 1000).toLocaleString()}
+                        </Text>
+                      </Group>
+                      <Button color={accentColor as any} size="xs" onClick={() => resumeSavedGame(save.game_id)} loading={loading}>
+                        Continue
+                      </Button>
                     </Group>
-                    <Button variant="light" color="red" size="xs" onClick={resetProgress}>
-                      Start New From Scratch
-                    </Button>
-                  </Group>
-                  <Text size="sm" c="dimmed">
-                    Week {savedGame.week} • Bank ${Math.round(savedGame.bank).toLocaleString()} • Morale {Math.round(savedGame.morale)}%
-                  </Text>
-                  <Group gap="sm">
-                    <Button color={accentColor as any} onClick={resumeSavedGame}>Continue Saved Game</Button>
-                  </Group>
+                  ))}
                 </Stack>
               </Card>
             )}